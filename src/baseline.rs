@@ -0,0 +1,328 @@
+//! # Baseline Comparison
+//!
+//! Compares a run against a baseline so only newly introduced findings are reported,
+//! which turns Cargo Dokita into a regression gate for pull requests.
+//!
+//! A baseline is either a versioned [`Baseline`] file written with `--write-baseline`
+//! or a report previously written with `--format json`. Findings are matched by
+//! [`Finding::fingerprint`] (code, file and message); line numbers are ignored so
+//! unrelated edits that shift code around do not make old findings look new, and files
+//! are taken relative to the project root so a baseline survives moving the checkout.
+
+use crate::config::BaselineFormat;
+use crate::diagnostics::{Finding, JsonReport};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+/// Version of the [`Baseline`] file schema. Bump it when the layout changes incompatibly.
+pub const BASELINE_VERSION: u32 = 1;
+
+/// On-disk baseline: the fingerprints of findings accepted at some point in time.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Baseline {
+    /// Schema version, validated on load.
+    pub version: u32,
+    pub findings: Vec<BaselineEntry>,
+}
+
+/// One accepted finding. Only the fingerprint is used for matching; `code` and `file`
+/// are there so the file can be reviewed by hand. `file` is relative to the project root,
+/// so a committed baseline holds no machine-specific paths.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct BaselineEntry {
+    /// [`Finding::fingerprint`] of the accepted finding.
+    pub fingerprint: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+}
+
+impl Baseline {
+    /// A baseline accepting every finding in `findings`, fingerprinted relative to
+    /// `project_root`.
+    pub fn from_findings(findings: &[Finding], project_root: &Path) -> Self {
+        Baseline {
+            version: BASELINE_VERSION,
+            findings: findings
+                .iter()
+                .map(|finding| BaselineEntry {
+                    fingerprint: finding.fingerprint(project_root),
+                    code: Some(finding.code.clone()),
+                    file: finding.relative_file_path(project_root),
+                })
+                .collect(),
+        }
+    }
+
+    /// Reads a baseline file, rejecting versions this release does not know.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let content = fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read baseline {path:?}: {e}"))?;
+        let value: serde_json::Value = serde_json::from_str(&content)
+            .map_err(|e| format!("Failed to parse baseline {path:?}: {e}"))?;
+        let version = value
+            .get("version")
+            .and_then(serde_json::Value::as_u64)
+            .ok_or_else(|| format!("Baseline {path:?} has no version field"))?;
+        if version == 0 || version > u64::from(BASELINE_VERSION) {
+            return Err(format!(
+                "Baseline {path:?} has format version {version}, but this cargo-dokita only reads version {BASELINE_VERSION}. Upgrade cargo-dokita or regenerate the baseline."
+            ));
+        }
+        serde_json::from_value(value).map_err(|e| format!("Failed to parse baseline {path:?}: {e}"))
+    }
+
+    /// Writes the baseline as pretty-printed JSON.
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| format!("Failed to serialize baseline: {e}"))?;
+        fs::write(path, json + "\n").map_err(|e| format!("Failed to write baseline {path:?}: {e}"))
+    }
+}
+
+/// Loads the baseline at `path` in the given format. `Auto` treats files with a top-level
+/// `version` field as a [`Baseline`] and anything else as a `--format json` report, whose
+/// findings are fingerprinted relative to `project_root`.
+pub fn load_baseline(
+    path: &Path,
+    format: BaselineFormat,
+    project_root: &Path,
+) -> Result<Baseline, String> {
+    let is_versioned = || {
+        fs::read_to_string(path)
+            .ok()
+            .and_then(|content| serde_json::from_str::<serde_json::Value>(&content).ok())
+            .is_some_and(|value| value.get("version").is_some())
+    };
+    match format {
+        BaselineFormat::Baseline => Baseline::load(path),
+        BaselineFormat::Auto if is_versioned() => Baseline::load(path),
+        BaselineFormat::Auto | BaselineFormat::Report => Ok(Baseline::from_findings(
+            &load_baseline_findings(path)?,
+            project_root,
+        )),
+    }
+}
+
+/// Result of comparing the current findings with a baseline.
+#[derive(Debug)]
+pub struct BaselineDiff {
+    /// Findings not present in the baseline.
+    pub new: Vec<Finding>,
+    /// Baseline findings that are no longer reported.
+    pub fixed: usize,
+}
+
+/// Loads the findings of a `--format json` report.
+pub fn load_baseline_findings(path: &Path) -> Result<Vec<Finding>, String> {
+    let content =
+        fs::read_to_string(path).map_err(|e| format!("Failed to read baseline {path:?}: {e}"))?;
+    let report = JsonReport::parse(&content)
+        .map_err(|e| format!("Failed to parse baseline {path:?}: {e}"))?;
+    Ok(report.findings)
+}
+
+/// Splits `findings` into those missing from `baseline` and counts baseline entries that
+/// disappeared. Repeated identical findings are matched one-to-one, so a second copy of
+/// a known finding still counts as new. Findings are fingerprinted relative to `project_root`.
+pub fn diff_against_baseline(
+    findings: Vec<Finding>,
+    baseline: &Baseline,
+    project_root: &Path,
+) -> BaselineDiff {
+    let mut remaining: HashMap<&str, usize> = HashMap::new();
+    for entry in &baseline.findings {
+        *remaining.entry(entry.fingerprint.as_str()).or_default() += 1;
+    }
+
+    let is_new: Vec<bool> = findings
+        .iter()
+        .map(
+            |finding| match remaining.get_mut(finding.fingerprint(project_root).as_str()) {
+                Some(count) if *count > 0 => {
+                    *count -= 1;
+                    false
+                }
+                _ => true,
+            },
+        )
+        .collect();
+    let fixed = remaining.values().sum();
+
+    BaselineDiff {
+        new: findings
+            .into_iter()
+            .zip(is_new)
+            .filter_map(|(finding, is_new)| is_new.then_some(finding))
+            .collect(),
+        fixed,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diagnostics::Severity;
+    use tempfile::TempDir;
+
+    fn root() -> &'static Path {
+        Path::new("/work/demo")
+    }
+
+    fn finding(code: &str, line: usize) -> Finding {
+        Finding::new(
+            code,
+            format!("{code} message"),
+            Severity::Warning,
+            Some("src/lib.rs".to_string()),
+        )
+        .with_line(line)
+    }
+
+    #[test]
+    fn test_diff_reports_new_and_fixed_findings() {
+        let baseline =
+            Baseline::from_findings(&[finding("CODE001", 3), finding("CODE004", 10)], root());
+        // CODE001 moved to another line, CODE004 was fixed, CODE002 is new
+        let current = vec![finding("CODE001", 5), finding("CODE002", 8)];
+
+        let diff = diff_against_baseline(current, &baseline, root());
+
+        assert_eq!(diff.new.len(), 1);
+        assert_eq!(diff.new[0].code, "CODE002");
+        assert_eq!(diff.fixed, 1);
+    }
+
+    #[test]
+    fn test_diff_matches_repeated_findings_one_to_one() {
+        let baseline = Baseline::from_findings(&[finding("CODE001", 3)], root());
+        let current = vec![finding("CODE001", 3), finding("CODE001", 4)];
+
+        let diff = diff_against_baseline(current, &baseline, root());
+
+        assert_eq!(diff.new.len(), 1);
+        assert_eq!(diff.fixed, 0);
+    }
+
+    #[test]
+    fn test_load_baseline_from_json_report() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("baseline.json");
+        let report = JsonReport::new(vec![finding("CODE001", 3)]);
+        let content = format!(
+            "project path = .\n{}\n\nFound 1 issues:\n",
+            serde_json::to_string_pretty(&report).unwrap()
+        );
+        fs::write(&path, content).unwrap();
+
+        let findings = load_baseline_findings(&path).unwrap();
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].code, "CODE001");
+
+        fs::write(&path, "not json").unwrap();
+        assert!(load_baseline_findings(&path).is_err());
+    }
+
+    #[test]
+    fn test_baseline_round_trip() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("dokita-baseline.json");
+        let baseline =
+            Baseline::from_findings(&[finding("CODE001", 3), finding("CODE004", 10)], root());
+
+        baseline.save(&path).unwrap();
+        let loaded = Baseline::load(&path).unwrap();
+
+        assert_eq!(loaded, baseline);
+        assert_eq!(loaded.version, BASELINE_VERSION);
+        assert_eq!(loaded.findings[0].code.as_deref(), Some("CODE001"));
+        assert_eq!(
+            loaded.findings[0].fingerprint,
+            finding("CODE001", 3).fingerprint(root())
+        );
+    }
+
+    #[test]
+    fn test_baseline_stores_root_relative_paths() {
+        let absolute = Finding::new(
+            "CODE001",
+            "CODE001 message".to_string(),
+            Severity::Warning,
+            Some(
+                root()
+                    .join("src")
+                    .join("lib.rs")
+                    .to_string_lossy()
+                    .into_owned(),
+            ),
+        );
+        let baseline = Baseline::from_findings(std::slice::from_ref(&absolute), root());
+        assert_eq!(baseline.findings[0].file.as_deref(), Some("src/lib.rs"));
+
+        // The same project checked out elsewhere matches the baseline
+        let moved_root = Path::new("/builds/ci/demo");
+        let mut moved = absolute;
+        moved.file_path = Some(
+            moved_root
+                .join("src")
+                .join("lib.rs")
+                .to_string_lossy()
+                .into_owned(),
+        );
+        let diff = diff_against_baseline(vec![moved], &baseline, moved_root);
+        assert!(diff.new.is_empty());
+        assert_eq!(diff.fixed, 0);
+    }
+
+    #[test]
+    fn test_baseline_load_rejects_unknown_versions() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("dokita-baseline.json");
+
+        fs::write(&path, r#"{"version": 99, "findings": []}"#).unwrap();
+        let err = Baseline::load(&path).unwrap_err();
+        assert!(err.contains("format version 99"));
+
+        fs::write(&path, r#"{"findings": []}"#).unwrap();
+        assert!(
+            Baseline::load(&path)
+                .unwrap_err()
+                .contains("no version field")
+        );
+    }
+
+    #[test]
+    fn test_load_baseline_detects_format() {
+        let temp_dir = TempDir::new().unwrap();
+        let baseline_path = temp_dir.path().join("dokita-baseline.json");
+        let report_path = temp_dir.path().join("report.json");
+        let findings = vec![finding("CODE001", 3)];
+        Baseline::from_findings(&findings, root())
+            .save(&baseline_path)
+            .unwrap();
+        fs::write(
+            &report_path,
+            serde_json::to_string(&JsonReport::new(findings.clone())).unwrap(),
+        )
+        .unwrap();
+
+        let expected = Baseline::from_findings(&findings, root());
+        assert_eq!(
+            load_baseline(&baseline_path, BaselineFormat::Auto, root()).unwrap(),
+            expected
+        );
+        assert_eq!(
+            load_baseline(&report_path, BaselineFormat::Auto, root()).unwrap(),
+            expected
+        );
+        assert_eq!(
+            load_baseline(&report_path, BaselineFormat::Report, root()).unwrap(),
+            expected
+        );
+        // A report is not a versioned baseline
+        assert!(load_baseline(&report_path, BaselineFormat::Baseline, root()).is_err());
+    }
+}
@@ -1,7 +1,17 @@
 use cargo_dokita::MyError;
-use clap::{self, Arg, Command, command};
+use cargo_dokita::config::{BaselineFormat, ColorMode, Config, GroupBy};
+use clap::{self, Arg, ArgAction, ArgMatches, Command, command, value_parser};
 
 fn main() -> Result<(), MyError> {
+    #[cfg(debug_assertions)]
+    {
+        let duplicates = cargo_dokita::checks::duplicate_codes();
+        assert!(
+            duplicates.is_empty(),
+            "Check registry has duplicate codes: {duplicates:?}"
+        );
+    }
+
     let commands = command!()
         .subcommand(
             Command::new("dokita")
@@ -18,26 +28,401 @@ fn main() -> Result<(), MyError> {
                     Arg::new("FORMAT")
                         .short('f')
                         .long("format")
-                        .help("Results in either human readable or JSON format. human or json")
+                        .help("Results in human readable, JSON, JSON Lines, Markdown or compact tab-separated format. human, json, jsonl, markdown or compact")
                         .default_value("human")
                 )
+                .arg(
+                    Arg::new("offline")
+                        .long("offline")
+                        .help("Skip crates.io lookups for outdated dependencies")
+                        .action(ArgAction::SetTrue)
+                )
+                .arg(
+                    Arg::new("severity-threshold")
+                        .long("severity-threshold")
+                        .help("Only report findings at or above this severity")
+                        .value_parser(["error", "warning", "note"])
+                )
+                .arg(
+                    Arg::new("fail-on")
+                        .long("fail-on")
+                        .help("Minimum severity that makes the run exit non-zero (default: warning)")
+                        .value_parser(["error", "warning", "note"])
+                )
+                .arg(
+                    Arg::new("jobs")
+                        .short('j')
+                        .long("jobs")
+                        .help("Number of worker threads used for analysis")
+                        .value_parser(value_parser!(usize))
+                )
+                .arg(
+                    Arg::new("registry-token")
+                        .long("registry-token")
+                        .value_name("TOKEN")
+                        .help("Bearer token for a private registry_url (never sent to crates.io); also read from DOKITA_REGISTRY_TOKEN")
+                )
+                .arg(
+                    Arg::new("network-concurrency")
+                        .long("network-concurrency")
+                        .value_name("N")
+                        .help("Maximum number of crates.io lookups in flight at once (default: 8); independent of --jobs")
+                        .value_parser(value_parser!(usize))
+                )
+                .arg(
+                    Arg::new("ignore-path")
+                        .long("ignore-path")
+                        .help("Path, relative to the project root, to skip for this run; combined with configured excludes (repeatable)")
+                        .action(ArgAction::Append)
+                )
+                .arg(
+                    Arg::new("since")
+                        .long("since")
+                        .value_name("GIT_REF")
+                        .help("Only run code pattern checks on files changed since this git ref")
+                )
+                .arg(
+                    Arg::new("baseline")
+                        .long("baseline")
+                        .value_name("FILE")
+                        .help("Only report findings missing from this baseline file or earlier --format json report, plus a count of fixed ones")
+                )
+                .arg(
+                    Arg::new("baseline-format")
+                        .long("baseline-format")
+                        .help("How to read the --baseline file (default: auto, which detects it)")
+                        .value_parser(["auto", "baseline", "report"])
+                )
+                .arg(
+                    Arg::new("write-baseline")
+                        .long("write-baseline")
+                        .value_name("FILE")
+                        .help("Save this run's findings to FILE as a versioned baseline for --baseline")
+                )
+                .arg(
+                    Arg::new("input")
+                        .long("input")
+                        .value_name("FILE")
+                        .help("Re-render a saved --format json report in --format instead of analyzing the project")
+                )
+                .arg(
+                    Arg::new("group-by")
+                        .long("group-by")
+                        .help("Group human-readable output into sections")
+                        .value_parser(["none", "category", "file"])
+                )
+                .arg(
+                    Arg::new("collapse")
+                        .long("collapse")
+                        .help("Collapse repeated finding codes within a file into one line (human output only)")
+                        .action(ArgAction::SetTrue)
+                )
+                .arg(
+                    Arg::new("summary-by-check")
+                        .long("summary-by-check")
+                        .help("After the report, list every check with its number of findings, or why it did not run")
+                        .action(ArgAction::SetTrue)
+                )
+                .arg(
+                    Arg::new("max-findings")
+                        .long("max-findings")
+                        .value_name("N")
+                        .help("Report at most N findings, most severe first")
+                        .value_parser(value_parser!(usize))
+                )
+                .arg(
+                    Arg::new("deadline")
+                        .long("deadline")
+                        .value_name("SECS")
+                        .help("Stop the analysis after SECS seconds and report the findings so far with a TIMEOUT001 warning")
+                        .value_parser(value_parser!(u64))
+                )
+                .arg(
+                    Arg::new("no-vulnerability-scan")
+                        .long("no-vulnerability-scan")
+                        .help("Skip the cargo audit vulnerability scan (SEC001, AUD*)")
+                        .action(ArgAction::SetTrue)
+                )
+                .arg(
+                    Arg::new("fail-fast")
+                        .long("fail-fast")
+                        .help("Skip crates.io and cargo audit checks once a local check reports an error")
+                        .action(ArgAction::SetTrue)
+                )
+                .arg(
+                    Arg::new("no-cache")
+                        .long("no-cache")
+                        .help("Re-scan every file instead of reusing target/dokita-cache.json")
+                        .action(ArgAction::SetTrue)
+                )
+                .arg(
+                    Arg::new("print-schema")
+                        .long("print-schema")
+                        .help("Print the JSON Schema of the JSON output and exit")
+                        .action(ArgAction::SetTrue)
+                )
+                .arg(
+                    Arg::new("explain-all")
+                        .long("explain-all")
+                        .help("Print every check as Markdown (code, description, severity, fix) and exit")
+                        .action(ArgAction::SetTrue)
+                )
+                .arg(
+                    Arg::new("dependency-graph")
+                        .long("dependency-graph")
+                        .value_name("FORMAT")
+                        .help("Print the direct dependencies of workspace members with versions and outdated status (dot by default, or json) and exit")
+                        .value_parser(["dot", "json"])
+                        .num_args(0..=1)
+                        .default_missing_value("dot")
+                )
+                .arg(
+                    Arg::new("print-config")
+                        .long("print-config")
+                        .help("Print the effective merged configuration (TOML, or JSON with --format json) and exit")
+                        .action(ArgAction::SetTrue)
+                )
+                .arg(
+                    Arg::new("show-enabled")
+                        .long("show-enabled")
+                        .help("List every check with its enabled state and effective severity, then exit")
+                        .action(ArgAction::SetTrue)
+                )
+                .arg(
+                    Arg::new("color")
+                        .long("color")
+                        .help("When to colorize output (honors NO_COLOR and CLICOLOR_FORCE in auto mode)")
+                        .value_parser(["auto", "always", "never"])
+                )
+                .arg(
+                    Arg::new("exclude")
+                        .long("exclude")
+                        .help("Path, relative to the project root, to skip during code checks (repeatable)")
+                        .action(ArgAction::Append)
+                )
+                .subcommand(
+                    Command::new("init")
+                        .about("Writes a commented .cargo-dokita.toml listing every check into the project root")
+                        .arg(
+                            Arg::new("project-path")
+                                .short('p')
+                                .long("project-path")
+                                .help("The project root to write the configuration into")
+                                .default_value("./")
+                        )
+                        .arg(
+                            Arg::new("force")
+                                .long("force")
+                                .help("Overwrite an existing .cargo-dokita.toml")
+                                .action(ArgAction::SetTrue)
+                        )
+                )
+                .subcommand(
+                    Command::new("check")
+                        .about("Runs a single check against the project and prints only its findings")
+                        .arg(
+                            Arg::new("CODE")
+                                .help("The check code to run, e.g. MD001")
+                                .required(true)
+                        )
+                        .arg(
+                            Arg::new("project-path")
+                                .short('p')
+                                .long("project-path")
+                                .help("The Project Path you want to analyze")
+                                .default_value("./")
+                        )
+                        .arg(
+                            Arg::new("FORMAT")
+                                .short('f')
+                                .long("format")
+                                .help("Results in human readable, JSON, JSON Lines, Markdown or compact tab-separated format. human, json, jsonl, markdown or compact")
+                                .default_value("human")
+                        )
+                )
         )
         .get_matches();
 
     // Handle the dokita subcommand
     if let Some(matches) = commands.subcommand_matches("dokita") {
-        println!(
+        if let Some(init) = matches.subcommand_matches("init") {
+            let project_path = init.get_one::<String>("project-path").unwrap();
+            let path = cargo_dokita::config::write_init_file(
+                std::path::Path::new(project_path),
+                init.get_flag("force"),
+            )
+            .map_err(|e| {
+                eprintln!("Error: {e}");
+                MyError::InitFailed(e)
+            })?;
+            println!("Created {}", path.display());
+            return Ok(());
+        }
+
+        if let Some(check) = matches.subcommand_matches("check") {
+            let code = check.get_one::<String>("CODE").unwrap();
+            let project_path = check.get_one::<String>("project-path").unwrap();
+            let output_format = output_format(check);
+            return cargo_dokita::run_check(
+                project_path,
+                code,
+                &output_format,
+                cli_overrides(matches),
+            )
+            .inspect_err(|e| {
+                if let MyError::UnknownCheck(_) = e {
+                    eprintln!("Error: {e}. Valid codes are:");
+                    for check in cargo_dokita::checks::CHECKS {
+                        eprintln!("  {:<10} {}", check.code, check.description);
+                    }
+                }
+            });
+        }
+
+        if matches.get_flag("print-schema") {
+            let schema = cargo_dokita::diagnostics::json_schema();
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&schema).unwrap_or_default()
+            );
+            return Ok(());
+        }
+
+        if matches.get_flag("explain-all") {
+            print!("{}", cargo_dokita::checks::catalog_markdown());
+            return Ok(());
+        }
+
+        if matches.get_flag("print-config") {
+            let project_path = matches.get_one::<String>("project-path").unwrap();
+            return cargo_dokita::print_config(
+                project_path,
+                &output_format(matches),
+                cli_overrides(matches),
+            );
+        }
+
+        if let Some(input) = matches.get_one::<String>("input") {
+            let project_path = matches.get_one::<String>("project-path").unwrap();
+            return cargo_dokita::render_input(
+                project_path,
+                input,
+                &output_format(matches),
+                cli_overrides(matches),
+            );
+        }
+
+        if let Some(graph_format) = matches.get_one::<String>("dependency-graph") {
+            let project_path = matches.get_one::<String>("project-path").unwrap();
+            return cargo_dokita::print_dependency_graph(
+                project_path,
+                graph_format,
+                cli_overrides(matches),
+            );
+        }
+
+        if matches.get_flag("show-enabled") {
+            let project_path = matches.get_one::<String>("project-path").unwrap();
+            return cargo_dokita::show_enabled_checks(project_path, cli_overrides(matches));
+        }
+
+        eprintln!(
             "project path = {}",
             matches.get_one::<String>("project-path").unwrap()
         );
         let project_path = matches.get_one::<String>("project-path").unwrap();
-        let output_format = matches
-            .get_one::<String>("FORMAT")
-            .map(|s| s.to_ascii_lowercase())
-            .filter(|s| s == "json")
-            .unwrap_or_else(|| "human".to_string());
+        let output_format = output_format(matches);
 
-        cargo_dokita::analyze_project(project_path, &output_format)?;
+        cargo_dokita::analyze_project_with_overrides(
+            project_path,
+            &output_format,
+            cli_overrides(matches),
+        )?;
     }
     Ok(())
 }
+
+/// Normalizes `--format` to `json`, `jsonl`, `markdown` or `human` (the fallback for anything else).
+fn output_format(matches: &ArgMatches) -> String {
+    matches
+        .get_one::<String>("FORMAT")
+        .map(|s| s.to_ascii_lowercase())
+        .map(|s| match s.as_str() {
+            "json-lines" => "jsonl".to_string(),
+            "md" => "markdown".to_string(),
+            _ => s,
+        })
+        .filter(|s| matches!(s.as_str(), "json" | "jsonl" | "markdown" | "compact"))
+        .unwrap_or_else(|| "human".to_string())
+}
+
+/// Builds the configuration layer contributed by command-line flags.
+/// Only flags the user actually passed are set, so the project file still applies otherwise.
+fn cli_overrides(matches: &ArgMatches) -> Config {
+    let mut overrides = Config::default();
+
+    if matches.get_flag("offline") {
+        overrides.general.offline = Some(true);
+    }
+    if matches.get_flag("collapse") {
+        overrides.general.collapse = Some(true);
+    }
+    if matches.get_flag("summary-by-check") {
+        overrides.general.summary_by_check = Some(true);
+    }
+    if matches.get_flag("no-cache") {
+        overrides.general.cache = Some(false);
+    }
+    if matches.get_flag("no-vulnerability-scan") {
+        overrides.general.vulnerability_scan = Some(false);
+    }
+    if matches.get_flag("fail-fast") {
+        overrides.general.fail_fast = Some(true);
+    }
+    overrides.general.severity_threshold = matches
+        .get_one::<String>("severity-threshold")
+        .and_then(|level| level.parse().ok());
+    overrides.general.fail_on = matches
+        .get_one::<String>("fail-on")
+        .and_then(|level| level.parse().ok());
+    overrides.general.jobs = matches.get_one::<usize>("jobs").copied();
+    overrides.general.network_concurrency =
+        matches.get_one::<usize>("network-concurrency").copied();
+    overrides.general.max_findings = matches.get_one::<usize>("max-findings").copied();
+    overrides.general.deadline = matches.get_one::<u64>("deadline").copied();
+    overrides.general.exclude = matches
+        .get_many::<String>("exclude")
+        .map(|paths| paths.cloned().collect());
+    overrides.general.ignore_paths = matches
+        .get_many::<String>("ignore-path")
+        .map(|paths| paths.cloned().collect());
+    overrides.general.since = matches.get_one::<String>("since").cloned();
+    overrides.general.baseline = matches.get_one::<String>("baseline").cloned();
+    overrides.general.baseline_format =
+        matches
+            .get_one::<String>("baseline-format")
+            .map(|format| match format.as_str() {
+                "baseline" => BaselineFormat::Baseline,
+                "report" => BaselineFormat::Report,
+                _ => BaselineFormat::Auto,
+            });
+    overrides.general.write_baseline = matches.get_one::<String>("write-baseline").cloned();
+    overrides.general.registry_token = matches.get_one::<String>("registry-token").cloned();
+    overrides.general.group_by =
+        matches
+            .get_one::<String>("group-by")
+            .map(|group_by| match group_by.as_str() {
+                "category" => GroupBy::Category,
+                "file" => GroupBy::File,
+                _ => GroupBy::None,
+            });
+    overrides.general.color = matches
+        .get_one::<String>("color")
+        .map(|mode| match mode.as_str() {
+            "always" => ColorMode::Always,
+            "never" => ColorMode::Never,
+            _ => ColorMode::Auto,
+        });
+
+    overrides
+}
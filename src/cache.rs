@@ -0,0 +1,250 @@
+//! # Incremental Code-Check Cache
+//!
+//! Caches the results of [`check_code_patterns`] per file so repeated runs only re-scan
+//! files whose contents changed.
+//!
+//! ## Features
+//! - Stores per-file findings keyed by path and a hash of the file contents.
+//! - Persists to `target/dokita-cache.json` in the analyzed project.
+//! - Discards the whole cache when the tool version or any setting that affects the
+//!   code checks changes.
+//! - Manifest and dependency checks are not cached; they always run in full.
+//!
+//! The cache is used by default and bypassed with `--no-cache` (see [`Config::use_cache`]).
+
+use crate::code_checks::check_code_patterns;
+use crate::config::Config;
+use crate::diagnostics::Finding;
+use serde::{Deserialize, Serialize};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+
+/// Cache location, relative to the project root.
+pub const CACHE_FILE_PATH: &str = "target/dokita-cache.json";
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct FileCache {
+    pub tool_version: String,
+    /// Hash of the settings that influence code-check results.
+    pub settings_hash: u64,
+    /// Keyed by the file path as it appears in findings.
+    pub entries: HashMap<String, CacheEntry>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CacheEntry {
+    pub content_hash: u64,
+    pub findings: Vec<Finding>,
+}
+
+impl FileCache {
+    fn empty(settings_hash: u64) -> Self {
+        FileCache {
+            tool_version: env!("CARGO_PKG_VERSION").to_string(),
+            settings_hash,
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Loads the cache for `project_root`. A missing, unreadable or stale cache yields an empty one.
+    pub fn load(project_root: &Path, settings_hash: u64) -> Self {
+        fs::read_to_string(project_root.join(CACHE_FILE_PATH))
+            .ok()
+            .and_then(|content| serde_json::from_str::<FileCache>(&content).ok())
+            .filter(|cache| {
+                cache.tool_version == env!("CARGO_PKG_VERSION")
+                    && cache.settings_hash == settings_hash
+            })
+            .unwrap_or_else(|| FileCache::empty(settings_hash))
+    }
+
+    pub fn save(&self, project_root: &Path) -> Result<(), String> {
+        let cache_path = project_root.join(CACHE_FILE_PATH);
+        if let Some(parent) = cache_path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create cache directory {parent:?}: {e}"))?;
+        }
+        let content =
+            serde_json::to_string(self).map_err(|e| format!("Failed to serialize cache: {e}"))?;
+        fs::write(&cache_path, content)
+            .map_err(|e| format!("Failed to write cache file {cache_path:?}: {e}"))
+    }
+}
+
+/// Hash of the configuration that affects [`check_code_patterns`] output.
+fn settings_hash(config: &Config) -> u64 {
+    let mut enabled: Vec<(&String, &bool)> = config.checks.enabled.iter().collect();
+    enabled.sort();
+
+    let mut hasher = DefaultHasher::new();
+    enabled.hash(&mut hasher);
+    config.checks.ignore_prefixes.hash(&mut hasher);
+    config.discouraged_allows().hash(&mut hasher);
+    config.shadowed_prelude_names().hash(&mut hasher);
+    config.max_fn_params().hash(&mut hasher);
+    config.todo_issue_pattern().hash(&mut hasher);
+    hasher.finish()
+}
+
+fn content_hash(content: &[u8]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    content.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Same as [`check_code_patterns`], but reuses cached findings for unchanged files
+/// and only scans the rest. The cache is updated afterwards.
+pub fn check_code_patterns_cached(
+    rust_files: &[PathBuf],
+    project_root: &Path,
+    config: &Config,
+) -> Vec<Finding> {
+    let mut cache = FileCache::load(project_root, settings_hash(config));
+
+    let hashes: HashMap<String, u64> = rust_files
+        .iter()
+        .filter_map(|file| {
+            let hash = content_hash(&fs::read(file).ok()?);
+            Some((file.to_string_lossy().into_owned(), hash))
+        })
+        .collect();
+
+    let stale_files: Vec<PathBuf> = rust_files
+        .iter()
+        .filter(|file| {
+            let key = file.to_string_lossy();
+            match (cache.entries.get(key.as_ref()), hashes.get(key.as_ref())) {
+                (Some(entry), Some(hash)) => entry.content_hash != *hash,
+                _ => true,
+            }
+        })
+        .cloned()
+        .collect();
+
+    let mut fresh_findings: HashMap<String, Vec<Finding>> = HashMap::new();
+    for finding in check_code_patterns(&stale_files, project_root, config) {
+        let key = finding.file_path.clone().unwrap_or_default();
+        fresh_findings.entry(key).or_default().push(finding);
+    }
+
+    // Rebuild the cache from the current file list so deleted files drop out
+    let stale_set: HashSet<&PathBuf> = stale_files.iter().collect();
+    let mut entries = HashMap::new();
+    let mut findings = Vec::new();
+    for file in rust_files {
+        let key = file.to_string_lossy().into_owned();
+        let file_findings = match fresh_findings.remove(&key) {
+            Some(file_findings) => file_findings,
+            None if stale_set.contains(file) => Vec::new(),
+            None => cache
+                .entries
+                .remove(&key)
+                .map(|entry| entry.findings)
+                .unwrap_or_default(),
+        };
+        // Files that could not be read have no hash and are re-scanned next time
+        if let Some(hash) = hashes.get(&key) {
+            entries.insert(
+                key,
+                CacheEntry {
+                    content_hash: *hash,
+                    findings: file_findings.clone(),
+                },
+            );
+        }
+        findings.extend(file_findings);
+    }
+    cache.entries = entries;
+
+    if let Err(e) = cache.save(project_root) {
+        eprintln!("Warning: {e}");
+    }
+
+    findings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn setup_project() -> (TempDir, PathBuf) {
+        let temp_dir = TempDir::new().unwrap();
+        let src_dir = temp_dir.path().join("src");
+        fs::create_dir_all(&src_dir).unwrap();
+        let module = src_dir.join("module.rs");
+        fs::write(&module, "pub fn f() {\n    thing().unwrap();\n}\n").unwrap();
+        (temp_dir, module)
+    }
+
+    #[test]
+    fn test_cached_run_matches_uncached_run() {
+        let (temp_dir, module) = setup_project();
+        let project_root = temp_dir.path();
+        let rust_files = vec![module];
+        let config = Config::default();
+
+        let uncached = check_code_patterns(&rust_files, project_root, &config);
+        let first = check_code_patterns_cached(&rust_files, project_root, &config);
+        assert!(project_root.join(CACHE_FILE_PATH).is_file());
+        let second = check_code_patterns_cached(&rust_files, project_root, &config);
+
+        let codes = |f: &[Finding]| f.iter().map(|f| f.code.clone()).collect::<Vec<_>>();
+        assert_eq!(codes(&uncached), codes(&first));
+        assert_eq!(codes(&first), codes(&second));
+        assert_eq!(second[0].line_number, Some(2));
+    }
+
+    #[test]
+    fn test_cache_reuses_unchanged_and_rescans_changed_files() {
+        let (temp_dir, module) = setup_project();
+        let project_root = temp_dir.path();
+        let rust_files = vec![module.clone()];
+        let config = Config::default();
+
+        check_code_patterns_cached(&rust_files, project_root, &config);
+
+        // Tamper with the cached findings: an unchanged file must return them as-is
+        let mut cache = FileCache::load(project_root, settings_hash(&config));
+        let key = module.to_string_lossy().into_owned();
+        cache.entries.get_mut(&key).unwrap().findings.clear();
+        cache.save(project_root).unwrap();
+        assert!(check_code_patterns_cached(&rust_files, project_root, &config).is_empty());
+
+        // Changing the file invalidates its entry
+        fs::write(
+            &module,
+            "pub fn f() {\n    thing().unwrap();\n    other().unwrap();\n}\n",
+        )
+        .unwrap();
+        let findings = check_code_patterns_cached(&rust_files, project_root, &config);
+        assert_eq!(findings.iter().filter(|f| f.code == "CODE001").count(), 2);
+    }
+
+    #[test]
+    fn test_cache_invalidated_by_settings_change() {
+        let (temp_dir, _module) = setup_project();
+        let project_root = temp_dir.path();
+
+        let mut config = Config::default();
+        FileCache::empty(settings_hash(&config))
+            .save(project_root)
+            .unwrap();
+        config.checks.enabled.insert("CODE018".to_string(), false);
+
+        let cache = FileCache::load(project_root, settings_hash(&config));
+        assert_eq!(cache.settings_hash, settings_hash(&config));
+        assert!(cache.entries.is_empty());
+        assert_ne!(settings_hash(&config), settings_hash(&Config::default()));
+
+        let mut pattern_config = Config::default();
+        pattern_config.checks.todo_issue_pattern = Some(r"\(#\d+\)".to_string());
+        assert_ne!(
+            settings_hash(&pattern_config),
+            settings_hash(&Config::default())
+        );
+    }
+}
@@ -0,0 +1,907 @@
+//! # Check Registry
+//!
+//! Central list of every finding code Cargo Dokita can emit, together with its category,
+//! default severity, a short description and the suggested fix.
+//!
+//! Checks still decide their own message and severity when they push a [`Finding`]; the
+//! registry is the single source of truth for metadata that must stay consistent across
+//! checks, such as the [`Category`] attached to every finding.
+//!
+//! [`Finding`]: crate::diagnostics::Finding
+
+use crate::config::Config;
+use crate::diagnostics::{Category, Finding, Severity};
+use std::collections::HashMap;
+
+/// Static description of a single check.
+#[derive(Debug, Clone)]
+pub struct CheckInfo {
+    pub code: &'static str,
+    pub category: Category,
+    pub severity: Severity,
+    pub description: &'static str,
+    pub fix: &'static str,
+    /// Opt-in checks only run when enabled in `[checks] enabled`.
+    pub enabled_by_default: bool,
+}
+
+impl CheckInfo {
+    /// Marks the check as opt-in (disabled unless explicitly enabled).
+    const fn opt_in(self) -> Self {
+        CheckInfo {
+            enabled_by_default: false,
+            ..self
+        }
+    }
+}
+
+const fn check(
+    code: &'static str,
+    category: Category,
+    severity: Severity,
+    description: &'static str,
+    fix: &'static str,
+) -> CheckInfo {
+    CheckInfo {
+        code,
+        category,
+        severity,
+        description,
+        fix,
+        enabled_by_default: true,
+    }
+}
+
+/// Every known check, grouped by category.
+pub static CHECKS: &[CheckInfo] = &[
+    // Metadata
+    check(
+        "MD001",
+        Category::Metadata,
+        Severity::Warning,
+        "Missing 'description' field in Cargo.toml",
+        "Add a clear description of your package's purpose",
+    ),
+    check(
+        "MD002",
+        Category::Metadata,
+        Severity::Warning,
+        "Missing 'license' field in Cargo.toml",
+        "Specify a license (e.g., \"MIT\", \"Apache-2.0\")",
+    ),
+    check(
+        "MD003",
+        Category::Metadata,
+        Severity::Note,
+        "Missing 'repository' field in Cargo.toml",
+        "Add your repository URL for better discoverability",
+    ),
+    check(
+        "MD004",
+        Category::Metadata,
+        Severity::Note,
+        "Missing or invalid 'readme' field",
+        "Add a README file or set `readme = false` if intentional",
+    ),
+    check(
+        "MD005",
+        Category::Metadata,
+        Severity::Error,
+        "Missing [package] section in Cargo.toml",
+        "Add a proper [package] section with name and version",
+    ),
+    check(
+        "MD016",
+        Category::Metadata,
+        Severity::Note,
+        "Both `license` and `license-file` are set",
+        "Keep only one; prefer an SPDX `license` expression",
+    ),
+    check(
+        "MD017",
+        Category::Metadata,
+        Severity::Error,
+        "Package `version` is not valid semver",
+        "Use a MAJOR.MINOR.PATCH version such as `1.0.0`",
+    ),
+    check(
+        "MD018",
+        Category::Metadata,
+        Severity::Note,
+        "Package `version` is pre-1.0 (`0.x`) (opt-in)",
+        "Remember that a 0.x minor bump is a breaking change",
+    )
+    .opt_in(),
+    check(
+        "MD019",
+        Category::Metadata,
+        Severity::Warning,
+        "`categories` contains a slug crates.io does not know",
+        "Use slugs from https://crates.io/category_slugs",
+    ),
+    check(
+        "MD020",
+        Category::Metadata,
+        Severity::Warning,
+        "Package `name` breaks the crates.io naming rules",
+        "Use ASCII letters, digits, `-` and `_`, starting with a letter (max 64 chars)",
+    ),
+    check(
+        "MD015",
+        Category::Metadata,
+        Severity::Note,
+        "Dependency table is not sorted alphabetically (opt-in)",
+        "Sort the entries, e.g. with `cargo sort`",
+    )
+    .opt_in(),
+    // Dependencies
+    check(
+        "DP001",
+        Category::Dependency,
+        Severity::Warning,
+        "Wildcard version \"*\" used in dependencies",
+        "Specify explicit version ranges (e.g., \"1.0\")",
+    ),
+    check(
+        "DP002",
+        Category::Dependency,
+        Severity::Note,
+        "Outdated dependency detected",
+        "Update to the latest version available on crates.io",
+    ),
+    check(
+        "DP012",
+        Category::Dependency,
+        Severity::Warning,
+        "Deprecated `[replace]` section used",
+        "Move the overrides to `[patch]`",
+    ),
+    check(
+        "DP013",
+        Category::Dependency,
+        Severity::Note,
+        "`[patch]` entry points at a local path",
+        "Make sure the override is not meant to be published",
+    ),
+    check(
+        "DP014",
+        Category::Dependency,
+        Severity::Note,
+        "`default-features = false` without any `features`",
+        "Confirm the minimal feature set is intended or list the needed features",
+    ),
+    check(
+        "DP015",
+        Category::Dependency,
+        Severity::Note,
+        "Binary with caret-range dependencies has no `Cargo.lock` (opt-in)",
+        "Commit `Cargo.lock` or pin exact versions with `=`",
+    )
+    .opt_in(),
+    check(
+        "DP016",
+        Category::Dependency,
+        Severity::Note,
+        "Two dependencies serve the same purpose (opt-in)",
+        "Standardize on one of the crates",
+    )
+    .opt_in(),
+    check(
+        "DP017",
+        Category::Dependency,
+        Severity::Note,
+        "Dependency is overridden in `[patch.crates-io]`; outdated check skipped",
+        "Compare the local override against crates.io manually",
+    ),
+    check(
+        "DP018",
+        Category::Dependency,
+        Severity::Warning,
+        "Every published version of a dependency is yanked",
+        "Migrate to a maintained alternative",
+    ),
+    check(
+        "DP019",
+        Category::Dependency,
+        Severity::Note,
+        "Test or benchmark crate listed in `[dependencies]`",
+        "Move it to `[dev-dependencies]`",
+    ),
+    check(
+        "DP020",
+        Category::Dependency,
+        Severity::Note,
+        "Dependency not found on crates.io",
+        "Fix the crate name or remove the stale entry",
+    ),
+    check(
+        "API001",
+        Category::Dependency,
+        Severity::Warning,
+        "Failed to fetch latest version from crates.io",
+        "Check network connection; may be temporary",
+    ),
+    check(
+        "FT001",
+        Category::Dependency,
+        Severity::Warning,
+        "Feature enables a dependency that is not declared",
+        "Declare the dependency or fix the feature entry",
+    ),
+    check(
+        "FT002",
+        Category::Dependency,
+        Severity::Note,
+        "Optional dependency not referenced by any feature",
+        "Enable it from a feature (e.g. `\"dep:name\"`) or make it non-optional",
+    ),
+    // Code quality
+    check(
+        "CODE001",
+        Category::CodeQuality,
+        Severity::Warning,
+        "`.unwrap()` used in library context",
+        "Use `?` operator or proper error handling",
+    ),
+    check(
+        "CODE002",
+        Category::CodeQuality,
+        Severity::Note,
+        "`.expect()` used in library context",
+        "Prefer `?` operator or specific error handling",
+    ),
+    check(
+        "CODE003",
+        Category::CodeQuality,
+        Severity::Note,
+        "Print/debug macros in library code",
+        "Remove debug output before release",
+    ),
+    check(
+        "CODE004",
+        Category::CodeQuality,
+        Severity::Note,
+        "TODO/FIXME/XXX comments found",
+        "Address or create issues for outstanding work",
+    ),
+    check(
+        "CODE017",
+        Category::CodeQuality,
+        Severity::Note,
+        "Deprecated `try!` macro used in crate source",
+        "Replace `try!(expr)` with `expr?`",
+    ),
+    check(
+        "CODE018",
+        Category::CodeQuality,
+        Severity::Note,
+        "Blanket `allow` of an important lint",
+        "Use targeted allows on the items that need it",
+    ),
+    check(
+        "CODE019",
+        Category::CodeQuality,
+        Severity::Note,
+        "`async`/`.await` used without a runtime dependency",
+        "Add a runtime such as `tokio` or `async-std`",
+    ),
+    check(
+        "CODE021",
+        Category::CodeQuality,
+        Severity::Note,
+        "Top-level item shadows a std prelude name",
+        "Rename it (e.g. `MyResult`) or re-export it under a distinct name",
+    ),
+    check(
+        "CODE022",
+        Category::CodeQuality,
+        Severity::Warning,
+        "`std::` used in a `#![no_std]` crate",
+        "Use `core::`/`alloc::` paths or add `extern crate std`",
+    ),
+    check(
+        "CODE023",
+        Category::CodeQuality,
+        Severity::Note,
+        "`debug_assert!` argument calls a function",
+        "Move the call out of the assertion so it also runs in release builds",
+    ),
+    check(
+        "CODE024",
+        Category::CodeQuality,
+        Severity::Note,
+        "Public function returns `Result<_, String>` or `Box<dyn Error>` (opt-in)",
+        "Return a dedicated error type",
+    )
+    .opt_in(),
+    check(
+        "CODE025",
+        Category::CodeQuality,
+        Severity::Warning,
+        "Multiple `.unwrap()` calls chained on one line",
+        "Propagate errors with `?` or handle each step",
+    ),
+    check(
+        "CODE026",
+        Category::CodeQuality,
+        Severity::Note,
+        "Function takes more parameters than `max_fn_params`",
+        "Group related parameters into a struct",
+    ),
+    check(
+        "CODE027",
+        Category::CodeQuality,
+        Severity::Note,
+        "`.unwrap_err()`/`.expect_err()` used in library context",
+        "Match on both `Ok` and `Err` explicitly",
+    ),
+    check(
+        "CODE028",
+        Category::CodeQuality,
+        Severity::Note,
+        "`HashMap::new()`/`HashSet::new()` with the default hasher (opt-in)",
+        "Use a faster hasher such as ahash or rustc-hash in hot paths",
+    )
+    .opt_in(),
+    check(
+        "CODE029",
+        Category::CodeQuality,
+        Severity::Note,
+        "Glob `pub use ...::*;` re-export in the crate root (opt-in)",
+        "Re-export the intended items explicitly",
+    )
+    .opt_in(),
+    check(
+        "CODE030",
+        Category::CodeQuality,
+        Severity::Warning,
+        "`#[tokio::main]`/`#[async_std::main]` without the runtime in `[dependencies]`",
+        "Add the runtime crate to `[dependencies]`",
+    ),
+    check(
+        "CODE031",
+        Category::CodeQuality,
+        Severity::Note,
+        "Crate-level `allow(clippy::all)`/`allow(clippy::pedantic)`",
+        "Allow specific clippy lints on the items that need them",
+    ),
+    check(
+        "CODE032",
+        Category::CodeQuality,
+        Severity::Note,
+        "`println!`/`eprintln!` in a crate that depends on `log` or `tracing` (opt-in)",
+        "Use the logging crate's macros",
+    )
+    .opt_in(),
+    check(
+        "CODE033",
+        Category::CodeQuality,
+        Severity::Note,
+        "`format!` called with a plain string literal and nothing to interpolate",
+        "Use `\"...\".to_string()` or `String::from`",
+    ),
+    check(
+        "CODE034",
+        Category::CodeQuality,
+        Severity::Note,
+        "Type derives `Hash` but implements `PartialEq` by hand, or vice versa (opt-in)",
+        "Derive both traits or implement both by hand",
+    )
+    .opt_in(),
+    check(
+        "CODE035",
+        Category::CodeQuality,
+        Severity::Note,
+        "TODO/FIXME comment without an issue reference (opt-in)",
+        "Link the comment to a tracking issue, e.g. `TODO(#123)`",
+    )
+    .opt_in(),
+    check(
+        "CODE036",
+        Category::CodeQuality,
+        Severity::Note,
+        "Source file uses CRLF line endings while most files use LF (opt-in)",
+        "Convert the file to LF or pin line endings in `.gitattributes`",
+    )
+    .opt_in(),
+    check(
+        "CODE038",
+        Category::CodeQuality,
+        Severity::Warning,
+        "Manual `unsafe impl Send` or `unsafe impl Sync`",
+        "Document the invariants that make the impl sound in a `// SAFETY:` comment",
+    ),
+    check(
+        "CODE039",
+        Category::CodeQuality,
+        Severity::Note,
+        "Library crate has no crate-level `//!` documentation (opt-in)",
+        "Start src/lib.rs with a `//!` comment describing the crate",
+    )
+    .opt_in(),
+    check(
+        "CODE040",
+        Category::CodeQuality,
+        Severity::Note,
+        "Type is both `Copy` and `Drop` (opt-in)",
+        "Remove the `Copy` derive or the `Drop` impl",
+    )
+    .opt_in(),
+    check(
+        "CODE041",
+        Category::CodeQuality,
+        Severity::Note,
+        "`pub fn` calls `.unwrap()` near the start of its body (opt-in)",
+        "Propagate with `?` or return an `Option`/`Result`",
+    )
+    .opt_in(),
+    check(
+        "CODE042",
+        Category::CodeQuality,
+        Severity::Note,
+        "`#[test]` function name defined in more than one place (opt-in)",
+        "Give each test a distinct name",
+    )
+    .opt_in(),
+    // Security and audit
+    check(
+        "SEC001",
+        Category::Security,
+        Severity::Error,
+        "Known security vulnerability in dependency",
+        "Update to patched version or find alternative",
+    ),
+    check(
+        "AUD001",
+        Category::Audit,
+        Severity::Warning,
+        "cargo-audit execution failed",
+        "Install cargo-audit: `cargo install cargo-audit`",
+    ),
+    check(
+        "AUD002",
+        Category::Audit,
+        Severity::Warning,
+        "cargo-audit reported issues",
+        "Review audit output and address findings",
+    ),
+    check(
+        "AUD003",
+        Category::Audit,
+        Severity::Warning,
+        "Failed to parse cargo-audit output",
+        "Check cargo-audit installation and output format",
+    ),
+    check(
+        "AUD004",
+        Category::Audit,
+        Severity::Warning,
+        "cargo-audit not found in PATH",
+        "Install cargo-audit tool",
+    ),
+    // Project structure
+    check(
+        "STRUCT001",
+        Category::Structure,
+        Severity::Warning,
+        "Missing main source files (lib.rs/main.rs/bin/)",
+        "Add proper source files or check project structure",
+    ),
+    check(
+        "STRUCT002",
+        Category::Structure,
+        Severity::Note,
+        "Missing README.md file",
+        "Create a README.md file documenting your project",
+    ),
+    check(
+        "STRUCT003",
+        Category::Structure,
+        Severity::Warning,
+        "Missing LICENSE file",
+        "Add a LICENSE file (LICENSE, LICENSE-MIT, etc.)",
+    ),
+    check(
+        "STRUCT007",
+        Category::Structure,
+        Severity::Warning,
+        "Binary entry file has no `fn main`",
+        "Add `fn main` to src/main.rs or the `[[bin]]` path",
+    ),
+    check(
+        "STRUCT008",
+        Category::Structure,
+        Severity::Note,
+        "build.rs uses a build crate missing from `[build-dependencies]`",
+        "Add the crate (e.g. `cc`) under `[build-dependencies]`",
+    ),
+    check(
+        "STRUCT009",
+        Category::Structure,
+        Severity::Note,
+        "Crate directory missing from `[workspace] members`",
+        "Add it to `members` or `exclude`",
+    ),
+    check(
+        "STRUCT010",
+        Category::Structure,
+        Severity::Note,
+        "Integration test file contains no `#[test]` functions",
+        "Add tests or move shared helpers into tests/common/mod.rs",
+    ),
+    check(
+        "STRUCT011",
+        Category::Structure,
+        Severity::Note,
+        "build.rs reads files but emits no `cargo:rerun-if-changed` (opt-in)",
+        "Print `cargo:rerun-if-changed=<path>` for each input file",
+    )
+    .opt_in(),
+    check(
+        "STRUCT012",
+        Category::Structure,
+        Severity::Warning,
+        "Two binary targets share a name",
+        "Rename one of the `[[bin]]` targets or `src/bin` files",
+    ),
+    check(
+        "STRUCT013",
+        Category::Structure,
+        Severity::Note,
+        "Large non-Rust file under `src/`",
+        "Move the file out of `src/` or exclude it from the package",
+    ),
+    // Lint configuration
+    check(
+        "LINT001",
+        Category::Lint,
+        Severity::Note,
+        "Missing recommended lint denials",
+        "Add `#![deny(warnings)]` to src/lib.rs or src/main.rs",
+    ),
+    check(
+        "LINT002",
+        Category::Lint,
+        Severity::Note,
+        "Crate never uses `unsafe` but does not forbid it",
+        "Add `#![forbid(unsafe_code)]` to the crate root",
+    ),
+    // Edition
+    check(
+        "ED001",
+        Category::Edition,
+        Severity::Note,
+        "Project uses an older Rust edition",
+        "Update `edition` to the latest stable edition",
+    ),
+    check(
+        "ED002",
+        Category::Edition,
+        Severity::Note,
+        "No Rust edition specified (implicitly 2015)",
+        "Set `edition` explicitly in Cargo.toml",
+    ),
+    check(
+        "ED005",
+        Category::Edition,
+        Severity::Warning,
+        "`edition` is newer than `rust-version` supports",
+        "Raise `rust-version` or use an older edition",
+    ),
+    check(
+        "ED006",
+        Category::Edition,
+        Severity::Note,
+        "Toolchain pinned to nightly without any `#![feature]`",
+        "Pin a stable toolchain in rust-toolchain.toml",
+    ),
+    check(
+        "ED007",
+        Category::Edition,
+        Severity::Note,
+        "Toolchain pinned to a stable release far behind the latest",
+        "Update the channel in rust-toolchain.toml",
+    ),
+    check(
+        "ED008",
+        Category::Edition,
+        Severity::Note,
+        "Workspace members use different editions",
+        "Move every member to one edition, e.g. via `[workspace.package] edition`",
+    ),
+    check(
+        "ED009",
+        Category::Edition,
+        Severity::Note,
+        "Published library crate does not declare `rust-version`",
+        "Set `rust-version` to the oldest Rust release you support",
+    ),
+    // I/O
+    check(
+        "IO001",
+        Category::Io,
+        Severity::Warning,
+        "File read error during analysis",
+        "Check file permissions and existence",
+    ),
+    check(
+        "TIMEOUT001",
+        Category::Io,
+        Severity::Warning,
+        "Analysis stopped at the `--deadline`; findings are incomplete",
+        "Raise the deadline, exclude large directories or run with `--offline`",
+    ),
+];
+
+/// Looks up a check by its code.
+pub fn lookup(code: &str) -> Option<&'static CheckInfo> {
+    CHECKS.iter().find(|check| check.code == code)
+}
+
+/// Codes registered more than once, in registry order. Must always be empty; checked
+/// by a unit test and, in debug builds, when the binary starts.
+pub fn duplicate_codes() -> Vec<&'static str> {
+    let mut seen = std::collections::HashSet::new();
+    CHECKS
+        .iter()
+        .map(|check| check.code)
+        .filter(|code| !seen.insert(*code))
+        .collect()
+}
+
+/// Whether `code` runs when the configuration says nothing about it.
+/// Unknown codes are enabled.
+pub fn is_enabled_by_default(code: &str) -> bool {
+    lookup(code).is_none_or(|check| check.enabled_by_default)
+}
+
+/// Category for `code`. Codes missing from the registry fall back to their prefix.
+pub fn category_for(code: &str) -> Category {
+    if let Some(check) = lookup(code) {
+        return check.category;
+    }
+    let prefix = code.trim_end_matches(|c: char| c.is_ascii_digit());
+    match prefix {
+        "MD" => Category::Metadata,
+        "DP" | "API" | "FT" => Category::Dependency,
+        "STRUCT" => Category::Structure,
+        "SEC" => Category::Security,
+        "AUD" => Category::Audit,
+        "LINT" => Category::Lint,
+        "ED" => Category::Edition,
+        "IO" | "TIMEOUT" => Category::Io,
+        _ => Category::CodeQuality,
+    }
+}
+
+/// The whole registry as Markdown, one section per check in registry order
+/// (`--explain-all`). Meant to be pasted into project documentation.
+pub fn catalog_markdown() -> String {
+    let mut markdown = String::from("# Cargo Dokita checks\n");
+    for check in CHECKS {
+        markdown.push_str(&format!(
+            "\n## {}\n\n{}\n\n- **Category:** {}\n- **Severity:** {:?}\n- **Enabled by default:** {}\n- **Fix:** {}\n",
+            check.code,
+            check.description,
+            check.category.label(),
+            check.severity,
+            if check.enabled_by_default { "yes" } else { "no (opt-in)" },
+            check.fix
+        ));
+    }
+    markdown
+}
+
+/// Whether a check runs under a configuration, and why.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckState {
+    /// Turned on in `[checks] enabled`.
+    EnabledByConfig,
+    /// Turned off in `[checks] enabled`.
+    DisabledByConfig,
+    /// Matched by `[checks] ignore_prefixes`.
+    IgnoredByPrefix,
+    EnabledByDefault,
+    /// Opt-in check that the configuration does not mention.
+    DisabledByDefault,
+}
+
+impl CheckState {
+    /// Resolves the state of `code` the same way [`Config::is_check_enabled`] does.
+    pub fn resolve(code: &str, config: &Config) -> Self {
+        match config.checks.enabled.get(code) {
+            Some(true) => CheckState::EnabledByConfig,
+            Some(false) => CheckState::DisabledByConfig,
+            None if config.is_ignored_by_prefix(code) => CheckState::IgnoredByPrefix,
+            None if is_enabled_by_default(code) => CheckState::EnabledByDefault,
+            None => CheckState::DisabledByDefault,
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        matches!(
+            self,
+            CheckState::EnabledByConfig | CheckState::EnabledByDefault
+        )
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            CheckState::EnabledByConfig => "enabled (config)",
+            CheckState::DisabledByConfig => "disabled (config)",
+            CheckState::IgnoredByPrefix => "disabled (prefix)",
+            CheckState::EnabledByDefault => "enabled (default)",
+            CheckState::DisabledByDefault => "disabled (opt-in)",
+        }
+    }
+}
+
+/// Severity `check` reports with under `config`, taking severity settings such as
+/// `outdated_severity` into account.
+pub fn effective_severity(check: &CheckInfo, config: &Config) -> Severity {
+    match check.code {
+        "DP002" => config.outdated_severity(),
+        _ => check.severity.clone(),
+    }
+}
+
+/// Checks of the crates.io lookups, which need the network. `cargo dokita check` keeps
+/// the network on only for these, so every check reported by the lookups belongs here.
+pub const OUTDATED_CHECK_CODES: &[&str] = &["DP002", "DP017", "DP018", "DP020", "API001"];
+
+/// Whether `code` belongs to the network and audit phase, which runs after every local check.
+fn is_network_phase_check(code: &str) -> bool {
+    OUTDATED_CHECK_CODES.contains(&code) || code.starts_with("SEC") || code.starts_with("AUD")
+}
+
+/// Whether `fail_fast` stops the analysis before the network and audit phase: a local
+/// check of an enabled code reported an error.
+pub fn stops_before_network_phase(local_findings: &[Finding], config: &Config) -> bool {
+    config.fail_fast()
+        && local_findings
+            .iter()
+            .any(|f| f.severity == Severity::Error && config.is_check_enabled(&f.code))
+}
+
+/// Enabled checks that did not run in the analysis that produced `findings`, with the
+/// reason, e.g. `"skipped (offline)"`. After a TIMEOUT001 every check without findings
+/// is listed, since the phase it belongs to may not have finished.
+pub fn skipped_checks(
+    findings: &[Finding],
+    config: &Config,
+) -> HashMap<&'static str, &'static str> {
+    let timed_out = findings.iter().any(|f| f.code == "TIMEOUT001");
+    let stopped_early = stops_before_network_phase(findings, config);
+    CHECKS
+        .iter()
+        .filter(|check| config.is_check_enabled(check.code))
+        .filter_map(|check| {
+            let reason = if timed_out && !findings.iter().any(|f| f.code == check.code) {
+                "skipped (deadline)"
+            } else if !is_network_phase_check(check.code) {
+                return None;
+            } else if stopped_early {
+                "skipped (fail-fast)"
+            } else if OUTDATED_CHECK_CODES.contains(&check.code) && config.is_offline() {
+                "skipped (offline)"
+            } else if !OUTDATED_CHECK_CODES.contains(&check.code) && !config.vulnerability_scan() {
+                "skipped (vulnerability_scan off)"
+            } else {
+                return None;
+            };
+            Some((check.code, reason))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lookup_known_and_unknown_codes() {
+        let check = lookup("STRUCT007").unwrap();
+        assert_eq!(check.category, Category::Structure);
+        assert_eq!(check.severity, Severity::Warning);
+        assert!(lookup("NOPE001").is_none());
+    }
+
+    #[test]
+    fn test_outdated_check_codes_are_registered() {
+        for code in OUTDATED_CHECK_CODES {
+            assert_eq!(lookup(code).unwrap().category, Category::Dependency);
+        }
+    }
+
+    #[test]
+    fn test_catalog_markdown_lists_every_check() {
+        let markdown = catalog_markdown();
+        assert!(markdown.starts_with("# Cargo Dokita checks\n"));
+        assert!(markdown.contains("\n## STRUCT007\n\nBinary entry file has no `fn main`\n"));
+        assert!(markdown.contains("- **Severity:** Warning"));
+        assert!(markdown.contains("- **Enabled by default:** no (opt-in)"));
+        assert_eq!(markdown.matches("\n## ").count(), CHECKS.len());
+    }
+
+    #[test]
+    fn test_registry_codes_are_unique() {
+        let mut seen = std::collections::HashSet::new();
+        for check in CHECKS {
+            assert!(
+                seen.insert(check.code),
+                "duplicate check code {}",
+                check.code
+            );
+        }
+        assert!(duplicate_codes().is_empty());
+    }
+
+    #[test]
+    fn test_registry_categories_match_code_prefixes() {
+        for check in CHECKS {
+            let prefix = check.code.trim_end_matches(|c: char| c.is_ascii_digit());
+            let expected = match prefix {
+                "MD" => Category::Metadata,
+                "DP" | "API" | "FT" => Category::Dependency,
+                "CODE" => Category::CodeQuality,
+                "STRUCT" => Category::Structure,
+                "SEC" => Category::Security,
+                "AUD" => Category::Audit,
+                "LINT" => Category::Lint,
+                "ED" => Category::Edition,
+                "IO" | "TIMEOUT" => Category::Io,
+                other => panic!("unexpected prefix {other} for {}", check.code),
+            };
+            assert_eq!(check.category, expected, "{}", check.code);
+        }
+    }
+
+    #[test]
+    fn test_opt_in_checks_are_disabled_by_default() {
+        assert!(!is_enabled_by_default("MD015"));
+        assert!(is_enabled_by_default("MD001"));
+        assert!(is_enabled_by_default("NOPE001"));
+    }
+
+    #[test]
+    fn test_category_for_falls_back_to_prefix() {
+        assert_eq!(category_for("MD999"), Category::Metadata);
+        assert_eq!(category_for("DP999"), Category::Dependency);
+    }
+
+    #[test]
+    fn test_check_state_distinguishes_config_and_defaults() {
+        let mut config = Config::default();
+        config.checks.enabled.insert("MD001".to_string(), false);
+        config.checks.enabled.insert("MD015".to_string(), true);
+
+        let state = |code| CheckState::resolve(code, &config);
+        assert_eq!(state("MD001"), CheckState::DisabledByConfig);
+        assert_eq!(state("MD015"), CheckState::EnabledByConfig);
+        assert_eq!(state("MD002"), CheckState::EnabledByDefault);
+        assert_eq!(
+            CheckState::resolve("MD015", &Config::default()),
+            CheckState::DisabledByDefault
+        );
+        for check in CHECKS {
+            assert_eq!(
+                state(check.code).is_enabled(),
+                config.is_check_enabled(check.code)
+            );
+        }
+    }
+
+    #[test]
+    fn test_effective_severity_honors_outdated_severity() {
+        let mut config = Config::default();
+        config.checks.outdated_severity = Some(Severity::Warning);
+        assert_eq!(
+            effective_severity(lookup("DP002").unwrap(), &config),
+            Severity::Warning
+        );
+        assert_eq!(
+            effective_severity(lookup("MD003").unwrap(), &config),
+            Severity::Note
+        );
+    }
+}
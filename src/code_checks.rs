@@ -6,9 +6,12 @@
 //! # Features
 //!
 //! ## Code Pattern Analysis
-//! - Detects potentially problematic patterns like `.unwrap()` and `.expect()` in library code
-//! - Identifies debug macros (`println!`, `dbg!`) that should be removed before release
+//! - Detects potentially problematic patterns like `.unwrap()`, `.expect()` and `.unwrap_err()` in library code
+//! - Identifies print and debug macros (`print!`, `println!`, `eprint!`, `eprintln!`, `dbg!`) that should be removed before release
 //! - Finds TODO/FIXME/XXX comments that need attention
+//! - Flags blanket `allow` attributes (e.g. `#![allow(warnings)]`) that hide real problems
+//! - Optionally flags public functions returning `Result<_, String>` or `Box<dyn Error>`
+//! - Flags functions with long parameter lists (configurable limit)
 //! - Supports parallel processing for improved performance on large codebases
 //!
 //! ## Project Structure Validation
@@ -18,6 +21,10 @@
 //!
 //! ## Lint Configuration Checks
 //! - Verifies presence of recommended `#![deny(...)]` attributes
+//! - Suggests `#![forbid(unsafe_code)]` for crates that never use `unsafe`
+//! - Flags async code in crates that declare no async runtime dependency
+//! - Flags `#[tokio::main]`/`#[async_std::main]` without the matching dependency
+//! - Flags `std::` paths in `#![no_std]` crates
 //! - Configurable through the project's configuration system
 //!
 //! # Usage
@@ -25,10 +32,11 @@
 //! ```rust,no_run
 //! use std::path::Path;
 //! use cargo_dokita::code_checks::{collect_rust_files, check_code_patterns, check_project_structure};
+//! use cargo_dokita::config::Config;
 //!
 //! let project_root = Path::new("./my_project");
 //! let rust_files = collect_rust_files(project_root);
-//! let findings = check_code_patterns(&rust_files, project_root);
+//! let findings = check_code_patterns(&rust_files, project_root, &Config::default());
 //!
 //! for finding in findings {
 //!     println!("{}: {}", finding.code, finding.message);
@@ -38,41 +46,156 @@
 //! The module is designed to integrate seamlessly with cargo-dokita's diagnostic system
 //! and configuration management, providing actionable feedback for Rust developers.
 
-use crate::config::Config;
+use crate::config::{Config, DEFAULT_SOURCE_EXTENSIONS, DEFAULT_TODO_ISSUE_PATTERN};
 use crate::diagnostics::{Finding, Severity};
-use crate::manifest::CargoManifest;
+use crate::manifest::{CargoManifest, Workspace};
 use once_cell::sync::Lazy;
 use rayon::prelude::*;
 use regex::Regex;
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 use walkdir::{DirEntry, WalkDir};
 
 static UNWRAP_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"\.unwrap\(\)").unwrap());
 static EXPECT_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r#"\.expect\s*\("#).unwrap());
-static PRINTLN_DBG_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"(println!|dbg!)\s*\(").unwrap());
+static UNWRAP_ERR_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\.(unwrap_err\(\)|expect_err\s*\()").unwrap());
+static PRINT_MACRO_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\b(eprintln|eprint|println|print|dbg)!\s*\(").unwrap());
 static TODO_COMMENT_REGEX: Lazy<Regex> =
     Lazy::new(|| Regex::new(r"//\s*(TODO|FIXME|XXX)").unwrap());
+static TRY_MACRO_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"\btry!\s*\(").unwrap());
+static MAIN_FN_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?m)^\s*(pub\s+)?(async\s+)?fn\s+main\s*\(").unwrap());
+static ALLOW_ATTR_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"#(!?)\[allow\(([^)]+)\)\]").unwrap());
+static TOP_LEVEL_TYPE_DEF_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(?:pub(?:\([^)]*\))?\s+)?(type|struct|enum|trait)\s+([A-Za-z_][A-Za-z0-9_]*)\b")
+        .unwrap()
+});
+static NO_STD_ATTR_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?m)^\s*#!\[no_std\]").unwrap());
+static EXTERN_CRATE_STD_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?m)^\s*extern\s+crate\s+std\b").unwrap());
+static STD_PATH_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"\bstd::").unwrap());
+static DEBUG_ASSERT_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\b(debug_assert(?:_eq|_ne)?)!\s*\((.*)").unwrap());
+static CALL_EXPR_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"[A-Za-z_][A-Za-z0-9_]*\s*\(").unwrap());
+static STRINGLY_RESULT_FN_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"^\s*pub\s+(?:const\s+|async\s+|unsafe\s+)*fn\s+([A-Za-z_][A-Za-z0-9_]*).*->\s*Result<.*,\s*(String|Box<dyn\s+(?:std::error::)?Error\b[^>]*>)\s*>",
+    )
+    .unwrap()
+});
+static FN_SIGNATURE_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\bfn\s+([A-Za-z_][A-Za-z0-9_]*)\s*(?:<[^(]*>)?\s*\(").unwrap());
+static PUB_FN_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?m)^\s*pub\s+(?:(?:const|async|unsafe|extern\s+"[^"]*")\s+)*fn\s+([A-Za-z_][A-Za-z0-9_]*)"#)
+        .unwrap()
+});
+static DEFAULT_HASHER_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\b(HashMap|HashSet)::new\(\)").unwrap());
+static GLOB_REEXPORT_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^\s*pub\s+use\s+((?:::)?[A-Za-z_][A-Za-z0-9_:]*)::\*\s*;").unwrap());
+static RUNTIME_MAIN_ATTR_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^\s*#\[(tokio|async_std)::main\b").unwrap());
+static TEST_ATTR_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"#\[\s*(?:[A-Za-z_][A-Za-z0-9_]*::)*(?:test|test_case)\b").unwrap());
+// `#[test]` (or `#[tokio::test]` etc.), further attributes, then the function name
+static TEST_FN_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?m)^[ \t]*#\[\s*(?:[A-Za-z_][A-Za-z0-9_]*::)*test\s*\]\s*(?:#\[[^\]]*\]\s*)*(?:pub(?:\([^)]*\))?\s+)?(?:async\s+)?fn\s+([A-Za-z_][A-Za-z0-9_]*)",
+    )
+    .unwrap()
+});
+static PRINTLN_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\b(println|eprintln)!\s*\(").unwrap());
+static FEATURE_ATTR_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?m)^\s*#!\[feature\(").unwrap());
+static UNSAFE_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\bunsafe\s*(\{|fn\b|impl\b|trait\b|extern\b)").unwrap());
+static FORBID_UNSAFE_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"#!\[forbid\([^)]*\bunsafe_code\b[^)]*\)\]").unwrap());
+static ASYNC_USAGE_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\basync\s+fn\b|\.await\b").unwrap());
+static BUILD_CRATE_PATH_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\b(cc|bindgen|cbindgen|prost_build|tonic_build|pkg_config|cmake|vcpkg|vergen)::")
+        .unwrap()
+});
+static LITERAL_FORMAT_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"\bformat!\s*\(\s*"((?:[^"\\]|\\.)*)"\s*,?\s*\)"#).unwrap());
+static BUILD_FILE_ACCESS_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\bfs::|\bPath\b|\bread(?:_to_string|_dir|_to_end)?\s*\(").unwrap());
+static RERUN_IF_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"cargo::?rerun-if-").unwrap());
+static DERIVE_ATTR_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"#\[derive\(([^)]*)\)\]").unwrap());
+static TYPE_DEF_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^\s*(?:pub(?:\([^)]*\))?\s+)?(?:struct|enum|union)\s+([A-Za-z_][A-Za-z0-9_]*)")
+        .unwrap()
+});
+static EQ_HASH_IMPL_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\bimpl\b(?:\s*<[^{]*?>)?\s+(?:(?:std|core)::(?:cmp|hash)::)?(PartialEq|Hash)\b(?:<[^{]*?>)?\s+for\s+([A-Za-z_][A-Za-z0-9_]*)")
+        .unwrap()
+});
+static COPY_DROP_IMPL_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\bimpl\b(?:\s*<[^{]*?>)?\s+(?:(?:std|core)::(?:marker|ops)::)?(Copy|Drop)\s+for\s+([A-Za-z_][A-Za-z0-9_]*)")
+        .unwrap()
+});
+static UNSAFE_SEND_SYNC_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\bunsafe\s+impl\b(?:\s*<[^{]*?>)?\s+(?:(?:std|core)::marker::)?(Send|Sync)\s+for\s+([A-Za-z_][A-Za-z0-9_]*)")
+        .unwrap()
+});
 static DENY_LINT_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"#!\[deny\(([^)]+)\)\]").unwrap());
 
-fn is_rust_file(entry: &DirEntry) -> bool {
-    entry.file_type().is_file() && entry.path().extension().is_some_and(|ext| ext == "rs")
+/// Whether `entry` is a file whose name ends in `.{ext}` for one of `extensions`.
+/// Multi-part extensions such as `rs.in` are supported.
+fn is_rust_file(entry: &DirEntry, extensions: &[&str]) -> bool {
+    let file_name = entry.file_name().to_string_lossy();
+    entry.file_type().is_file()
+        && extensions.iter().any(|ext| {
+            file_name
+                .strip_suffix(ext)
+                .is_some_and(|stem| stem.len() > 1 && stem.ends_with('.'))
+        })
 }
 
+/// Source roots scanned in every project, relative to the project root.
+pub const DEFAULT_SOURCE_DIRS: &[&str] = &["src", "tests", "examples", "benches"];
+
 pub fn collect_rust_files(project_root: &Path) -> Vec<PathBuf> {
+    collect_rust_files_with_extra_dirs(project_root, &[])
+}
+
+/// Like [`collect_rust_files`], but also scans `extra_dirs` (relative to the project root,
+/// e.g. `xtask` or `fuzz`). Directories that do not exist are skipped.
+pub fn collect_rust_files_with_extra_dirs(
+    project_root: &Path,
+    extra_dirs: &[String],
+) -> Vec<PathBuf> {
+    collect_source_files(project_root, extra_dirs, DEFAULT_SOURCE_EXTENSIONS)
+}
+
+/// Like [`collect_rust_files_with_extra_dirs`], but collects files with any of `extensions`
+/// (see [`Config::source_extensions`]) instead of only `.rs`.
+pub fn collect_source_files(
+    project_root: &Path,
+    extra_dirs: &[String],
+    extensions: &[&str],
+) -> Vec<PathBuf> {
     let mut rust_files = Vec::new();
-    let source_roots = [
-        project_root.join("src"),
-        project_root.join("tests"),
-        project_root.join("examples"),
-        project_root.join("benches"),
-    ];
+    let source_roots: Vec<PathBuf> = DEFAULT_SOURCE_DIRS
+        .iter()
+        .copied()
+        .chain(extra_dirs.iter().map(String::as_str))
+        .map(|dir| project_root.join(dir))
+        .collect();
 
     for root in source_roots.iter().filter(|p| p.exists() && p.is_dir()) {
         WalkDir::new(root)
             .into_iter()
             .filter_map(Result::ok) // Ignore errors during walk, or handle them
-            .filter(is_rust_file)
+            .filter(|entry| is_rust_file(entry, extensions))
             .for_each(|entry| rust_files.push(entry.path().to_path_buf()));
     }
     rust_files
@@ -88,7 +211,35 @@ fn is_library_file(file_path: &Path, project_root: &Path) -> bool {
         && file_path != project_root.join("build.rs") // Not build script
 }
 
-pub fn check_code_patterns(rust_files: &[PathBuf], project_root: &Path) -> Vec<Finding> {
+pub fn check_code_patterns(
+    rust_files: &[PathBuf],
+    project_root: &Path,
+    config: &Config,
+) -> Vec<Finding> {
+    let discouraged_allows = config.discouraged_allows();
+    let check_allows = config.is_check_enabled("CODE018");
+    let check_clippy_allows = config.is_check_enabled("CODE031");
+    let shadowed_prelude_names = config.shadowed_prelude_names();
+    let check_prelude_shadowing = config.is_check_enabled("CODE021");
+    let check_debug_asserts = config.is_check_enabled("CODE023");
+    let check_stringly_errors = config.is_check_enabled("CODE024");
+    let check_chained_unwraps = config.is_check_enabled("CODE025");
+    let check_param_count = config.is_check_enabled("CODE026");
+    let check_default_hasher = config.is_check_enabled("CODE028");
+    let check_glob_reexports = config.is_check_enabled("CODE029");
+    let check_literal_format = config.is_check_enabled("CODE033");
+    let check_hash_eq = config.is_check_enabled("CODE034");
+    let check_todo_issues = config.is_check_enabled("CODE035");
+    let check_unsafe_send_sync = config.is_check_enabled("CODE038");
+    let check_crate_docs = config.is_check_enabled("CODE039");
+    let check_copy_drop = config.is_check_enabled("CODE040");
+    let check_pub_fn_unwraps = config.is_check_enabled("CODE041");
+    // An unparsable configured pattern falls back to the built-in one; resolving the
+    // configuration already warned about it
+    let todo_issue_regex = Regex::new(config.todo_issue_pattern())
+        .unwrap_or_else(|_| Regex::new(DEFAULT_TODO_ISSUE_PATTERN).unwrap());
+    let max_fn_params = config.max_fn_params();
+
     let findings_from_all_files: Vec<Finding> = rust_files
         .par_iter()
         .flat_map(|file_path_ref| {
@@ -96,6 +247,9 @@ pub fn check_code_patterns(rust_files: &[PathBuf], project_root: &Path) -> Vec<F
             let mut per_file_findings: Vec<Finding> = Vec::new();
 
             let is_lib_context = is_library_file(file_path, project_root);
+            // Library or binary source: anything under src/, but not tests, examples or benches
+            let is_crate_source = file_path.starts_with(project_root.join("src"));
+            let is_lib_root = file_path == project_root.join("src").join("lib.rs");
 
 
         // Skip build.rs for some checks like unwrap/expect, as they are common there
@@ -132,6 +286,19 @@ pub fn check_code_patterns(rust_files: &[PathBuf], project_root: &Path) -> Vec<F
                 ).with_line(line_number_for_finding));
             }
 
+            // Flag chained unwraps on one line in addition to CODE001
+            if check_chained_unwraps && is_lib_context && !file_path.ends_with("build.rs") {
+                let unwrap_count = UNWRAP_REGEX.find_iter(line_content).count();
+                if unwrap_count >= 2 {
+                    per_file_findings.push(Finding::new(
+                        "CODE025",
+                        format!("{unwrap_count} chained '.unwrap()' calls on one line. Any of them can panic; propagate errors with '?' instead."),
+                        Severity::Warning,
+                        Some(file_path.to_string_lossy().into_owned()),
+                    ).with_line(line_number_for_finding));
+                }
+            }
+
             // Check for .expect() in library context
             if is_lib_context && EXPECT_REGEX.is_match(line_content) && !file_path.ends_with("build.rs") {
                 per_file_findings.push(Finding::new(
@@ -142,37 +309,432 @@ pub fn check_code_patterns(rust_files: &[PathBuf], project_root: &Path) -> Vec<F
                 ).with_line(line_number_for_finding));
             }
 
-            // Check for println!/dbg! in library context
-            if is_lib_context && PRINTLN_DBG_REGEX.is_match(line_content) && !file_path.ends_with("build.rs") {
+            // Check for .unwrap_err()/.expect_err() in library context
+            if is_lib_context && !file_path.ends_with("build.rs") && let Some(cap) = UNWRAP_ERR_REGEX.captures(line_content) {
+                let method = cap[1].trim_end_matches(['(', ')', ' ']);
+                per_file_findings.push(Finding::new(
+                    "CODE027",
+                    format!("'.{method}()' used in library context. It panics when the result is Ok; match on both arms instead."),
+                    Severity::Note,
+                    Some(file_path.to_string_lossy().into_owned()),
+                ).with_line(line_number_for_finding));
+            }
+
+            // Check for print!/println!/eprint!/eprintln!/dbg! in library context
+            if is_lib_context && !file_path.ends_with("build.rs") && let Some(cap) = PRINT_MACRO_REGEX.captures(line_content) {
                  // Further refine: allow in main fn of examples, benches.
                  // This check is tricky without knowing the exact role of the file.
                  // For now, broad check on `is_lib_context`.
+                let macro_name = &cap[1];
                 per_file_findings.push(Finding::new(
                     "CODE003",
-                    "Diagnostic macro (println! or dbg!) found in library context. Remove before release.".to_string(),
+                    format!("Diagnostic macro ({macro_name}!) found in library context. Remove before release."),
                     Severity::Note,
                     Some(file_path.to_string_lossy().into_owned()),
                 ).with_line(line_number_for_finding));
             }
 
             // Check for TODO/FIXME comments (applies to all files)
-            if TODO_COMMENT_REGEX.is_match(line_content) {
-                let comment_type = TODO_COMMENT_REGEX.captures(line_content).unwrap().get(1).unwrap().as_str();
+            if let Some(cap) = TODO_COMMENT_REGEX.captures(line_content) {
+                let comment_type = cap.get(1).unwrap().as_str();
                 per_file_findings.push(Finding::new(
                     "CODE004",
                     format!("Found '{comment_type}' comment. Address or create an issue for it."),
                     Severity::Note,
                     Some(file_path.to_string_lossy().into_owned()),
                 ).with_line(line_number_for_finding));
+
+                // The rest of the comment must reference a tracking issue
+                let rest = &line_content[cap.get(0).unwrap().end()..];
+                if check_todo_issues && !todo_issue_regex.is_match(rest) {
+                    per_file_findings.push(Finding::new(
+                        "CODE035",
+                        format!("'{comment_type}' comment does not reference a tracking issue."),
+                        Severity::Note,
+                        Some(file_path.to_string_lossy().into_owned()),
+                    ).with_line(line_number_for_finding));
+                }
+            }
+
+            // Check for the deprecated try! macro in library/binary source
+            if is_crate_source && TRY_MACRO_REGEX.is_match(line_content) {
+                per_file_findings.push(Finding::new(
+                    "CODE017",
+                    "Deprecated 'try!' macro used. Use the '?' operator instead.".to_string(),
+                    Severity::Note,
+                    Some(file_path.to_string_lossy().into_owned()),
+                ).with_line(line_number_for_finding));
+            }
+
+            // Check for top-level items that shadow std prelude names
+            if check_prelude_shadowing && let Some(cap) = TOP_LEVEL_TYPE_DEF_REGEX.captures(line_content) && shadowed_prelude_names.contains(&&cap[2]) {
+                per_file_findings.push(Finding::new(
+                    "CODE021",
+                    format!("`{} {}` shadows the std prelude name `{}`. Consider a distinct name to avoid confusing callers.", &cap[1], &cap[2], &cap[2]),
+                    Severity::Note,
+                    Some(file_path.to_string_lossy().into_owned()),
+                ).with_line(line_number_for_finding));
+            }
+
+            // Check for debug assertions whose arguments call functions (side effects vanish in release)
+            if check_debug_asserts && let Some(cap) = DEBUG_ASSERT_REGEX.captures(line_content) && CALL_EXPR_REGEX.is_match(&cap[2]) {
+                per_file_findings.push(Finding::new(
+                    "CODE023",
+                    format!("`{}!` argument contains a function call. Any side effect will not run in release builds.", &cap[1]),
+                    Severity::Note,
+                    Some(file_path.to_string_lossy().into_owned()),
+                ).with_line(line_number_for_finding));
+            }
+
+            // Check for public library functions with stringly-typed or boxed errors
+            if check_stringly_errors && is_lib_context && let Some(cap) = STRINGLY_RESULT_FN_REGEX.captures(line_content) {
+                per_file_findings.push(Finding::new(
+                    "CODE024",
+                    format!("Public function `{}` returns `Result<_, {}>`. Consider a dedicated error type callers can match on.", &cap[1], &cap[2]),
+                    Severity::Note,
+                    Some(file_path.to_string_lossy().into_owned()),
+                ).with_line(line_number_for_finding));
+            }
+
+            // Check for hash collections built with the default SipHash hasher
+            if check_default_hasher && is_crate_source && let Some(cap) = DEFAULT_HASHER_REGEX.captures(line_content) {
+                per_file_findings.push(Finding::new(
+                    "CODE028",
+                    format!("`{}::new()` uses the default SipHash hasher. In hot paths consider a faster hasher (e.g. ahash or rustc-hash) via `with_hasher`.", &cap[1]),
+                    Severity::Note,
+                    Some(file_path.to_string_lossy().into_owned()),
+                ).with_line(line_number_for_finding));
+            }
+
+            // Check for format! calls whose only argument is a literal without placeholders
+            if check_literal_format && is_crate_source {
+                for cap in LITERAL_FORMAT_REGEX.captures_iter(line_content) {
+                    if cap[1].contains('{') {
+                        continue;
+                    }
+                    per_file_findings.push(Finding::new(
+                        "CODE033",
+                        format!("`format!(\"{}\")` has nothing to interpolate. Use `\"{}\".to_string()` or `String::from` instead.", &cap[1], &cap[1]),
+                        Severity::Note,
+                        Some(file_path.to_string_lossy().into_owned()),
+                    ).with_line(line_number_for_finding));
+                }
+            }
+
+            // Check for manual Send/Sync impls, which the compiler takes on trust
+            if check_unsafe_send_sync && let Some(cap) = UNSAFE_SEND_SYNC_REGEX.captures(line_content) {
+                per_file_findings.push(Finding::new(
+                    "CODE038",
+                    format!("`unsafe impl {} for {}` asserts thread safety the compiler cannot check. Document the invariants that make it sound in a `// SAFETY:` comment.", &cap[1], &cap[2]),
+                    Severity::Warning,
+                    Some(file_path.to_string_lossy().into_owned()),
+                ).with_line(line_number_for_finding));
+            }
+
+            // Check for glob re-exports in the crate root, which widen the public API silently
+            if check_glob_reexports && is_lib_root && let Some(cap) = GLOB_REEXPORT_REGEX.captures(line_content) {
+                per_file_findings.push(Finding::new(
+                    "CODE029",
+                    format!("Glob re-export `pub use {}::*;` makes every public item of `{}` part of the crate API. Prefer explicit re-exports.", &cap[1], &cap[1]),
+                    Severity::Note,
+                    Some(file_path.to_string_lossy().into_owned()),
+                ).with_line(line_number_for_finding));
+            }
+
+            // Check for blanket allow attributes that suppress important lints.
+            // Crate-level clippy allows get their own code (CODE031)
+            if check_allows || check_clippy_allows {
+                for cap in ALLOW_ATTR_REGEX.captures_iter(line_content) {
+                    let crate_level = &cap[1] == "!";
+                    let scope = if crate_level { "Crate-level" } else { "Item-level" };
+                    for lint in cap[2].split(',').map(str::trim) {
+                        if !discouraged_allows.contains(&lint) {
+                            continue;
+                        }
+                        if crate_level && lint.starts_with("clippy::") {
+                            if check_clippy_allows {
+                                per_file_findings.push(Finding::new(
+                                    "CODE031",
+                                    format!("Crate-level `allow({lint})` silences clippy for the whole crate. Allow the specific lints on the items that need them instead."),
+                                    Severity::Note,
+                                    Some(file_path.to_string_lossy().into_owned()),
+                                ).with_line(line_number_for_finding));
+                            }
+                        } else if check_allows {
+                            per_file_findings.push(Finding::new(
+                                "CODE018",
+                                format!("{scope} `allow({lint})` suppresses important diagnostics. Prefer targeted allows on the specific items that need them."),
+                                Severity::Note,
+                                Some(file_path.to_string_lossy().into_owned()),
+                            ).with_line(line_number_for_finding));
+                        }
+                    }
+                }
             }
         }
 
+            // Signatures often span several lines, so parameters are counted on the whole file
+            if check_param_count {
+                for cap in FN_SIGNATURE_REGEX.captures_iter(&content) {
+                    let signature = cap.get(0).unwrap();
+                    let Some(param_count) = count_fn_params(&content[signature.end()..]) else {
+                        continue;
+                    };
+                    if param_count > max_fn_params {
+                        let line_number = content[..signature.start()].matches('\n').count() + 1;
+                        per_file_findings.push(Finding::new(
+                            "CODE026",
+                            format!("Function `{}` takes {param_count} parameters (more than {max_fn_params}). Consider grouping them into a struct.", &cap[1]),
+                            Severity::Note,
+                            Some(file_path.to_string_lossy().into_owned()),
+                        ).with_line(line_number));
+                    }
+                }
+            }
+
+            // Derives and impls sit in different places in the file, so pair them up on the whole file
+            if check_hash_eq {
+                for (line_number, type_name, derived, manual) in hash_eq_mismatches(&content) {
+                    per_file_findings.push(Finding::new(
+                        "CODE034",
+                        format!("`{type_name}` derives `{derived}` but implements `{manual}` by hand. Equal values must hash equally; derive both or implement both."),
+                        Severity::Note,
+                        Some(file_path.to_string_lossy().into_owned()),
+                    ).with_line(line_number));
+                }
+            }
+
+            // Public functions only matter where they form a library API
+            if check_pub_fn_unwraps && (is_lib_context || is_lib_root) {
+                for (line_number, fn_name) in early_pub_fn_unwraps(&content) {
+                    per_file_findings.push(Finding::new(
+                        "CODE041",
+                        format!("Public function `{fn_name}` calls `.unwrap()` near the start of its body, likely on caller input. Propagate with `?` or return an `Option`/`Result` instead."),
+                        Severity::Note,
+                        Some(file_path.to_string_lossy().into_owned()),
+                    ).with_line(line_number));
+                }
+            }
+
+            if check_copy_drop {
+                for (line_number, type_name) in copy_drop_conflicts(&content) {
+                    per_file_findings.push(Finding::new(
+                        "CODE040",
+                        format!("`{type_name}` is `Copy` but also implements `Drop`. A type cannot be both; remove one of them."),
+                        Severity::Note,
+                        Some(file_path.to_string_lossy().into_owned()),
+                    ).with_line(line_number));
+                }
+            }
+
+            if check_crate_docs && is_lib_root && !has_crate_docs(&content) {
+                per_file_findings.push(Finding::new(
+                    "CODE039",
+                    "Library crate has no crate-level documentation. Start src/lib.rs with a `//!` comment describing the crate.".to_string(),
+                    Severity::Note,
+                    Some(file_path.to_string_lossy().into_owned()),
+                ));
+            }
+
             per_file_findings
         }).collect();
 
     findings_from_all_files
 }
 
+/// Whether the file opens with inner docs (`//!`, `/*!` or `#![doc = ...]`). Blank lines,
+/// plain comments and other crate attributes may come before them.
+fn has_crate_docs(content: &str) -> bool {
+    for line in content.lines().map(str::trim) {
+        if line.starts_with("//!") || line.starts_with("/*!") || line.starts_with("#![doc") {
+            return true;
+        }
+        if !(line.is_empty() || line.starts_with("//") || line.starts_with("#![")) {
+            return false;
+        }
+    }
+    false
+}
+
+/// Types with a `#[derive(...)]` in `content`. A derive is tied to the first item after
+/// it that is not another attribute or a comment.
+///
+/// Returns the line of the type definition, its name and the derived trait names
+/// (without paths).
+fn derived_types(content: &str) -> Vec<(usize, String, Vec<&str>)> {
+    let mut types = Vec::new();
+    for cap in DERIVE_ATTR_REGEX.captures_iter(content) {
+        let derives: Vec<&str> = cap
+            .get(1)
+            .unwrap()
+            .as_str()
+            .split(',')
+            .map(|name| name.trim().rsplit("::").next().unwrap_or(""))
+            .collect();
+        let after = cap.get(0).unwrap().end();
+        let start_line = content[..after].matches('\n').count() + 1;
+        let Some((offset, type_name)) = content[after..]
+            .lines()
+            .enumerate()
+            .find(|(_, line)| {
+                let line = line.trim();
+                !(line.is_empty() || line.starts_with("#[") || line.starts_with("//"))
+            })
+            .and_then(|(offset, line)| {
+                Some((offset, TYPE_DEF_REGEX.captures(line)?[1].to_string()))
+            })
+        else {
+            continue;
+        };
+        types.push((start_line + offset, type_name, derives));
+    }
+    types
+}
+
+/// Finds types whose `Hash` and `PartialEq` come from different places: one derived and
+/// the other implemented by hand in the same file (see [`derived_types`]).
+///
+/// Returns the line of the type definition, its name, the derived trait and the
+/// hand-written one.
+fn hash_eq_mismatches(content: &str) -> Vec<(usize, String, &'static str, &'static str)> {
+    let manual: Vec<(&str, &str)> = EQ_HASH_IMPL_REGEX
+        .captures_iter(content)
+        .map(|cap| (cap.get(1).unwrap().as_str(), cap.get(2).unwrap().as_str()))
+        .collect();
+    if manual.is_empty() {
+        return Vec::new();
+    }
+
+    let mut mismatches = Vec::new();
+    for (line, type_name, derives) in derived_types(content) {
+        for (derived, other) in [("Hash", "PartialEq"), ("PartialEq", "Hash")] {
+            if derives.contains(&derived)
+                && !derives.contains(&other)
+                && manual.contains(&(other, type_name.as_str()))
+            {
+                mismatches.push((line, type_name.clone(), derived, other));
+            }
+        }
+    }
+    mismatches
+}
+
+/// Number of lines at the start of a `pub fn` body searched by [`early_pub_fn_unwraps`].
+const PUB_FN_UNWRAP_WINDOW: usize = 5;
+
+/// Finds `pub fn`s whose body calls `.unwrap()` within its first
+/// [`PUB_FN_UNWRAP_WINDOW`] lines, where arguments are usually unpacked. The body is
+/// found by matching braces from the first `{` after the signature; declarations
+/// without a body are skipped. Returns the line of the first such unwrap and the name.
+fn early_pub_fn_unwraps(content: &str) -> Vec<(usize, String)> {
+    let mut unwraps = Vec::new();
+    for cap in PUB_FN_REGEX.captures_iter(content) {
+        let signature_end = cap.get(0).unwrap().end();
+        let rest = &content[signature_end..];
+        let Some(open) = rest
+            .find(['{', ';'])
+            .filter(|&i| rest.as_bytes()[i] == b'{')
+        else {
+            continue;
+        };
+
+        let mut depth = 0usize;
+        let mut close = None;
+        for (i, c) in rest[open..].char_indices() {
+            match c {
+                '{' => depth += 1,
+                '}' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        close = Some(open + i);
+                        break;
+                    }
+                }
+                _ => {}
+            }
+        }
+        let body = &rest[open + 1..close.unwrap_or(rest.len())];
+
+        let body_line = content[..signature_end + open].matches('\n').count() + 1;
+        // The line holding `{` counts as offset 0, so one-line bodies are covered too
+        if let Some(offset) = body
+            .lines()
+            .take(PUB_FN_UNWRAP_WINDOW + 1)
+            .position(|line| !line.trim_start().starts_with("//") && UNWRAP_REGEX.is_match(line))
+        {
+            unwraps.push((body_line + offset, cap[1].to_string()));
+        }
+    }
+    unwraps
+}
+
+/// Finds types in `content` that are `Copy`, derived or implemented by hand, and also
+/// have an `impl Drop`. Returns the line of the `Drop` impl and the type name.
+fn copy_drop_conflicts(content: &str) -> Vec<(usize, String)> {
+    let mut copy_types: Vec<String> = Vec::new();
+    let mut drop_impls: Vec<(usize, &str)> = Vec::new();
+    for cap in COPY_DROP_IMPL_REGEX.captures_iter(content) {
+        let type_name = cap.get(2).unwrap().as_str();
+        if &cap[1] == "Copy" {
+            copy_types.push(type_name.to_string());
+        } else {
+            let line = content[..cap.get(0).unwrap().start()].matches('\n').count() + 1;
+            drop_impls.push((line, type_name));
+        }
+    }
+    if drop_impls.is_empty() {
+        return Vec::new();
+    }
+    copy_types.extend(
+        derived_types(content)
+            .into_iter()
+            .filter(|(_, _, derives)| derives.contains(&"Copy"))
+            .map(|(_, type_name, _)| type_name),
+    );
+
+    drop_impls
+        .into_iter()
+        .filter(|(_, type_name)| copy_types.iter().any(|t| t == type_name))
+        .map(|(line, type_name)| (line, type_name.to_string()))
+        .collect()
+}
+
+/// Counts the parameters of a function whose parameter list starts right after its
+/// opening parenthesis (`rest` begins just past the `(`). Commas are only counted at the
+/// top level, outside nested parentheses, brackets and generic arguments.
+/// Returns `None` if the list is not closed.
+fn count_fn_params(rest: &str) -> Option<usize> {
+    let mut depth = 0usize;
+    let mut commas = 0;
+    let mut has_content = false;
+    let mut prev = ' ';
+    for c in rest.chars() {
+        match c {
+            '(' | '[' | '{' | '<' => depth += 1,
+            // `->` inside `impl Fn(A) -> B` is not a closing angle bracket
+            '>' if prev == '-' => {}
+            ')' if depth == 0 => {
+                // A trailing comma does not start another parameter
+                let params = if has_content { commas + 1 } else { commas };
+                return Some(params);
+            }
+            ')' | ']' | '}' | '>' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                commas += 1;
+                has_content = false;
+                prev = c;
+                continue;
+            }
+            _ => {}
+        }
+        if !c.is_whitespace() {
+            has_content = true;
+        }
+        prev = c;
+    }
+    None
+}
+
 pub fn check_project_structure(
     project_root: &Path,
     manifest_data: Option<&CargoManifest>, // Pass the parsed Cargo.toml
@@ -201,7 +763,9 @@ pub fn check_project_structure(
         })
     });
 
-    if let Some(_pkg) = manifest_data.and_then(|m| m.package.as_ref()) {
+    if let Some(manifest) = manifest_data
+        && let Some(pkg) = manifest.package.as_ref()
+    {
         // If it has a `[package]` section and is not a virtual workspace manifest
         if !is_likely_library && !has_main_rs && !has_bin_dir {
             findings.push(Finding::new(
@@ -212,6 +776,75 @@ pub fn check_project_structure(
             ));
         }
 
+        // Binary entry points (src/main.rs and any [[bin]] paths) must define `fn main`.
+        // Binaries whose required-features are off by default are skipped: a plain
+        // `cargo build` never compiles them.
+        let mut entry_files = Vec::new();
+        let mut gated_files = Vec::new();
+        if has_main_rs {
+            entry_files.push(project_root.join("src").join("main.rs"));
+        }
+        for bin in manifest.bin.iter().flatten() {
+            let path = match (&bin.path, &bin.name) {
+                (Some(path), _) => project_root.join(path),
+                (None, Some(name)) if *name == pkg.name => project_root.join("src").join("main.rs"),
+                (None, Some(name)) => project_root
+                    .join("src")
+                    .join("bin")
+                    .join(format!("{name}.rs")),
+                (None, None) => continue,
+            };
+            if !manifest.features_missing_by_default(bin).is_empty() {
+                gated_files.push(path);
+            } else if bin.path.is_some() && !entry_files.contains(&path) {
+                entry_files.push(path);
+            }
+        }
+        entry_files.retain(|path| !gated_files.contains(path));
+        for entry_file in entry_files {
+            if let Ok(content) = fs::read_to_string(&entry_file)
+                && !MAIN_FN_REGEX.is_match(&content)
+            {
+                findings.push(Finding::new(
+                    "STRUCT007",
+                    format!(
+                        "Binary entry file {:?} does not define `fn main`.",
+                        entry_file.strip_prefix(project_root).unwrap_or(&entry_file)
+                    ),
+                    Severity::Warning,
+                    Some(entry_file.to_string_lossy().into_owned()),
+                ));
+            }
+        }
+
+        // A build.rs that uses well-known build crates needs them in [build-dependencies]
+        let build_rs = project_root.join("build.rs");
+        if let Ok(content) = fs::read_to_string(&build_rs) {
+            let build_deps = manifest_data.and_then(|m| m.build_dependencies.as_ref());
+            let mut missing: Vec<&str> = Vec::new();
+            for caps in BUILD_CRATE_PATH_REGEX.captures_iter(&content) {
+                let crate_ident = caps.get(1).map_or("", |m| m.as_str());
+                let declared = build_deps.is_some_and(|deps| {
+                    deps.keys()
+                        .any(|name| name.replace('-', "_") == crate_ident)
+                });
+                if !declared && !missing.contains(&crate_ident) {
+                    missing.push(crate_ident);
+                }
+            }
+            for crate_ident in missing {
+                findings.push(Finding::new(
+                    "STRUCT008",
+                    format!(
+                        "build.rs uses `{crate_ident}::` but '{}' is not listed in [build-dependencies].",
+                        crate_ident.replace('_', "-")
+                    ),
+                    Severity::Note,
+                    Some(build_rs.to_string_lossy().into_owned()),
+                ));
+            }
+        }
+
         // If it has main.rs, it's likely a binary.
         // If it has lib.rs, it's likely a library.
         // If it has both, it's a common pattern for a crate that is both a lib and has a default binary.
@@ -246,7 +879,7 @@ pub fn check_project_structure(
             }
         }
 
-        // Check for LICENSE file (could also be in manifest checks if `license-file` points to it)
+        // Check for LICENSE file, or the file `license-file` points to
         // Common names: LICENSE, LICENSE.txt, LICENSE-MIT, LICENSE-APACHE, COPYING
         let license_files = [
             "LICENSE",
@@ -263,27 +896,33 @@ pub fn check_project_structure(
         });
 
         if !has_license_file {
-            // Check if Cargo.toml specifies `license-file`
-            let license_file_specified = manifest_data
-                .and_then(|m| m.package.as_ref())
-                .is_some_and(|p| {
-                    // Assuming you add `license_file: Option<String>` to your Package struct
-                    // p.license_file.as_ref().map_or(false, |lf| project_root.join(lf).is_file())
-                    // For now, let's assume it's not specified if Package struct doesn't have it
-                    p.license.is_some() && p.license.as_deref() != Some("") // If license is specified, a file is good practice
-                });
-
-            if !license_file_specified
-                || manifest_data
-                    .and_then(|m| m.package.as_ref())
-                    .is_none_or(|p| p.license.is_none())
-            {
-                findings.push(Finding::new(
-                    "STRUCT003",
-                    "Missing LICENSE file in project root. Consider adding one (e.g., LICENSE-MIT or LICENSE-APACHE).".to_string(),
-                    Severity::Warning, // More important than README
-                    Some("Project Root".to_string()), // Generic path
-                ));
+            let package = manifest_data.and_then(|m| m.package.as_ref());
+            let license_file = package
+                .and_then(|p| p.license_file.as_deref())
+                .filter(|lf| !lf.is_empty());
+            // An SPDX `license` expression is accepted without a file on disk
+            let license_specified =
+                package.is_some_and(|p| p.license.as_deref().is_some_and(|l| !l.is_empty()));
+
+            match license_file {
+                Some(lf) if project_root.join(lf).is_file() => {}
+                Some(lf) if !license_specified => {
+                    findings.push(Finding::new(
+                        "STRUCT003",
+                        format!("The 'license-file' in Cargo.toml points to '{lf}', which does not exist."),
+                        Severity::Warning,
+                        Some("Cargo.toml".to_string()),
+                    ));
+                }
+                _ if !license_specified => {
+                    findings.push(Finding::new(
+                        "STRUCT003",
+                        "Missing LICENSE file in project root. Consider adding one (e.g., LICENSE-MIT or LICENSE-APACHE).".to_string(),
+                        Severity::Warning, // More important than README
+                        Some("Project Root".to_string()), // Generic path
+                    ));
+                }
+                _ => {}
             }
         }
     }
@@ -335,74 +974,881 @@ pub fn check_missing_denied_lints(
     findings
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::config::{ChecksConfig, Config};
-    use crate::diagnostics::Severity;
-    use crate::manifest::{CargoManifest, Package};
-    use std::collections::HashMap;
-    use std::fs;
-    use tempfile::TempDir;
-
-    // Helper function to create a temporary directory with test files
-    fn create_test_dir() -> TempDir {
-        TempDir::new().expect("Failed to create temp dir")
+/// Flags binary names that cargo would see twice (STRUCT012): repeated `[[bin]]` names,
+/// `src/bin/foo.rs` next to `src/bin/foo/main.rs`, and a `[[bin]]` whose name matches an
+/// auto-discovered binary while its `path` points at a different file.
+pub fn check_binary_name_collisions(
+    project_root: &Path,
+    manifest: &CargoManifest,
+    config: &Config,
+) -> Vec<Finding> {
+    if !config.is_check_enabled("STRUCT012") {
+        return Vec::new();
     }
 
-    // Helper function to create a basic config
-    fn create_test_config() -> Config {
-        let mut enabled_checks = HashMap::new();
-        enabled_checks.insert("LINT001".to_string(), true);
-
-        Config {
-            general: Default::default(),
-            checks: ChecksConfig {
-                enabled: enabled_checks,
-            },
+    // Binaries cargo discovers on its own: src/bin/<name>.rs and src/bin/<name>/main.rs
+    let mut discovered: Vec<(String, PathBuf)> = Vec::new();
+    if let Ok(entries) = fs::read_dir(project_root.join("src").join("bin")) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() && path.join("main.rs").is_file() {
+                let name = entry.file_name().to_string_lossy().into_owned();
+                discovered.push((name, path.join("main.rs")));
+            } else if path.extension().is_some_and(|ext| ext == "rs")
+                && let Some(stem) = path.file_stem()
+            {
+                discovered.push((stem.to_string_lossy().into_owned(), path));
+            }
+        }
+    }
+    discovered.sort();
+
+    let mut collisions: Vec<String> = Vec::new();
+    for pair in discovered.windows(2) {
+        if pair[0].0 == pair[1].0 {
+            collisions.push(format!(
+                "binary '{}' is both {:?} and {:?}",
+                pair[0].0,
+                pair[0].1.strip_prefix(project_root).unwrap_or(&pair[0].1),
+                pair[1].1.strip_prefix(project_root).unwrap_or(&pair[1].1)
+            ));
         }
     }
 
-    // Helper function to create a basic CargoManifest
-    fn create_test_manifest(name: &str) -> CargoManifest {
-        CargoManifest {
-            package: Some(Package {
-                name: name.to_string(),
-                version: "0.1.0".to_string(),
-                license: Some("MIT".to_string()),
-                readme: None,
-                description: Some("Test package".to_string()),
-                edition: Some("2021".to_string()),
-                repository: None,
-            }),
-            dependencies: None,
-            dev_dependencies: None,
-            build_dependencies: None,
+    let declared: Vec<(&str, Option<&str>, Vec<&str>)> = manifest
+        .bin
+        .iter()
+        .flatten()
+        .filter_map(|bin| {
+            Some((
+                bin.name.as_deref()?,
+                bin.path.as_deref(),
+                manifest.features_missing_by_default(bin),
+            ))
+        })
+        .collect();
+    for (index, (name, path, gated_by)) in declared.iter().enumerate() {
+        // Targets behind non-default required-features only collide in those builds
+        let condition = if gated_by.is_empty() {
+            String::new()
+        } else {
+            format!(
+                " (only when building with features: {})",
+                gated_by.join(", ")
+            )
+        };
+        if declared[..index].iter().any(|(other, _, _)| other == name) {
+            collisions.push(format!(
+                "[[bin]] name '{name}' is declared more than once{condition}"
+            ));
+            continue;
+        }
+        let Some(path) = path else {
+            // Without a path cargo uses the discovered file itself
+            continue;
+        };
+        if let Some((_, file)) = discovered
+            .iter()
+            .find(|(found, file)| found == name && *file != project_root.join(path))
+        {
+            collisions.push(format!(
+                "[[bin]] '{name}' (path {path:?}) has the same name as the auto-discovered {:?}{condition}",
+                file.strip_prefix(project_root).unwrap_or(file)
+            ));
         }
     }
 
-    #[test]
-    fn test_is_rust_file() {
-        let temp_dir = create_test_dir();
-        let rust_file = temp_dir.path().join("test.rs");
-        let non_rust_file = temp_dir.path().join("test.txt");
+    collisions
+        .into_iter()
+        .map(|collision| {
+            Finding::new(
+                "STRUCT012",
+                format!("Binary name collision: {collision}. Rename one of the targets."),
+                Severity::Warning,
+                Some("Cargo.toml".to_string()),
+            )
+        })
+        .collect()
+}
 
-        fs::write(&rust_file, "fn main() {}").unwrap();
-        fs::write(&non_rust_file, "hello").unwrap();
+/// Flags non-Rust files under `src/` larger than [`Config::max_asset_size_kib`]
+/// (STRUCT013). Such files are usually pulled in with `include_bytes!` and bloat the
+/// published crate. Configured excludes are honored.
+pub fn check_large_source_assets(project_root: &Path, config: &Config) -> Vec<Finding> {
+    if !config.is_check_enabled("STRUCT013") {
+        return Vec::new();
+    }
+    let limit_kib = config.max_asset_size_kib();
+    let extensions = config.source_extensions();
+    let excluded = config.excluded_paths();
 
-        let rust_entry = WalkDir::new(&rust_file)
-            .into_iter()
-            .next()
-            .unwrap()
-            .unwrap();
-        let non_rust_entry = WalkDir::new(&non_rust_file)
-            .into_iter()
-            .next()
-            .unwrap()
-            .unwrap();
+    let mut findings = Vec::new();
+    for entry in WalkDir::new(project_root.join("src"))
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_file() && !is_rust_file(entry, &extensions))
+    {
+        let relative = entry
+            .path()
+            .strip_prefix(project_root)
+            .unwrap_or(entry.path());
+        if excluded.iter().any(|path| relative.starts_with(path)) {
+            continue;
+        }
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        if metadata.len() > limit_kib * 1024 {
+            findings.push(Finding::new(
+                "STRUCT013",
+                format!(
+                    "{relative:?} is {:.1} MiB, above the {limit_kib} KiB limit for non-Rust files under src/. Move it out of src/ or exclude it from the package.",
+                    metadata.len() as f64 / (1024.0 * 1024.0)
+                ),
+                Severity::Note,
+                Some(entry.path().to_string_lossy().into_owned()),
+            ));
+        }
+    }
+    findings
+}
 
-        assert!(is_rust_file(&rust_entry));
-        assert!(!is_rust_file(&non_rust_entry));
+/// Flags source files with CRLF line endings when most files use LF (CODE036, opt-in).
+/// Reads raw bytes, so files that are not valid UTF-8 are covered too. The majority vote
+/// needs every file, which is why this runs outside the cached per-file pattern checks.
+pub fn check_line_endings(rust_files: &[PathBuf], config: &Config) -> Vec<Finding> {
+    if !config.is_check_enabled("CODE036") {
+        return Vec::new();
+    }
+
+    let crlf_files: Vec<&PathBuf> = rust_files
+        .par_iter()
+        .filter(|file| fs::read(file).is_ok_and(|bytes| bytes.windows(2).any(|w| w == b"\r\n")))
+        .collect();
+    // A crate that standardizes on CRLF is consistent, not noisy
+    if crlf_files.len() * 2 >= rust_files.len() {
+        return Vec::new();
+    }
+
+    crlf_files
+        .into_iter()
+        .map(|file| {
+            Finding::new(
+                "CODE036",
+                "File uses CRLF line endings while most source files use LF. Convert it to LF or pin line endings in .gitattributes.".to_string(),
+                Severity::Note,
+                Some(file.to_string_lossy().into_owned()),
+            )
+        })
+        .collect()
+}
+
+/// Flags `#[test]` function names defined in more than one place across the crate
+/// (CODE042, opt-in). Two `mod tests` with a `works` test both fail as
+/// `test tests::works ... FAILED`, which does not say which file to open. One finding per
+/// name, at its first location, listing all of them.
+pub fn check_duplicate_test_names(
+    project_root: &Path,
+    rust_files: &[PathBuf],
+    config: &Config,
+) -> Vec<Finding> {
+    if !config.is_check_enabled("CODE042") {
+        return Vec::new();
+    }
+
+    let mut locations: BTreeMap<String, Vec<(PathBuf, usize)>> = BTreeMap::new();
+    for file in rust_files {
+        let Ok(content) = fs::read_to_string(file) else {
+            continue;
+        };
+        for cap in TEST_FN_REGEX.captures_iter(&content) {
+            let name = cap.get(1).unwrap();
+            let line = content[..name.start()].matches('\n').count() + 1;
+            locations
+                .entry(name.as_str().to_string())
+                .or_default()
+                .push((file.clone(), line));
+        }
+    }
+
+    locations
+        .into_iter()
+        .filter(|(_, defs)| defs.len() > 1)
+        .map(|(name, mut defs)| {
+            defs.sort();
+            let listed: Vec<String> = defs
+                .iter()
+                .map(|(file, line)| {
+                    let relative = file.strip_prefix(project_root).unwrap_or(file);
+                    format!("{}:{line}", relative.display())
+                })
+                .collect();
+            let (first_file, first_line) = &defs[0];
+            Finding::new(
+                "CODE042",
+                format!(
+                    "Test name `{name}` is defined in {} places ({}). Give each test a distinct name so failures are unambiguous.",
+                    defs.len(),
+                    listed.join(", ")
+                ),
+                Severity::Note,
+                Some(first_file.to_string_lossy().into_owned()),
+            )
+            .with_line(*first_line)
+        })
+        .collect()
+}
+
+/// Flags crates in immediate subdirectories of a workspace root that are neither listed
+/// in `[workspace] members` (globs allowed) nor excluded (STRUCT009).
+pub fn check_workspace_members(
+    project_root: &Path,
+    manifest: &CargoManifest,
+    config: &Config,
+) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    let Some(workspace) = &manifest.workspace else {
+        return findings;
+    };
+    if !config.is_check_enabled("STRUCT009") {
+        return findings;
+    }
+
+    let member_patterns: Vec<glob::Pattern> = workspace
+        .members
+        .iter()
+        .filter_map(|member| glob::Pattern::new(member.trim_end_matches('/')).ok())
+        .collect();
+    let Ok(entries) = fs::read_dir(project_root) else {
+        return findings;
+    };
+    let mut unlisted: Vec<String> = entries
+        .filter_map(Result::ok)
+        .filter(|entry| entry.path().join("Cargo.toml").is_file())
+        .map(|entry| entry.file_name().to_string_lossy().into_owned())
+        .filter(|dir| dir != "target" && !dir.starts_with('.'))
+        .filter(|dir| !member_patterns.iter().any(|pattern| pattern.matches(dir)))
+        .filter(|dir| {
+            !workspace
+                .exclude
+                .iter()
+                .any(|excluded| excluded.trim_end_matches('/') == dir)
+        })
+        .collect();
+    unlisted.sort();
+
+    for dir in unlisted {
+        findings.push(Finding::new(
+            "STRUCT009",
+            format!("Directory '{dir}' contains a Cargo.toml but is not a workspace member. Add it to `members` or `exclude`."),
+            Severity::Note,
+            Some("Cargo.toml".to_string()),
+        ));
+    }
+    findings
+}
+
+/// Edition of the package in a parsed Cargo.toml. `edition.workspace = true` resolves to
+/// `workspace_edition`; a missing edition is 2015, as cargo treats it.
+fn resolved_edition(manifest: &toml::Table, workspace_edition: Option<&str>) -> Option<String> {
+    let edition = manifest.get("package")?.get("edition");
+    let resolved = match edition {
+        None => "2015",
+        Some(toml::Value::String(edition)) => edition.as_str(),
+        Some(inherited)
+            if inherited.get("workspace").and_then(toml::Value::as_bool) == Some(true) =>
+        {
+            workspace_edition?
+        }
+        Some(_) => return None,
+    };
+    Some(resolved.to_string())
+}
+
+/// Directories of the workspace members below `project_root`: `members` with globs
+/// expanded, minus `exclude`, keeping only directories with a Cargo.toml. The root
+/// package itself is not included.
+pub fn workspace_member_dirs(project_root: &Path, workspace: &Workspace) -> Vec<PathBuf> {
+    let mut member_dirs: Vec<PathBuf> = Vec::new();
+    for member in &workspace.members {
+        let pattern = project_root.join(member.trim_end_matches('/'));
+        for dir in glob::glob(&pattern.to_string_lossy())
+            .into_iter()
+            .flatten()
+            .flatten()
+        {
+            let relative = dir.strip_prefix(project_root).unwrap_or(&dir);
+            let excluded = workspace
+                .exclude
+                .iter()
+                .any(|excluded| relative == Path::new(excluded.trim_end_matches('/')));
+            if !excluded
+                && dir != project_root
+                && dir.join("Cargo.toml").is_file()
+                && !member_dirs.contains(&dir)
+            {
+                member_dirs.push(dir);
+            }
+        }
+    }
+    member_dirs
+}
+
+/// Reports, in a single finding, when the packages of a workspace resolve to different
+/// editions (ED008). Members are read as plain TOML so inherited fields such as
+/// `version.workspace = true` do not stop them from being inspected.
+pub fn check_workspace_editions(
+    project_root: &Path,
+    manifest: &CargoManifest,
+    config: &Config,
+) -> Vec<Finding> {
+    let Some(workspace) = &manifest.workspace else {
+        return Vec::new();
+    };
+    if !config.is_check_enabled("ED008") {
+        return Vec::new();
+    }
+    let read_table = |dir: &Path| {
+        fs::read_to_string(dir.join("Cargo.toml"))
+            .ok()
+            .and_then(|content| toml::from_str::<toml::Table>(&content).ok())
+    };
+    let Some(root_table) = read_table(project_root) else {
+        return Vec::new();
+    };
+    let workspace_edition = root_table
+        .get("workspace")
+        .and_then(|ws| ws.get("package"))
+        .and_then(|package| package.get("edition"))
+        .and_then(toml::Value::as_str);
+
+    // The root package, if any, is a member too
+    let mut member_dirs = vec![project_root.to_path_buf()];
+    member_dirs.extend(workspace_member_dirs(project_root, workspace));
+
+    let mut editions: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for dir in &member_dirs {
+        let Some(table) = read_table(dir) else {
+            continue;
+        };
+        let Some(edition) = resolved_edition(&table, workspace_edition) else {
+            continue;
+        };
+        let name = table
+            .get("package")
+            .and_then(|package| package.get("name"))
+            .and_then(toml::Value::as_str)
+            .map_or_else(|| dir.to_string_lossy().into_owned(), str::to_string);
+        editions.entry(edition).or_default().push(name);
+    }
+    if editions.len() < 2 {
+        return Vec::new();
+    }
+
+    let in_use: Vec<String> = editions
+        .iter_mut()
+        .map(|(edition, members)| {
+            members.sort();
+            format!("{edition} ({})", members.join(", "))
+        })
+        .collect();
+    vec![Finding::new(
+        "ED008",
+        format!(
+            "Workspace members use different editions: {}. Consider moving them to one edition, e.g. through `[workspace.package] edition` and `edition.workspace = true`.",
+            in_use.join("; ")
+        ),
+        Severity::Note,
+        Some("Cargo.toml".to_string()),
+    )]
+}
+
+/// Number of minor releases a pinned stable toolchain may trail the latest before ED007
+/// reports it (roughly a year of six-week releases).
+const MAX_TOOLCHAIN_LAG: u64 = 8;
+
+/// Reads `toolchain.channel` from `rust-toolchain.toml`, or from the legacy
+/// `rust-toolchain` file, which may also hold just the channel name.
+fn read_toolchain_channel(project_root: &Path) -> Option<(String, PathBuf)> {
+    ["rust-toolchain.toml", "rust-toolchain"]
+        .iter()
+        .map(|name| project_root.join(name))
+        .find(|path| path.is_file())
+        .and_then(|path| {
+            let content = fs::read_to_string(&path).ok()?;
+            let channel = match toml::from_str::<toml::Table>(&content) {
+                Ok(table) => table
+                    .get("toolchain")?
+                    .get("channel")?
+                    .as_str()?
+                    .to_string(),
+                Err(_) => content.trim().to_string(),
+            };
+            Some((channel, path))
+        })
+}
+
+/// Flags a `nightly` toolchain pin in a crate that uses no `#![feature]` (ED006) and a
+/// stable pin far behind [`Config::latest_rust_version`] (ED007).
+pub fn check_rust_toolchain(project_root: &Path, config: &Config) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    let Some((channel, toolchain_path)) = read_toolchain_channel(project_root) else {
+        return findings;
+    };
+    let toolchain_file = Some(toolchain_path.to_string_lossy().into_owned());
+
+    if channel.starts_with("nightly") {
+        let uses_features = ["src/lib.rs", "src/main.rs"].iter().any(|root| {
+            fs::read_to_string(project_root.join(root))
+                .is_ok_and(|content| FEATURE_ATTR_REGEX.is_match(&content))
+        });
+        if config.is_check_enabled("ED006") && !uses_features {
+            findings.push(Finding::new(
+                "ED006",
+                format!("Toolchain is pinned to '{channel}' but the crate enables no `#![feature]`. Consider a stable toolchain."),
+                Severity::Note,
+                toolchain_file,
+            ));
+        }
+        return findings;
+    }
+
+    if config.is_check_enabled("ED007")
+        && let Some((major, minor)) = crate::manifest::parse_rust_version(&channel)
+        && let Some((latest_major, latest_minor)) =
+            crate::manifest::parse_rust_version(config.latest_rust_version())
+        && (major < latest_major || minor + MAX_TOOLCHAIN_LAG < latest_minor)
+    {
+        findings.push(Finding::new(
+            "ED007",
+            format!(
+                "Toolchain is pinned to {channel}, far behind the latest stable {}. Consider updating the pin.",
+                config.latest_rust_version()
+            ),
+            Severity::Note,
+            toolchain_file,
+        ));
+    }
+    findings
+}
+
+/// Whether any of the given files contains an `unsafe` block, function, impl, trait or extern.
+/// Comment lines are ignored; unreadable files are treated as not using unsafe.
+pub fn uses_unsafe(rust_files: &[PathBuf]) -> bool {
+    rust_files.par_iter().any(|file_path| {
+        fs::read_to_string(file_path).is_ok_and(|content| {
+            content
+                .lines()
+                .filter(|line| !line.trim_start().starts_with("//"))
+                .any(|line| UNSAFE_REGEX.is_match(line))
+        })
+    })
+}
+
+pub fn check_forbid_unsafe_code(
+    rust_files: &[PathBuf],
+    project_root: &Path,
+    config: &Config,
+) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    if !config.is_check_enabled("LINT002") || uses_unsafe(rust_files) {
+        return findings;
+    }
+
+    let crate_roots = [
+        project_root.join("src/lib.rs"),
+        project_root.join("src/main.rs"),
+    ];
+    for root in crate_roots.iter().filter(|p| p.is_file()) {
+        if let Ok(content) = fs::read_to_string(root)
+            && !FORBID_UNSAFE_REGEX.is_match(&content)
+        {
+            findings.push(Finding::new(
+                "LINT002",
+                format!(
+                    "No `unsafe` code found. Consider adding `#![forbid(unsafe_code)]` to the top of {:?} to guarantee it stays that way.",
+                    root.file_name().unwrap_or_default()
+                ),
+                Severity::Note,
+                Some(root.to_string_lossy().into_owned()),
+            ));
+        }
+    }
+    findings
+}
+
+/// Whether the crate root (src/lib.rs or src/main.rs) declares `#![no_std]`.
+pub fn is_no_std_crate(project_root: &Path) -> bool {
+    ["src/lib.rs", "src/main.rs"].iter().any(|root| {
+        fs::read_to_string(project_root.join(root))
+            .is_ok_and(|content| NO_STD_ATTR_REGEX.is_match(&content))
+    })
+}
+
+/// Logging crates whose presence makes `println!`/`eprintln!` diagnostics inconsistent.
+const LOGGING_CRATES: &[&str] = &["log", "tracing"];
+
+/// Flags `println!`/`eprintln!` in `src/` when `[dependencies]` declares a logging crate
+/// (CODE032, opt-in). Scanning a file stops at its first `#[cfg(test)]`, which by
+/// convention starts the unit tests.
+pub fn check_println_with_logging_crate(
+    rust_files: &[PathBuf],
+    project_root: &Path,
+    manifest: &CargoManifest,
+    config: &Config,
+) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    if !config.is_check_enabled("CODE032") {
+        return findings;
+    }
+    let Some(logging_crate) = LOGGING_CRATES.iter().find(|name| {
+        manifest
+            .dependencies
+            .as_ref()
+            .is_some_and(|deps| deps.contains_key(**name))
+    }) else {
+        return findings;
+    };
+
+    let src_dir = project_root.join("src");
+    for file_path in rust_files.iter().filter(|file| file.starts_with(&src_dir)) {
+        let Ok(content) = fs::read_to_string(file_path) else {
+            continue;
+        };
+        for (line_num, line) in content.lines().enumerate() {
+            if line.trim_start().starts_with("#[cfg(test)]") {
+                break;
+            }
+            if line.trim_start().starts_with("//") {
+                continue;
+            }
+            if let Some(cap) = PRINTLN_REGEX.captures(line) {
+                findings.push(
+                    Finding::new(
+                        "CODE032",
+                        format!(
+                            "`{}!` used although `{logging_crate}` is a dependency. Use its logging macros (e.g. `info!`, `error!`) instead.",
+                            &cap[1]
+                        ),
+                        Severity::Note,
+                        Some(file_path.to_string_lossy().into_owned()),
+                    )
+                    .with_line(line_num + 1),
+                );
+            }
+        }
+    }
+    findings
+}
+
+/// File names under `tests/` treated as shared helpers rather than test crates.
+const TEST_HELPER_FILES: &[&str] = &["common.rs", "test_helpers.rs"];
+
+/// Flags integration test files (`tests/*.rs` and `tests/*/main.rs`) that contain no
+/// `#[test]`, `#[tokio::test]` or `#[test_case]` attribute (STRUCT010). Files in
+/// subdirectories are modules of a test crate and are not checked on their own.
+pub fn check_test_files_without_tests(
+    rust_files: &[PathBuf],
+    project_root: &Path,
+    config: &Config,
+) -> Vec<Finding> {
+    if !config.is_check_enabled("STRUCT010") {
+        return Vec::new();
+    }
+
+    let tests_dir = project_root.join("tests");
+    let mut findings = Vec::new();
+    for file in rust_files {
+        let Ok(relative) = file.strip_prefix(&tests_dir) else {
+            continue;
+        };
+        let components: Vec<_> = relative.components().collect();
+        let is_test_crate_root = match components.len() {
+            1 => !TEST_HELPER_FILES
+                .iter()
+                .any(|helper| relative.as_os_str() == *helper),
+            2 => components[1].as_os_str() == "main.rs",
+            _ => false,
+        };
+        if !is_test_crate_root {
+            continue;
+        }
+        if let Ok(content) = fs::read_to_string(file)
+            && !TEST_ATTR_REGEX.is_match(&content)
+        {
+            findings.push(Finding::new(
+                "STRUCT010",
+                format!(
+                    "Integration test file {relative:?} contains no `#[test]` functions. Add tests, or move shared helpers into tests/common/mod.rs."
+                ),
+                Severity::Note,
+                Some(file.to_string_lossy().into_owned()),
+            ));
+        }
+    }
+    findings
+}
+
+/// Flags a `build.rs` that looks like it reads files (`fs::`, `Path`, `read*(`) but never
+/// prints a `cargo:rerun-if-*` directive (STRUCT011, opt-in). Without one, cargo reruns
+/// the script whenever any file in the package changes.
+pub fn check_build_script_rerun(project_root: &Path, config: &Config) -> Vec<Finding> {
+    if !config.is_check_enabled("STRUCT011") {
+        return Vec::new();
+    }
+
+    let build_rs = project_root.join("build.rs");
+    match fs::read_to_string(&build_rs) {
+        Ok(content)
+            if BUILD_FILE_ACCESS_REGEX.is_match(&content) && !RERUN_IF_REGEX.is_match(&content) =>
+        {
+            vec![Finding::new(
+                "STRUCT011",
+                "build.rs reads files but never prints `cargo:rerun-if-changed`. Emit it for each input so the script only reruns when they change.".to_string(),
+                Severity::Note,
+                Some(build_rs.to_string_lossy().into_owned()),
+            )]
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// Flags `std::` paths in the sources of a `#![no_std]` crate, which will not compile
+/// unless the crate also opts back in with `extern crate std`.
+pub fn check_no_std_usage(
+    rust_files: &[PathBuf],
+    project_root: &Path,
+    config: &Config,
+) -> Vec<Finding> {
+    if !config.is_check_enabled("CODE022") || !is_no_std_crate(project_root) {
+        return Vec::new();
+    }
+
+    let src_dir = project_root.join("src");
+    let crate_sources: Vec<&PathBuf> = rust_files
+        .iter()
+        .filter(|file| file.starts_with(&src_dir))
+        .collect();
+    let contents: Vec<(&PathBuf, String)> = crate_sources
+        .into_iter()
+        .filter_map(|file| Some((file, fs::read_to_string(file).ok()?)))
+        .collect();
+    if contents
+        .iter()
+        .any(|(_, content)| EXTERN_CRATE_STD_REGEX.is_match(content))
+    {
+        return Vec::new();
+    }
+
+    let mut findings = Vec::new();
+    for (file_path, content) in contents {
+        for (line_num, line) in content.lines().enumerate() {
+            if !line.trim_start().starts_with("//") && STD_PATH_REGEX.is_match(line) {
+                findings.push(
+                    Finding::new(
+                        "CODE022",
+                        "`std::` used in a `#![no_std]` crate. Use `core::` or `alloc::` instead."
+                            .to_string(),
+                        Severity::Warning,
+                        Some(file_path.to_string_lossy().into_owned()),
+                    )
+                    .with_line(line_num + 1),
+                );
+            }
+        }
+    }
+    findings
+}
+
+/// Dependencies that provide a way to drive futures to completion.
+const ASYNC_RUNTIME_CRATES: &[&str] =
+    &["tokio", "async-std", "smol", "futures", "futures-executor"];
+
+/// Flags `async fn`/`.await` usage when neither `[dependencies]` nor `[dev-dependencies]`
+/// declares a known async runtime. Reports the first occurrence only.
+pub fn check_async_runtime(
+    rust_files: &[PathBuf],
+    manifest: &CargoManifest,
+    config: &Config,
+) -> Vec<Finding> {
+    if !config.is_check_enabled("CODE019") {
+        return Vec::new();
+    }
+
+    let has_runtime = [&manifest.dependencies, &manifest.dev_dependencies]
+        .into_iter()
+        .flatten()
+        .any(|deps| ASYNC_RUNTIME_CRATES.iter().any(|rt| deps.contains_key(*rt)));
+    if has_runtime {
+        return Vec::new();
+    }
+
+    let first_usage = rust_files.iter().find_map(|file_path| {
+        let content = fs::read_to_string(file_path).ok()?;
+        content
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim_start().starts_with("//"))
+            .find(|(_, line)| ASYNC_USAGE_REGEX.is_match(line))
+            .map(|(line_num, _)| (file_path, line_num + 1))
+    });
+
+    match first_usage {
+        Some((file_path, line)) => vec![
+            Finding::new(
+                "CODE019",
+                format!(
+                    "Async code found but no async runtime ({}) is declared in Cargo.toml. Futures need an executor to run.",
+                    ASYNC_RUNTIME_CRATES.join(", ")
+                ),
+                Severity::Note,
+                Some(file_path.to_string_lossy().into_owned()),
+            )
+            .with_line(line),
+        ],
+        None => Vec::new(),
+    }
+}
+
+/// Flags `#[tokio::main]`/`#[async_std::main]` in binary entry files (src/main.rs,
+/// src/bin/*.rs and `[[bin]]` paths) when the matching crate is not in `[dependencies]`.
+pub fn check_async_main_runtime(
+    project_root: &Path,
+    manifest: &CargoManifest,
+    config: &Config,
+) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    if !config.is_check_enabled("CODE030") {
+        return findings;
+    }
+
+    let mut entry_files = vec![project_root.join("src").join("main.rs")];
+    if let Ok(entries) = fs::read_dir(project_root.join("src").join("bin")) {
+        let mut bin_files: Vec<PathBuf> = entries
+            .filter_map(Result::ok)
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "rs"))
+            .collect();
+        bin_files.sort();
+        entry_files.extend(bin_files);
+    }
+    for bin_path in manifest
+        .bin
+        .iter()
+        .flatten()
+        .filter_map(|b| b.path.as_ref())
+    {
+        let path = project_root.join(bin_path);
+        if !entry_files.contains(&path) {
+            entry_files.push(path);
+        }
+    }
+
+    let has_dependency = |crate_ident: &str| {
+        manifest.dependencies.as_ref().is_some_and(|deps| {
+            deps.keys()
+                .any(|name| name.replace('-', "_") == crate_ident)
+        })
+    };
+
+    for entry_file in entry_files {
+        let Ok(content) = fs::read_to_string(&entry_file) else {
+            continue;
+        };
+        for (line_num, line) in content.lines().enumerate() {
+            if let Some(cap) = RUNTIME_MAIN_ATTR_REGEX.captures(line)
+                && !has_dependency(&cap[1])
+            {
+                let crate_name = cap[1].replace('_', "-");
+                findings.push(
+                    Finding::new(
+                        "CODE030",
+                        format!(
+                            "`#[{}::main]` is used but `{crate_name}` is not in [dependencies]. The binary will not compile.",
+                            &cap[1]
+                        ),
+                        Severity::Warning,
+                        Some(entry_file.to_string_lossy().into_owned()),
+                    )
+                    .with_line(line_num + 1),
+                );
+            }
+        }
+    }
+    findings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{ChecksConfig, Config};
+    use crate::diagnostics::Severity;
+    use crate::manifest::{BinTarget, CargoManifest, Dependency, Package};
+    use std::collections::HashMap;
+    use std::fs;
+    use tempfile::TempDir;
+
+    // Helper function to create a temporary directory with test files
+    fn create_test_dir() -> TempDir {
+        TempDir::new().expect("Failed to create temp dir")
+    }
+
+    // Helper function to create a basic config
+    fn create_test_config() -> Config {
+        let mut enabled_checks = HashMap::new();
+        enabled_checks.insert("LINT001".to_string(), true);
+
+        Config {
+            general: Default::default(),
+            checks: ChecksConfig {
+                enabled: enabled_checks,
+                ..Default::default()
+            },
+        }
+    }
+
+    // Helper function to create a basic CargoManifest
+    fn create_test_manifest(name: &str) -> CargoManifest {
+        CargoManifest {
+            package: Some(Package {
+                name: name.to_string(),
+                version: "0.1.0".to_string(),
+                license: Some("MIT".to_string()),
+                readme: None,
+                description: Some("Test package".to_string()),
+                edition: Some("2021".to_string()),
+                repository: None,
+                ..Default::default()
+            }),
+            dependencies: None,
+            dev_dependencies: None,
+            build_dependencies: None,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_is_rust_file() {
+        let temp_dir = create_test_dir();
+        let rust_file = temp_dir.path().join("test.rs");
+        let non_rust_file = temp_dir.path().join("test.txt");
+
+        fs::write(&rust_file, "fn main() {}").unwrap();
+        fs::write(&non_rust_file, "hello").unwrap();
+
+        let rust_entry = WalkDir::new(&rust_file)
+            .into_iter()
+            .next()
+            .unwrap()
+            .unwrap();
+        let non_rust_entry = WalkDir::new(&non_rust_file)
+            .into_iter()
+            .next()
+            .unwrap()
+            .unwrap();
+
+        assert!(is_rust_file(&rust_entry, DEFAULT_SOURCE_EXTENSIONS));
+        assert!(!is_rust_file(&non_rust_entry, DEFAULT_SOURCE_EXTENSIONS));
     }
 
     #[test]
@@ -485,7 +1931,7 @@ fn test_function() {
         fs::write(&test_file, test_content).unwrap();
 
         let rust_files = vec![test_file];
-        let findings = check_code_patterns(&rust_files, project_root);
+        let findings = check_code_patterns(&rust_files, project_root, &Config::default());
 
         // Should find unwrap, expect, println, and TODO
         assert!(findings.iter().any(|f| f.code == "CODE001")); // unwrap
@@ -498,6 +1944,32 @@ fn test_function() {
         assert_eq!(unwrap_finding.line_number, Some(3));
     }
 
+    #[test]
+    fn test_check_code_patterns_print_macros() {
+        let temp_dir = create_test_dir();
+        let project_root = temp_dir.path();
+        let src_dir = project_root.join("src");
+
+        fs::create_dir_all(&src_dir).unwrap();
+
+        let test_file = src_dir.join("module.rs");
+        let test_content = r#"
+pub fn report(x: u8) -> String {
+    eprintln!("value: {x}");
+    format!("value: {x}")
+}
+"#;
+        fs::write(&test_file, test_content).unwrap();
+
+        let rust_files = vec![test_file];
+        let findings = check_code_patterns(&rust_files, project_root, &Config::default());
+
+        let print_findings: Vec<_> = findings.iter().filter(|f| f.code == "CODE003").collect();
+        assert_eq!(print_findings.len(), 1);
+        assert_eq!(print_findings[0].line_number, Some(3));
+        assert!(print_findings[0].message.contains("eprintln!"));
+    }
+
     #[test]
     fn test_check_code_patterns_build_script_exclusion() {
         let temp_dir = create_test_dir();
@@ -512,16 +1984,782 @@ fn main() {
 "#;
         fs::write(&build_rs, build_content).unwrap();
 
-        let rust_files = vec![build_rs];
-        let findings = check_code_patterns(&rust_files, project_root);
+        let rust_files = vec![build_rs];
+        let findings = check_code_patterns(&rust_files, project_root, &Config::default());
+
+        // Should not find CODE001, CODE002, or CODE003 in build.rs
+        assert!(!findings.iter().any(|f| f.code == "CODE001"));
+        assert!(!findings.iter().any(|f| f.code == "CODE002"));
+        assert!(!findings.iter().any(|f| f.code == "CODE003"));
+
+        // But should still find TODO comments
+        // (if we add one to the test content)
+    }
+
+    #[test]
+    fn test_check_code_patterns_try_macro_detection() {
+        let temp_dir = create_test_dir();
+        let project_root = temp_dir.path();
+        let src_dir = project_root.join("src");
+        let tests_dir = project_root.join("tests");
+
+        fs::create_dir_all(&src_dir).unwrap();
+        fs::create_dir_all(&tests_dir).unwrap();
+
+        let main_rs = src_dir.join("main.rs");
+        let main_content = r#"
+fn main() {
+    let value = try!(foo());
+    let other = parser.try_foo();
+}
+"#;
+        fs::write(&main_rs, main_content).unwrap();
+
+        // try! outside of src/ is not crate source and should not be flagged
+        let test_file = tests_dir.join("integration.rs");
+        fs::write(&test_file, "fn helper() { try!(foo()); }").unwrap();
+
+        let rust_files = vec![main_rs, test_file];
+        let findings = check_code_patterns(&rust_files, project_root, &Config::default());
+
+        let try_findings: Vec<&Finding> = findings.iter().filter(|f| f.code == "CODE017").collect();
+        assert_eq!(try_findings.len(), 1);
+        assert_eq!(try_findings[0].line_number, Some(3));
+        assert_eq!(try_findings[0].severity, Severity::Note);
+    }
+
+    #[test]
+    fn test_check_code_patterns_discouraged_allows() {
+        let temp_dir = create_test_dir();
+        let project_root = temp_dir.path();
+        let src_dir = project_root.join("src");
+
+        fs::create_dir_all(&src_dir).unwrap();
+
+        let lib_rs = src_dir.join("lib.rs");
+        let lib_content = r#"#![allow(warnings)]
+
+#[allow(clippy::too_many_arguments)]
+pub fn many(a: u8, b: u8, c: u8, d: u8, e: u8, f: u8, g: u8, h: u8) {}
+
+#[allow(unused, clippy::needless_return)]
+fn helper() {}
+"#;
+        fs::write(&lib_rs, lib_content).unwrap();
+
+        let rust_files = vec![lib_rs];
+        let findings = check_code_patterns(&rust_files, project_root, &Config::default());
+
+        let allow_findings: Vec<&Finding> =
+            findings.iter().filter(|f| f.code == "CODE018").collect();
+        assert_eq!(allow_findings.len(), 2);
+        assert_eq!(allow_findings[0].line_number, Some(1));
+        assert!(
+            allow_findings[0]
+                .message
+                .contains("Crate-level `allow(warnings)`")
+        );
+        assert_eq!(allow_findings[1].line_number, Some(6));
+        assert!(
+            allow_findings[1]
+                .message
+                .contains("Item-level `allow(unused)`")
+        );
+    }
+
+    #[test]
+    fn test_check_code_patterns_crate_level_clippy_allows() {
+        let temp_dir = create_test_dir();
+        let project_root = temp_dir.path();
+        let src_dir = project_root.join("src");
+
+        fs::create_dir_all(&src_dir).unwrap();
+
+        let lib_rs = src_dir.join("lib.rs");
+        fs::write(
+            &lib_rs,
+            "#![allow(clippy::all)]\n#![allow(clippy::pedantic, clippy::module_name_repetitions)]\n#[allow(clippy::needless_return)]\nfn f() {}\n",
+        )
+        .unwrap();
+
+        let rust_files = vec![lib_rs];
+        let findings = check_code_patterns(&rust_files, project_root, &Config::default());
+        let clippy_findings: Vec<&Finding> =
+            findings.iter().filter(|f| f.code == "CODE031").collect();
+        assert_eq!(clippy_findings.len(), 2);
+        assert_eq!(clippy_findings[0].line_number, Some(1));
+        assert!(clippy_findings[0].message.contains("`allow(clippy::all)`"));
+        assert_eq!(clippy_findings[1].line_number, Some(2));
+        assert!(
+            clippy_findings[1]
+                .message
+                .contains("`allow(clippy::pedantic)`")
+        );
+        // Reported as CODE031 only, not also as CODE018
+        assert!(!findings.iter().any(|f| f.code == "CODE018"));
+
+        // The clippy groups come from the configurable discouraged-allows list
+        let mut config = Config::default();
+        config.checks.discouraged_allows = Some(vec!["warnings".to_string()]);
+        let findings = check_code_patterns(&rust_files, project_root, &config);
+        assert!(!findings.iter().any(|f| f.code == "CODE031"));
+    }
+
+    #[test]
+    fn test_check_code_patterns_discouraged_allows_configurable() {
+        let temp_dir = create_test_dir();
+        let project_root = temp_dir.path();
+        let src_dir = project_root.join("src");
+
+        fs::create_dir_all(&src_dir).unwrap();
+
+        let lib_rs = src_dir.join("lib.rs");
+        fs::write(
+            &lib_rs,
+            "#![allow(warnings)]\n#[allow(clippy::too_many_arguments)]\nfn f() {}\n",
+        )
+        .unwrap();
+
+        let mut config = Config::default();
+        config.checks.discouraged_allows = Some(vec!["clippy::too_many_arguments".to_string()]);
+
+        let rust_files = vec![lib_rs];
+        let findings = check_code_patterns(&rust_files, project_root, &config);
+        let allow_findings: Vec<&Finding> =
+            findings.iter().filter(|f| f.code == "CODE018").collect();
+        assert_eq!(allow_findings.len(), 1);
+        assert_eq!(allow_findings[0].line_number, Some(2));
+
+        // Disabling the check silences it entirely
+        config.checks.enabled.insert("CODE018".to_string(), false);
+        let findings = check_code_patterns(&rust_files, project_root, &config);
+        assert!(!findings.iter().any(|f| f.code == "CODE018"));
+    }
+
+    #[test]
+    fn test_check_code_patterns_prelude_shadowing() {
+        let temp_dir = create_test_dir();
+        let project_root = temp_dir.path();
+        let src_dir = project_root.join("src");
+
+        fs::create_dir_all(&src_dir).unwrap();
+
+        let error_rs = src_dir.join("error.rs");
+        fs::write(
+            &error_rs,
+            "pub type Result<T> = std::result::Result<T, Error>;\npub type MyResult = Result<(), Error>;\n",
+        )
+        .unwrap();
+
+        let rust_files = vec![error_rs];
+        let findings = check_code_patterns(&rust_files, project_root, &Config::default());
+        let shadowing: Vec<&Finding> = findings.iter().filter(|f| f.code == "CODE021").collect();
+        assert_eq!(shadowing.len(), 1);
+        assert_eq!(shadowing[0].line_number, Some(1));
+        assert!(shadowing[0].message.contains("`Result`"));
+
+        let mut config = Config::default();
+        config.checks.shadowed_prelude_names = Some(vec!["Option".to_string()]);
+        let findings = check_code_patterns(&rust_files, project_root, &config);
+        assert!(!findings.iter().any(|f| f.code == "CODE021"));
+    }
+
+    #[test]
+    fn test_check_code_patterns_debug_assert_side_effects() {
+        let temp_dir = create_test_dir();
+        let project_root = temp_dir.path();
+        let src_dir = project_root.join("src");
+
+        fs::create_dir_all(&src_dir).unwrap();
+
+        let module = src_dir.join("state.rs");
+        fs::write(
+            &module,
+            "fn f(x: u8) {\n    debug_assert!(x == 1);\n    debug_assert!(mutate_state());\n    debug_assert_eq!(next_id(), 2);\n}\n",
+        )
+        .unwrap();
+
+        let rust_files = vec![module];
+        let findings = check_code_patterns(&rust_files, project_root, &Config::default());
+        let asserts: Vec<&Finding> = findings.iter().filter(|f| f.code == "CODE023").collect();
+        assert_eq!(asserts.len(), 2);
+        assert_eq!(asserts[0].line_number, Some(3));
+        assert!(asserts[1].message.contains("debug_assert_eq!"));
+    }
+
+    #[test]
+    fn test_check_code_patterns_stringly_typed_errors() {
+        let temp_dir = create_test_dir();
+        let project_root = temp_dir.path();
+        let src_dir = project_root.join("src");
+
+        fs::create_dir_all(&src_dir).unwrap();
+
+        let api_rs = src_dir.join("api.rs");
+        fs::write(
+            &api_rs,
+            "pub fn f() -> Result<(), String> {\n    Ok(())\n}\nfn g() -> Result<(), String> {\n    Ok(())\n}\npub fn h() -> Result<u8, Box<dyn std::error::Error + Send>> {\n    Ok(0)\n}\n",
+        )
+        .unwrap();
+        let rust_files = vec![api_rs];
+
+        // Opt-in: silent by default
+        let findings = check_code_patterns(&rust_files, project_root, &Config::default());
+        assert!(!findings.iter().any(|f| f.code == "CODE024"));
+
+        let mut config = Config::default();
+        config.checks.enabled.insert("CODE024".to_string(), true);
+        let findings = check_code_patterns(&rust_files, project_root, &config);
+        let flagged: Vec<&Finding> = findings.iter().filter(|f| f.code == "CODE024").collect();
+        assert_eq!(flagged.len(), 2);
+        assert_eq!(flagged[0].line_number, Some(1));
+        assert!(flagged[0].message.contains("`f`"));
+        assert_eq!(flagged[1].line_number, Some(7));
+    }
+
+    #[test]
+    fn test_check_code_patterns_default_hasher() {
+        let temp_dir = create_test_dir();
+        let project_root = temp_dir.path();
+        let src_dir = project_root.join("src");
+
+        fs::create_dir_all(&src_dir).unwrap();
+
+        let module = src_dir.join("module.rs");
+        fs::write(
+            &module,
+            "fn f() {\n    let a: HashMap<u8, u8> = HashMap::new();\n    let b = HashMap::with_hasher(FxBuildHasher::default());\n    let c = std::collections::HashSet::<u8>::new();\n    let d: HashSet<u8> = HashSet::new();\n}\n",
+        )
+        .unwrap();
+        let rust_files = vec![module];
+
+        // Opt-in: silent by default
+        let findings = check_code_patterns(&rust_files, project_root, &Config::default());
+        assert!(!findings.iter().any(|f| f.code == "CODE028"));
+
+        let mut config = Config::default();
+        config.checks.enabled.insert("CODE028".to_string(), true);
+        let findings = check_code_patterns(&rust_files, project_root, &config);
+        let flagged: Vec<&Finding> = findings.iter().filter(|f| f.code == "CODE028").collect();
+        assert_eq!(flagged.len(), 2);
+        assert_eq!(flagged[0].line_number, Some(2));
+        assert!(flagged[0].message.contains("`HashMap::new()`"));
+        assert_eq!(flagged[1].line_number, Some(5));
+        assert!(flagged[1].message.contains("`HashSet::new()`"));
+    }
+
+    #[test]
+    fn test_check_code_patterns_literal_format() {
+        let temp_dir = create_test_dir();
+        let project_root = temp_dir.path();
+        let src_dir = project_root.join("src");
+
+        fs::create_dir_all(&src_dir).unwrap();
+
+        let module = src_dir.join("module.rs");
+        fs::write(
+            &module,
+            "fn f(x: u8) {\n    let a = format!(\"hello\");\n    let b = format!(\"hi {x}\");\n    let c = format!(\"{}\", x);\n    let d = format!(\"{{literal}}\");\n}\n",
+        )
+        .unwrap();
+        let rust_files = vec![module];
+
+        let findings = check_code_patterns(&rust_files, project_root, &Config::default());
+        let flagged: Vec<&Finding> = findings.iter().filter(|f| f.code == "CODE033").collect();
+        assert_eq!(flagged.len(), 1);
+        assert_eq!(flagged[0].line_number, Some(2));
+        assert_eq!(flagged[0].severity, Severity::Note);
+        assert!(flagged[0].message.contains("`format!(\"hello\")`"));
+    }
+
+    #[test]
+    fn test_check_code_patterns_unsafe_send_sync() {
+        let temp_dir = create_test_dir();
+        let project_root = temp_dir.path();
+        let src_dir = project_root.join("src");
+
+        fs::create_dir_all(&src_dir).unwrap();
+
+        let module = src_dir.join("module.rs");
+        fs::write(
+            &module,
+            "unsafe impl Send for Foo {}\n\
+             impl Send for Foo {}\n\
+             unsafe impl<T: Send> Sync for Wrapper<T> {}\n",
+        )
+        .unwrap();
+        let rust_files = vec![module];
+
+        let findings = check_code_patterns(&rust_files, project_root, &Config::default());
+        let flagged: Vec<&Finding> = findings.iter().filter(|f| f.code == "CODE038").collect();
+        assert_eq!(flagged.len(), 2);
+        assert_eq!(flagged[0].line_number, Some(1));
+        assert_eq!(flagged[0].severity, Severity::Warning);
+        assert!(flagged[0].message.contains("`unsafe impl Send for Foo`"));
+        assert_eq!(flagged[1].line_number, Some(3));
+        assert!(
+            flagged[1]
+                .message
+                .contains("`unsafe impl Sync for Wrapper`")
+        );
+    }
+
+    #[test]
+    fn test_check_code_patterns_crate_docs() {
+        let temp_dir = create_test_dir();
+        let project_root = temp_dir.path();
+        let src_dir = project_root.join("src");
+
+        fs::create_dir_all(&src_dir).unwrap();
+
+        let lib_rs = src_dir.join("lib.rs");
+        let main_rs = src_dir.join("main.rs");
+        fs::write(&lib_rs, "#![deny(missing_docs)]\n\npub fn add() {}\n").unwrap();
+        fs::write(&main_rs, "fn main() {}\n").unwrap();
+        let rust_files = vec![lib_rs.clone(), main_rs];
+
+        let mut config = Config::default();
+        let findings = check_code_patterns(&rust_files, project_root, &config);
+        assert!(!findings.iter().any(|f| f.code == "CODE039"));
+
+        config.checks.enabled.insert("CODE039".to_string(), true);
+        let findings = check_code_patterns(&rust_files, project_root, &config);
+        let flagged: Vec<&Finding> = findings.iter().filter(|f| f.code == "CODE039").collect();
+        assert_eq!(flagged.len(), 1);
+        assert_eq!(
+            flagged[0].file_path.as_deref(),
+            Some(lib_rs.to_string_lossy().as_ref())
+        );
+
+        fs::write(
+            &lib_rs,
+            "#![deny(missing_docs)]\n//! Adds numbers.\n\npub fn add() {}\n",
+        )
+        .unwrap();
+        let findings = check_code_patterns(&rust_files, project_root, &config);
+        assert!(!findings.iter().any(|f| f.code == "CODE039"));
+    }
+
+    #[test]
+    fn test_check_code_patterns_pub_fn_unwrap() {
+        let temp_dir = create_test_dir();
+        let project_root = temp_dir.path();
+        let src_dir = project_root.join("src");
+
+        fs::create_dir_all(&src_dir).unwrap();
+
+        let module = src_dir.join("module.rs");
+        fs::write(
+            &module,
+            r#"pub fn parse_port(input: &str) -> u16 {
+    let trimmed = input.trim();
+    trimmed.parse().unwrap()
+}
+
+fn helper(input: &str) -> u16 {
+    input.parse().unwrap()
+}
+
+pub fn checked(input: &str) -> Option<u16> {
+    input.parse().ok()
+}
+"#,
+        )
+        .unwrap();
+        let rust_files = vec![module];
+
+        let findings = check_code_patterns(&rust_files, project_root, &Config::default());
+        assert!(!findings.iter().any(|f| f.code == "CODE041"));
+
+        let mut config = Config::default();
+        config.checks.enabled.insert("CODE041".to_string(), true);
+        let findings = check_code_patterns(&rust_files, project_root, &config);
+        let flagged: Vec<&Finding> = findings.iter().filter(|f| f.code == "CODE041").collect();
+        assert_eq!(flagged.len(), 1);
+        assert_eq!(flagged[0].line_number, Some(3));
+        assert!(flagged[0].message.contains("`parse_port`"));
+    }
+
+    #[test]
+    fn test_check_code_patterns_copy_drop_conflict() {
+        let temp_dir = create_test_dir();
+        let project_root = temp_dir.path();
+        let src_dir = project_root.join("src");
+
+        fs::create_dir_all(&src_dir).unwrap();
+
+        let module = src_dir.join("module.rs");
+        fs::write(
+            &module,
+            r#"#[derive(Clone, Copy)]
+pub struct Handle(u32);
+
+impl Drop for Handle {
+    fn drop(&mut self) {}
+}
+
+#[derive(Clone)]
+pub struct Guard(u32);
+
+impl Drop for Guard {
+    fn drop(&mut self) {}
+}
+"#,
+        )
+        .unwrap();
+        let rust_files = vec![module];
+
+        let findings = check_code_patterns(&rust_files, project_root, &Config::default());
+        assert!(!findings.iter().any(|f| f.code == "CODE040"));
+
+        let mut config = Config::default();
+        config.checks.enabled.insert("CODE040".to_string(), true);
+        let findings = check_code_patterns(&rust_files, project_root, &config);
+        let flagged: Vec<&Finding> = findings.iter().filter(|f| f.code == "CODE040").collect();
+        assert_eq!(flagged.len(), 1);
+        assert_eq!(flagged[0].line_number, Some(4));
+        assert!(flagged[0].message.contains("`Handle`"));
+    }
+
+    #[test]
+    fn test_check_code_patterns_hash_eq_mismatch() {
+        let temp_dir = create_test_dir();
+        let project_root = temp_dir.path();
+        let src_dir = project_root.join("src");
+
+        fs::create_dir_all(&src_dir).unwrap();
+
+        let module = src_dir.join("module.rs");
+        fs::write(
+            &module,
+            r#"#[derive(Debug, Clone, Hash)]
+pub struct Key {
+    id: u32,
+    label: String,
+}
+
+impl PartialEq for Key {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+#[derive(PartialEq, Eq, Hash)]
+struct Consistent(u8);
+
+#[derive(PartialEq)]
+/// Compared by value, hashed by hand
+enum Kind {
+    A,
+}
+
+impl std::hash::Hash for Kind {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+"#,
+        )
+        .unwrap();
+        let rust_files = vec![module];
+
+        // Opt-in: silent by default
+        let findings = check_code_patterns(&rust_files, project_root, &Config::default());
+        assert!(!findings.iter().any(|f| f.code == "CODE034"));
+
+        let mut config = Config::default();
+        config.checks.enabled.insert("CODE034".to_string(), true);
+        let findings = check_code_patterns(&rust_files, project_root, &config);
+        let flagged: Vec<&Finding> = findings.iter().filter(|f| f.code == "CODE034").collect();
+        assert_eq!(flagged.len(), 2);
+        assert_eq!(flagged[0].line_number, Some(2));
+        assert!(flagged[0].message.contains("`Key` derives `Hash`"));
+        assert_eq!(flagged[1].line_number, Some(18));
+        assert!(flagged[1].message.contains("`Kind` derives `PartialEq`"));
+    }
+
+    #[test]
+    fn test_check_code_patterns_glob_reexports() {
+        let temp_dir = create_test_dir();
+        let project_root = temp_dir.path();
+        let src_dir = project_root.join("src");
+
+        fs::create_dir_all(&src_dir).unwrap();
+
+        let content = "mod foo;\npub use foo::*;\npub use foo::Bar;\npub(crate) use foo::*;\npub use crate::internal::*;\n";
+        let lib_rs = src_dir.join("lib.rs");
+        let module = src_dir.join("module.rs");
+        fs::write(&lib_rs, content).unwrap();
+        fs::write(&module, content).unwrap();
+        let rust_files = vec![lib_rs, module];
+
+        // Opt-in: silent by default
+        let findings = check_code_patterns(&rust_files, project_root, &Config::default());
+        assert!(!findings.iter().any(|f| f.code == "CODE029"));
+
+        let mut config = Config::default();
+        config.checks.enabled.insert("CODE029".to_string(), true);
+        let findings = check_code_patterns(&rust_files, project_root, &config);
+        let flagged: Vec<&Finding> = findings.iter().filter(|f| f.code == "CODE029").collect();
+        // Only the crate root, and only the `pub use ...::*;` lines
+        assert_eq!(flagged.len(), 2);
+        assert_eq!(flagged[0].line_number, Some(2));
+        assert!(flagged[0].message.contains("`pub use foo::*;`"));
+        assert_eq!(flagged[1].line_number, Some(5));
+        assert!(
+            flagged
+                .iter()
+                .all(|f| f.file_path.as_deref().unwrap().ends_with("lib.rs"))
+        );
+    }
+
+    #[test]
+    fn test_check_code_patterns_chained_unwraps() {
+        let temp_dir = create_test_dir();
+        let project_root = temp_dir.path();
+        let src_dir = project_root.join("src");
+
+        fs::create_dir_all(&src_dir).unwrap();
+
+        let module = src_dir.join("module.rs");
+        fs::write(
+            &module,
+            "fn f() {\n    a.unwrap().b().unwrap();\n    c.unwrap();\n}\n",
+        )
+        .unwrap();
+
+        let findings = check_code_patterns(&[module], project_root, &Config::default());
+        let chained: Vec<&Finding> = findings.iter().filter(|f| f.code == "CODE025").collect();
+        assert_eq!(chained.len(), 1);
+        assert_eq!(chained[0].line_number, Some(2));
+        assert_eq!(chained[0].severity, Severity::Warning);
+        assert_eq!(findings.iter().filter(|f| f.code == "CODE001").count(), 2);
+    }
+
+    #[test]
+    fn test_count_fn_params() {
+        assert_eq!(count_fn_params(") {}"), Some(0));
+        assert_eq!(count_fn_params("a: u8, b: (u8, u8)) {}"), Some(2));
+        assert_eq!(
+            count_fn_params("m: HashMap<K, V>, f: impl Fn(u8, u8) -> u8,\n) {}"),
+            Some(2)
+        );
+        assert_eq!(count_fn_params("a: u8"), None);
+    }
+
+    #[test]
+    fn test_check_code_patterns_long_parameter_lists() {
+        let temp_dir = create_test_dir();
+        let project_root = temp_dir.path();
+        let src_dir = project_root.join("src");
+
+        fs::create_dir_all(&src_dir).unwrap();
+
+        let module = src_dir.join("module.rs");
+        fs::write(
+            &module,
+            "fn small(a: u8, b: u8, c: u8) {}\n\
+             \n\
+             pub fn big(\n    a: u8,\n    b: (u8, u8, u8),\n    c: u8,\n    d: u8,\n    e: u8,\n    f: u8,\n    g: u8,\n    h: u8,\n) {}\n",
+        )
+        .unwrap();
+        let rust_files = vec![module];
+
+        let findings = check_code_patterns(&rust_files, project_root, &Config::default());
+        let flagged: Vec<&Finding> = findings.iter().filter(|f| f.code == "CODE026").collect();
+        assert_eq!(flagged.len(), 1);
+        assert_eq!(flagged[0].line_number, Some(3));
+        assert!(flagged[0].message.contains("`big` takes 8 parameters"));
+
+        let mut config = Config::default();
+        config.checks.max_fn_params = Some(2);
+        let findings = check_code_patterns(&rust_files, project_root, &config);
+        assert_eq!(findings.iter().filter(|f| f.code == "CODE026").count(), 2);
+    }
+
+    #[test]
+    fn test_check_code_patterns_todo_without_issue() {
+        let temp_dir = create_test_dir();
+        let project_root = temp_dir.path();
+        let src_dir = project_root.join("src");
+
+        fs::create_dir_all(&src_dir).unwrap();
+        let module = src_dir.join("module.rs");
+        fs::write(
+            &module,
+            "// TODO: fix (#123)\n\
+             // TODO: fix\n\
+             // FIXME: see PROJ-42\n",
+        )
+        .unwrap();
+        let rust_files = vec![module];
+
+        // Opt-in: silent by default
+        let findings = check_code_patterns(&rust_files, project_root, &Config::default());
+        assert!(!findings.iter().any(|f| f.code == "CODE035"));
+
+        let mut config = Config::default();
+        config.checks.enabled.insert("CODE035".to_string(), true);
+        let findings = check_code_patterns(&rust_files, project_root, &config);
+        let flagged: Vec<&Finding> = findings.iter().filter(|f| f.code == "CODE035").collect();
+        assert_eq!(flagged.len(), 1);
+        assert_eq!(flagged[0].line_number, Some(2));
+
+        config.checks.todo_issue_pattern = Some(r"\(#\d+\)".to_string());
+        let findings = check_code_patterns(&rust_files, project_root, &config);
+        assert_eq!(findings.iter().filter(|f| f.code == "CODE035").count(), 2);
+    }
+
+    #[test]
+    fn test_check_code_patterns_unwrap_err() {
+        let temp_dir = create_test_dir();
+        let project_root = temp_dir.path();
+        let src_dir = project_root.join("src");
+
+        fs::create_dir_all(&src_dir).unwrap();
+
+        let module = src_dir.join("module.rs");
+        fs::write(
+            &module,
+            "fn f() {\n    let e = result.unwrap_err();\n    if result.is_err() {}\n    other.expect_err(\"ok\");\n}\n",
+        )
+        .unwrap();
+
+        let findings = check_code_patterns(&[module], project_root, &Config::default());
+        let flagged: Vec<&Finding> = findings.iter().filter(|f| f.code == "CODE027").collect();
+        assert_eq!(flagged.len(), 2);
+        assert_eq!(flagged[0].line_number, Some(2));
+        assert!(flagged[0].message.contains("'.unwrap_err()'"));
+        assert_eq!(flagged[1].line_number, Some(4));
+        assert!(flagged[1].message.contains("'.expect_err()'"));
+        assert!(
+            !findings
+                .iter()
+                .any(|f| f.code == "CODE001" || f.code == "CODE002")
+        );
+    }
+
+    #[test]
+    fn test_check_rust_toolchain_nightly() {
+        let temp_dir = create_test_dir();
+        let project_root = temp_dir.path();
+        fs::create_dir_all(project_root.join("src")).unwrap();
+        fs::write(project_root.join("src/lib.rs"), "pub fn f() {}\n").unwrap();
+        fs::write(
+            project_root.join("rust-toolchain.toml"),
+            "[toolchain]\nchannel = \"nightly-2024-05-01\"\n",
+        )
+        .unwrap();
+
+        let findings = check_rust_toolchain(project_root, &Config::default());
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].code, "ED006");
+
+        // Nightly is expected when the crate uses unstable features
+        fs::write(
+            project_root.join("src/lib.rs"),
+            "#![feature(never_type)]\npub fn f() {}\n",
+        )
+        .unwrap();
+        assert!(check_rust_toolchain(project_root, &Config::default()).is_empty());
+    }
+
+    #[test]
+    fn test_check_rust_toolchain_old_stable_pin() {
+        let temp_dir = create_test_dir();
+        let project_root = temp_dir.path();
+        fs::write(
+            project_root.join("rust-toolchain.toml"),
+            "[toolchain]\nchannel = \"1.60.0\"\n",
+        )
+        .unwrap();
+
+        let mut config = Config::default();
+        config.checks.latest_rust_version = Some("1.85".to_string());
+        let findings = check_rust_toolchain(project_root, &config);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].code, "ED007");
+        assert!(findings[0].message.contains("1.85"));
+
+        // A recent pin and the legacy plain-text file are fine
+        fs::remove_file(project_root.join("rust-toolchain.toml")).unwrap();
+        fs::write(project_root.join("rust-toolchain"), "1.80\n").unwrap();
+        assert!(check_rust_toolchain(project_root, &config).is_empty());
+    }
 
-        // Should not find CODE001, CODE002, or CODE003 in build.rs
-        assert!(!findings.iter().any(|f| f.code == "CODE001"));
-        assert!(!findings.iter().any(|f| f.code == "CODE002"));
-        assert!(!findings.iter().any(|f| f.code == "CODE003"));
+    #[test]
+    fn test_check_workspace_members_flags_unlisted_crate() {
+        let temp_dir = create_test_dir();
+        let project_root = temp_dir.path();
+        for dir in ["crates/core", "tools", "scratch", "forgotten"] {
+            fs::create_dir_all(project_root.join(dir)).unwrap();
+            fs::write(project_root.join(dir).join("Cargo.toml"), "[package]\n").unwrap();
+        }
+        fs::write(
+            project_root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"crates/*\", \"tool*\"]\nexclude = [\"scratch\"]\n",
+        )
+        .unwrap();
+        let manifest = CargoManifest::parse(&project_root.join("Cargo.toml")).unwrap();
+
+        let findings = check_workspace_members(project_root, &manifest, &Config::default());
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].code, "STRUCT009");
+        assert!(findings[0].message.contains("'forgotten'"));
 
-        // But should still find TODO comments
-        // (if we add one to the test content)
+        // Not a workspace root: nothing to check
+        let manifest = create_test_manifest("test-project");
+        assert!(check_workspace_members(project_root, &manifest, &Config::default()).is_empty());
+    }
+
+    #[test]
+    fn test_check_workspace_editions() {
+        let temp_dir = create_test_dir();
+        let project_root = temp_dir.path();
+        let members = [
+            (
+                "crates/api",
+                "name = \"api\"\nversion.workspace = true\nedition.workspace = true",
+            ),
+            (
+                "crates/cli",
+                "name = \"cli\"\nversion = \"0.1.0\"\nedition = \"2021\"",
+            ),
+            (
+                "crates/legacy",
+                "name = \"legacy\"\nversion = \"0.1.0\"\nedition = \"2018\"",
+            ),
+        ];
+        for (dir, package) in members {
+            fs::create_dir_all(project_root.join(dir)).unwrap();
+            fs::write(
+                project_root.join(dir).join("Cargo.toml"),
+                format!("[package]\n{package}\n"),
+            )
+            .unwrap();
+        }
+        fs::write(
+            project_root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"crates/*\"]\nexclude = [\"crates/legacy\"]\n\n[workspace.package]\nversion = \"0.1.0\"\nedition = \"2021\"\n",
+        )
+        .unwrap();
+        let manifest = CargoManifest::parse(&project_root.join("Cargo.toml")).unwrap();
+
+        // The excluded 2018 crate does not count; the rest agree on 2021
+        assert!(check_workspace_editions(project_root, &manifest, &Config::default()).is_empty());
+
+        fs::write(
+            project_root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"crates/*\"]\n\n[workspace.package]\nversion = \"0.1.0\"\nedition = \"2021\"\n",
+        )
+        .unwrap();
+        let manifest = CargoManifest::parse(&project_root.join("Cargo.toml")).unwrap();
+        let findings = check_workspace_editions(project_root, &manifest, &Config::default());
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].code, "ED008");
+        assert!(
+            findings[0]
+                .message
+                .contains("2018 (legacy); 2021 (api, cli)")
+        );
     }
 
     #[test]
@@ -533,7 +2771,7 @@ fn main() {
         let non_existent_file = project_root.join("nonexistent.rs");
         let rust_files = vec![non_existent_file];
 
-        let findings = check_code_patterns(&rust_files, project_root);
+        let findings = check_code_patterns(&rust_files, project_root, &Config::default());
 
         assert_eq!(findings.len(), 1);
         assert_eq!(findings[0].code, "IO001");
@@ -586,6 +2824,30 @@ fn main() {
         assert!(findings.iter().any(|f| f.code == "STRUCT003"));
     }
 
+    #[test]
+    fn test_check_project_structure_license_file() {
+        let temp_dir = create_test_dir();
+        let project_root = temp_dir.path();
+        fs::create_dir_all(project_root.join("src")).unwrap();
+        fs::write(project_root.join("src/main.rs"), "fn main() {}").unwrap();
+
+        let mut manifest = create_test_manifest("test-project");
+        let package = manifest.package.as_mut().unwrap();
+        package.license = None;
+        package.license_file = Some("legal/TERMS.txt".to_string());
+
+        // Referenced file missing
+        let findings = check_project_structure(project_root, Some(&manifest));
+        let struct003 = findings.iter().find(|f| f.code == "STRUCT003").unwrap();
+        assert!(struct003.message.contains("legal/TERMS.txt"));
+
+        // Referenced file present
+        fs::create_dir_all(project_root.join("legal")).unwrap();
+        fs::write(project_root.join("legal/TERMS.txt"), "terms").unwrap();
+        let findings = check_project_structure(project_root, Some(&manifest));
+        assert!(!findings.iter().any(|f| f.code == "STRUCT003"));
+    }
+
     #[test]
     fn test_check_project_structure_with_readme_and_license() {
         let temp_dir = create_test_dir();
@@ -606,6 +2868,106 @@ fn main() {
         assert!(!findings.iter().any(|f| f.code == "STRUCT003"));
     }
 
+    #[test]
+    fn test_check_project_structure_main_without_fn_main() {
+        let temp_dir = create_test_dir();
+        let project_root = temp_dir.path();
+        let src_dir = project_root.join("src");
+
+        fs::create_dir_all(&src_dir).unwrap();
+        fs::write(src_dir.join("main.rs"), "").unwrap();
+
+        let manifest = create_test_manifest("test-project");
+
+        let findings = check_project_structure(project_root, Some(&manifest));
+
+        let finding = findings.iter().find(|f| f.code == "STRUCT007").unwrap();
+        assert_eq!(finding.severity, Severity::Warning);
+        assert!(finding.file_path.as_ref().unwrap().ends_with("main.rs"));
+    }
+
+    #[test]
+    fn test_check_project_structure_bin_targets_with_fn_main() {
+        let temp_dir = create_test_dir();
+        let project_root = temp_dir.path();
+        let bin_dir = project_root.join("src").join("bin");
+
+        fs::create_dir_all(&bin_dir).unwrap();
+        fs::write(project_root.join("src/main.rs"), "fn main() {}").unwrap();
+        fs::write(bin_dir.join("tool.rs"), "pub async fn main() {}").unwrap();
+        fs::write(bin_dir.join("broken.rs"), "fn helper() {}").unwrap();
+
+        let mut manifest = create_test_manifest("test-project");
+        manifest.bin = Some(vec![
+            BinTarget {
+                name: Some("tool".to_string()),
+                path: Some("src/bin/tool.rs".to_string()),
+                required_features: Vec::new(),
+            },
+            BinTarget {
+                name: Some("broken".to_string()),
+                path: Some("src/bin/broken.rs".to_string()),
+                required_features: Vec::new(),
+            },
+        ]);
+
+        let findings = check_project_structure(project_root, Some(&manifest));
+
+        let struct007: Vec<&Finding> = findings.iter().filter(|f| f.code == "STRUCT007").collect();
+        assert_eq!(struct007.len(), 1);
+        assert!(
+            struct007[0]
+                .file_path
+                .as_ref()
+                .unwrap()
+                .ends_with("broken.rs")
+        );
+    }
+
+    #[test]
+    fn test_check_project_structure_feature_gated_bin() {
+        let temp_dir = create_test_dir();
+        let project_root = temp_dir.path();
+        let bin_dir = project_root.join("src").join("bin");
+
+        fs::create_dir_all(&bin_dir).unwrap();
+        fs::write(project_root.join("src/main.rs"), "fn main() {}").unwrap();
+        fs::write(bin_dir.join("gated.rs"), "fn helper() {}").unwrap();
+        fs::write(bin_dir.join("default_on.rs"), "fn helper() {}").unwrap();
+
+        let mut manifest = create_test_manifest("test-project");
+        manifest.features = Some(HashMap::from([
+            ("default".to_string(), vec!["cli".to_string()]),
+            ("cli".to_string(), Vec::new()),
+            ("server".to_string(), Vec::new()),
+        ]));
+        manifest.bin = Some(vec![
+            BinTarget {
+                name: Some("gated".to_string()),
+                path: Some("src/bin/gated.rs".to_string()),
+                required_features: vec!["server".to_string()],
+            },
+            BinTarget {
+                name: Some("default_on".to_string()),
+                path: Some("src/bin/default_on.rs".to_string()),
+                required_features: vec!["cli".to_string()],
+            },
+        ]);
+
+        let findings = check_project_structure(project_root, Some(&manifest));
+
+        // Only the binary whose features are on by default is reported
+        let struct007: Vec<&Finding> = findings.iter().filter(|f| f.code == "STRUCT007").collect();
+        assert_eq!(struct007.len(), 1);
+        assert!(
+            struct007[0]
+                .file_path
+                .as_ref()
+                .unwrap()
+                .ends_with("default_on.rs")
+        );
+    }
+
     #[test]
     fn test_check_missing_denied_lints_missing_warnings() {
         let temp_dir = create_test_dir();
@@ -675,6 +3037,492 @@ pub fn hello() {
         assert!(!findings.iter().any(|f| f.code == "LINT001"));
     }
 
+    #[test]
+    fn test_check_forbid_unsafe_code_suggested_when_no_unsafe() {
+        let temp_dir = create_test_dir();
+        let project_root = temp_dir.path();
+        let src_dir = project_root.join("src");
+
+        fs::create_dir_all(&src_dir).unwrap();
+        fs::write(
+            src_dir.join("lib.rs"),
+            "// unsafe { } in a comment does not count\npub fn safe() {}\n",
+        )
+        .unwrap();
+
+        let rust_files = collect_rust_files(project_root);
+        let findings = check_forbid_unsafe_code(&rust_files, project_root, &Config::default());
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].code, "LINT002");
+        assert!(findings[0].file_path.as_ref().unwrap().ends_with("lib.rs"));
+    }
+
+    #[test]
+    fn test_check_forbid_unsafe_code_already_forbidden() {
+        let temp_dir = create_test_dir();
+        let project_root = temp_dir.path();
+        let src_dir = project_root.join("src");
+
+        fs::create_dir_all(&src_dir).unwrap();
+        fs::write(
+            src_dir.join("lib.rs"),
+            "#![forbid(unsafe_code)]\npub fn safe() {}\n",
+        )
+        .unwrap();
+
+        let rust_files = collect_rust_files(project_root);
+        let findings = check_forbid_unsafe_code(&rust_files, project_root, &Config::default());
+
+        assert!(findings.is_empty());
+    }
+
+    #[test]
+    fn test_check_forbid_unsafe_code_skipped_when_unsafe_used() {
+        let temp_dir = create_test_dir();
+        let project_root = temp_dir.path();
+        let src_dir = project_root.join("src");
+
+        fs::create_dir_all(&src_dir).unwrap();
+        fs::write(src_dir.join("lib.rs"), "mod ffi;\n").unwrap();
+        fs::write(
+            src_dir.join("ffi.rs"),
+            "pub fn read(p: *const u8) -> u8 {\n    unsafe { *p }\n}\n",
+        )
+        .unwrap();
+
+        let rust_files = collect_rust_files(project_root);
+        assert!(uses_unsafe(&rust_files));
+
+        let findings = check_forbid_unsafe_code(&rust_files, project_root, &Config::default());
+        assert!(findings.is_empty());
+    }
+
+    #[test]
+    fn test_check_async_runtime() {
+        let temp_dir = create_test_dir();
+        let project_root = temp_dir.path();
+        let src_dir = project_root.join("src");
+
+        fs::create_dir_all(&src_dir).unwrap();
+        fs::write(
+            src_dir.join("lib.rs"),
+            "pub async fn fetch() -> u8 {\n    1\n}\n",
+        )
+        .unwrap();
+        let rust_files = collect_rust_files(project_root);
+
+        let mut manifest = create_test_manifest("async-crate");
+        let findings = check_async_runtime(&rust_files, &manifest, &Config::default());
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].code, "CODE019");
+        assert_eq!(findings[0].line_number, Some(1));
+
+        manifest.dependencies = Some(HashMap::from([(
+            "tokio".to_string(),
+            Dependency::Version("1".to_string()),
+        )]));
+        let findings = check_async_runtime(&rust_files, &manifest, &Config::default());
+        assert!(findings.is_empty());
+    }
+
+    #[test]
+    fn test_check_println_with_logging_crate() {
+        let temp_dir = create_test_dir();
+        let project_root = temp_dir.path();
+        let src_dir = project_root.join("src");
+
+        fs::create_dir_all(&src_dir).unwrap();
+        fs::create_dir_all(project_root.join("examples")).unwrap();
+        fs::write(
+            src_dir.join("lib.rs"),
+            "pub fn run() {\n    // println!(\"old\");\n    println!(\"starting\");\n}\n\n#[cfg(test)]\nmod tests {\n    fn t() { println!(\"debug\"); }\n}\n",
+        )
+        .unwrap();
+        fs::write(
+            project_root.join("examples/demo.rs"),
+            "fn main() { println!(\"hi\"); }\n",
+        )
+        .unwrap();
+        let rust_files = collect_rust_files(project_root);
+
+        let mut manifest = create_test_manifest("logging-crate");
+        manifest.dependencies = Some(HashMap::from([(
+            "log".to_string(),
+            Dependency::Version("0.4".to_string()),
+        )]));
+
+        // Opt-in: silent by default
+        assert!(
+            check_println_with_logging_crate(
+                &rust_files,
+                project_root,
+                &manifest,
+                &Config::default()
+            )
+            .is_empty()
+        );
+
+        let mut config = Config::default();
+        config.checks.enabled.insert("CODE032".to_string(), true);
+        let findings =
+            check_println_with_logging_crate(&rust_files, project_root, &manifest, &config);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].code, "CODE032");
+        assert_eq!(findings[0].line_number, Some(3));
+        assert!(findings[0].message.contains("`log`"));
+
+        // Without a logging crate there is nothing to be inconsistent with
+        manifest.dependencies = None;
+        assert!(
+            check_println_with_logging_crate(&rust_files, project_root, &manifest, &config)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_check_test_files_without_tests() {
+        let temp_dir = create_test_dir();
+        let project_root = temp_dir.path();
+        let tests_dir = project_root.join("tests");
+
+        fs::create_dir_all(tests_dir.join("suite")).unwrap();
+        fs::create_dir_all(tests_dir.join("common")).unwrap();
+        fs::write(tests_dir.join("foo.rs"), "").unwrap();
+        fs::write(tests_dir.join("api.rs"), "#[test]\nfn works() {}\n").unwrap();
+        fs::write(
+            tests_dir.join("runtime.rs"),
+            "#[tokio::test]\nasync fn works() {}\n",
+        )
+        .unwrap();
+        fs::write(tests_dir.join("common.rs"), "pub fn setup() {}\n").unwrap();
+        fs::write(tests_dir.join("common/mod.rs"), "pub fn setup() {}\n").unwrap();
+        fs::write(tests_dir.join("suite/main.rs"), "mod cases;\n").unwrap();
+        fs::write(tests_dir.join("suite/cases.rs"), "fn helper() {}\n").unwrap();
+
+        let rust_files = collect_rust_files(project_root);
+        let findings =
+            check_test_files_without_tests(&rust_files, project_root, &Config::default());
+
+        let mut flagged: Vec<&str> = findings
+            .iter()
+            .map(|f| f.file_path.as_deref().unwrap())
+            .collect();
+        flagged.sort();
+        assert_eq!(flagged.len(), 2);
+        assert!(flagged[0].ends_with("foo.rs"));
+        assert!(flagged[1].ends_with("main.rs"));
+        assert!(findings.iter().all(|f| f.code == "STRUCT010"));
+    }
+
+    #[test]
+    fn test_check_async_main_runtime() {
+        let temp_dir = create_test_dir();
+        let project_root = temp_dir.path();
+        let bin_dir = project_root.join("src").join("bin");
+
+        fs::create_dir_all(&bin_dir).unwrap();
+        fs::write(
+            project_root.join("src/main.rs"),
+            "#[tokio::main]\nasync fn main() {}\n",
+        )
+        .unwrap();
+        fs::write(
+            bin_dir.join("worker.rs"),
+            "#[async_std::main]\nasync fn main() {}\n",
+        )
+        .unwrap();
+
+        let mut manifest = create_test_manifest("async-bin");
+        let findings = check_async_main_runtime(project_root, &manifest, &Config::default());
+        assert_eq!(findings.len(), 2);
+        assert!(findings.iter().all(|f| f.code == "CODE030"));
+        assert_eq!(findings[0].line_number, Some(1));
+        assert!(
+            findings[0]
+                .message
+                .contains("`tokio` is not in [dependencies]")
+        );
+        assert!(findings[1].message.contains("`async-std`"));
+
+        manifest.dependencies = Some(HashMap::from([
+            ("tokio".to_string(), Dependency::Version("1".to_string())),
+            (
+                "async-std".to_string(),
+                Dependency::Version("1".to_string()),
+            ),
+        ]));
+        let findings = check_async_main_runtime(project_root, &manifest, &Config::default());
+        assert!(findings.is_empty());
+    }
+
+    #[test]
+    fn test_check_build_script_rerun() {
+        let temp_dir = create_test_dir();
+        let project_root = temp_dir.path();
+        let mut config = Config::default();
+        config.checks.enabled.insert("STRUCT011".to_string(), true);
+
+        fs::write(
+            project_root.join("build.rs"),
+            "fn main() {\n    let schema = std::fs::read_to_string(\"schema.json\").unwrap();\n    let _ = schema;\n}\n",
+        )
+        .unwrap();
+        // Opt-in: silent by default
+        assert!(check_build_script_rerun(project_root, &Config::default()).is_empty());
+        let findings = check_build_script_rerun(project_root, &config);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].code, "STRUCT011");
+        assert!(
+            findings[0]
+                .file_path
+                .as_deref()
+                .unwrap()
+                .ends_with("build.rs")
+        );
+
+        fs::write(
+            project_root.join("build.rs"),
+            "fn main() {\n    println!(\"cargo:rerun-if-changed=schema.json\");\n    let _ = std::fs::read_to_string(\"schema.json\");\n}\n",
+        )
+        .unwrap();
+        assert!(check_build_script_rerun(project_root, &config).is_empty());
+    }
+
+    #[test]
+    fn test_check_binary_name_collisions() {
+        let temp_dir = create_test_dir();
+        let project_root = temp_dir.path();
+        let bin_dir = project_root.join("src/bin");
+
+        fs::create_dir_all(bin_dir.join("bar")).unwrap();
+        fs::write(bin_dir.join("foo.rs"), "fn main() {}\n").unwrap();
+        fs::write(bin_dir.join("bar.rs"), "fn main() {}\n").unwrap();
+        fs::write(bin_dir.join("bar/main.rs"), "fn main() {}\n").unwrap();
+        fs::write(bin_dir.join("baz.rs"), "fn main() {}\n").unwrap();
+
+        let mut manifest = create_test_manifest("bins");
+        manifest.bin = Some(vec![
+            BinTarget {
+                name: Some("foo".to_string()),
+                path: Some("src/tools/foo.rs".to_string()),
+                required_features: Vec::new(),
+            },
+            // Points at the discovered file itself, so not a collision
+            BinTarget {
+                name: Some("baz".to_string()),
+                path: Some("src/bin/baz.rs".to_string()),
+                required_features: Vec::new(),
+            },
+        ]);
+
+        let findings = check_binary_name_collisions(project_root, &manifest, &Config::default());
+        assert_eq!(findings.len(), 2);
+        assert!(findings.iter().all(|f| f.code == "STRUCT012"));
+        assert!(findings[0].message.contains("binary 'bar'"));
+        assert!(findings[1].message.contains("[[bin]] 'foo'"));
+        assert!(findings[1].message.contains("src/bin/foo.rs"));
+        assert!(!findings[1].message.contains("only when building"));
+
+        manifest.bin.as_mut().unwrap()[0].required_features = vec!["tools".to_string()];
+        let findings = check_binary_name_collisions(project_root, &manifest, &Config::default());
+        assert!(
+            findings[1]
+                .message
+                .contains("(only when building with features: tools)")
+        );
+    }
+
+    #[test]
+    fn test_check_large_source_assets() {
+        let temp_dir = create_test_dir();
+        let project_root = temp_dir.path();
+        let assets_dir = project_root.join("src").join("assets");
+        fs::create_dir_all(&assets_dir).unwrap();
+        fs::write(project_root.join("src/lib.rs"), "x".repeat(2 * 1024 * 1024)).unwrap();
+        fs::write(assets_dir.join("small.json"), "{}").unwrap();
+        fs::write(assets_dir.join("blob.bin"), vec![0u8; 1024 * 1024 + 1]).unwrap();
+
+        let findings = check_large_source_assets(project_root, &Config::default());
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].code, "STRUCT013");
+        assert!(findings[0].message.contains("blob.bin"));
+        assert!(findings[0].message.contains("1.0 MiB"));
+
+        let mut config = Config::default();
+        config.checks.max_asset_size_kib = Some(2048);
+        assert!(check_large_source_assets(project_root, &config).is_empty());
+    }
+
+    #[test]
+    fn test_check_line_endings() {
+        let temp_dir = create_test_dir();
+        let src_dir = temp_dir.path().join("src");
+        fs::create_dir_all(&src_dir).unwrap();
+
+        let rust_files: Vec<PathBuf> = ["lib.rs", "a.rs", "b.rs"]
+            .iter()
+            .map(|name| src_dir.join(name))
+            .collect();
+        fs::write(&rust_files[0], "pub mod a;\npub mod b;\n").unwrap();
+        fs::write(&rust_files[1], "pub fn a() {}\n").unwrap();
+        fs::write(&rust_files[2], "pub fn b() {\r\n}\r\n").unwrap();
+
+        // Opt-in: silent by default
+        assert!(check_line_endings(&rust_files, &Config::default()).is_empty());
+
+        let mut config = Config::default();
+        config.checks.enabled.insert("CODE036".to_string(), true);
+        let findings = check_line_endings(&rust_files, &config);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].code, "CODE036");
+        assert!(findings[0].file_path.as_ref().unwrap().ends_with("b.rs"));
+
+        // Mostly CRLF: the crate does not standardize on LF
+        fs::write(&rust_files[1], "pub fn a() {}\r\n").unwrap();
+        assert!(check_line_endings(&rust_files, &config).is_empty());
+    }
+
+    #[test]
+    fn test_duplicate_test_names() {
+        let temp_dir = create_test_dir();
+        let src_dir = temp_dir.path().join("src");
+        fs::create_dir_all(&src_dir).unwrap();
+
+        let rust_files: Vec<PathBuf> = ["lib.rs", "parser.rs", "render.rs"]
+            .iter()
+            .map(|name| src_dir.join(name))
+            .collect();
+        fs::write(&rust_files[0], "mod parser;\nmod render;\n").unwrap();
+        fs::write(
+            &rust_files[1],
+            "#[cfg(test)]\nmod tests {\n    #[test]\n    fn works() {}\n\n    #[test]\n    fn parses() {}\n}\n",
+        )
+        .unwrap();
+        fs::write(
+            &rust_files[2],
+            "#[cfg(test)]\nmod tests {\n    #[test]\n    #[ignore]\n    fn works() {}\n}\n",
+        )
+        .unwrap();
+
+        // Opt-in: silent by default
+        assert!(
+            check_duplicate_test_names(temp_dir.path(), &rust_files, &Config::default()).is_empty()
+        );
+
+        let mut config = Config::default();
+        config.checks.enabled.insert("CODE042".to_string(), true);
+        let findings = check_duplicate_test_names(temp_dir.path(), &rust_files, &config);
+        assert_eq!(findings.len(), 1, "{findings:?}");
+        assert_eq!(findings[0].code, "CODE042");
+        assert_eq!(findings[0].line_number, Some(4));
+        assert!(
+            findings[0]
+                .file_path
+                .as_ref()
+                .unwrap()
+                .ends_with("parser.rs")
+        );
+        assert!(findings[0].message.contains("`works`"));
+        assert!(findings[0].message.contains("src/parser.rs:4"));
+        assert!(findings[0].message.contains("src/render.rs:5"));
+    }
+
+    #[test]
+    fn test_check_project_structure_build_rs_without_build_deps() {
+        let temp_dir = create_test_dir();
+        let project_root = temp_dir.path();
+
+        fs::create_dir_all(project_root.join("src")).unwrap();
+        fs::write(project_root.join("src/lib.rs"), "").unwrap();
+        fs::write(
+            project_root.join("build.rs"),
+            "fn main() {\n    cc::Build::new().file(\"src/native.c\").compile(\"native\");\n}\n",
+        )
+        .unwrap();
+
+        let mut manifest = create_test_manifest("native-crate");
+        let findings = check_project_structure(project_root, Some(&manifest));
+        let struct008: Vec<_> = findings.iter().filter(|f| f.code == "STRUCT008").collect();
+        assert_eq!(struct008.len(), 1);
+        assert!(struct008[0].message.contains("'cc'"));
+
+        manifest.build_dependencies = Some(HashMap::from([(
+            "cc".to_string(),
+            Dependency::Version("1".to_string()),
+        )]));
+        let findings = check_project_structure(project_root, Some(&manifest));
+        assert!(!findings.iter().any(|f| f.code == "STRUCT008"));
+    }
+
+    #[test]
+    fn test_check_no_std_usage() {
+        let temp_dir = create_test_dir();
+        let project_root = temp_dir.path();
+        let src_dir = project_root.join("src");
+
+        fs::create_dir_all(&src_dir).unwrap();
+        fs::write(
+            src_dir.join("lib.rs"),
+            "#![no_std]\n\nuse core::fmt;\nuse std::collections::HashMap;\n",
+        )
+        .unwrap();
+
+        let rust_files = collect_rust_files(project_root);
+        assert!(is_no_std_crate(project_root));
+        let findings = check_no_std_usage(&rust_files, project_root, &Config::default());
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].code, "CODE022");
+        assert_eq!(findings[0].line_number, Some(4));
+
+        // Crates without #![no_std] are not affected
+        fs::write(src_dir.join("lib.rs"), "use std::collections::HashMap;\n").unwrap();
+        assert!(!is_no_std_crate(project_root));
+        assert!(check_no_std_usage(&rust_files, project_root, &Config::default()).is_empty());
+    }
+
+    #[test]
+    fn test_collect_rust_files_with_extra_dirs() {
+        let temp_dir = create_test_dir();
+        let project_root = temp_dir.path();
+
+        fs::create_dir_all(project_root.join("src")).unwrap();
+        fs::create_dir_all(project_root.join("xtask/src")).unwrap();
+        fs::write(project_root.join("src/lib.rs"), "").unwrap();
+        fs::write(project_root.join("xtask/src/main.rs"), "fn main() {}").unwrap();
+
+        assert_eq!(collect_rust_files(project_root).len(), 1);
+
+        let files = collect_rust_files_with_extra_dirs(
+            project_root,
+            &["xtask".to_string(), "fuzz".to_string()],
+        );
+        assert_eq!(files.len(), 2);
+        assert!(files.contains(&project_root.join("xtask/src/main.rs")));
+    }
+
+    #[test]
+    fn test_collect_source_files_with_extra_extension() {
+        let temp_dir = create_test_dir();
+        let project_root = temp_dir.path();
+
+        fs::create_dir_all(project_root.join("src")).unwrap();
+        fs::write(project_root.join("src/lib.rs"), "").unwrap();
+        fs::write(project_root.join("src/generated.rs.in"), "").unwrap();
+        fs::write(project_root.join("src/notes.in"), "").unwrap();
+
+        let mut config = Config::default();
+        assert_eq!(
+            collect_source_files(project_root, &[], &config.source_extensions()).len(),
+            1
+        );
+
+        config.general.source_extensions = Some(vec!["rs".to_string(), ".rs.in".to_string()]);
+        let files = collect_source_files(project_root, &[], &config.source_extensions());
+        assert_eq!(files.len(), 2);
+        assert!(files.contains(&project_root.join("src/generated.rs.in")));
+    }
+
     #[test]
     fn test_regex_patterns() {
         // Test the static regex patterns
@@ -686,15 +3534,20 @@ pub fn hello() {
         assert!(EXPECT_REGEX.is_match("result.expect("));
         assert!(!EXPECT_REGEX.is_match("expected_value"));
 
-        assert!(PRINTLN_DBG_REGEX.is_match("println!(\"hello\")"));
-        assert!(PRINTLN_DBG_REGEX.is_match("dbg!(value)"));
-        assert!(!PRINTLN_DBG_REGEX.is_match("print_value()"));
+        assert!(PRINT_MACRO_REGEX.is_match("println!(\"hello\")"));
+        assert!(PRINT_MACRO_REGEX.is_match("dbg!(value)"));
+        assert!(PRINT_MACRO_REGEX.is_match("eprint!(\"oops\")"));
+        assert!(!PRINT_MACRO_REGEX.is_match("print_value()"));
+        assert!(!PRINT_MACRO_REGEX.is_match("format!(\"{x}\")"));
 
         assert!(TODO_COMMENT_REGEX.is_match("// TODO: fix this"));
         assert!(TODO_COMMENT_REGEX.is_match("// FIXME: broken"));
         assert!(TODO_COMMENT_REGEX.is_match("//XXX urgent"));
         assert!(!TODO_COMMENT_REGEX.is_match("// NOTE: this is fine"));
 
+        assert!(TRY_MACRO_REGEX.is_match("let x = try!(foo());"));
+        assert!(!TRY_MACRO_REGEX.is_match("let x = self.try_foo();"));
+
         assert!(DENY_LINT_REGEX.is_match("#![deny(warnings)]"));
         assert!(DENY_LINT_REGEX.is_match("#![deny(clippy::all, warnings)]"));
     }
@@ -722,7 +3575,7 @@ pub fn function_{i}() {{
         }
 
         let rust_files = collect_rust_files(project_root);
-        let findings = check_code_patterns(&rust_files, project_root);
+        let findings = check_code_patterns(&rust_files, project_root, &Config::default());
 
         // Should find issues in all files
         assert_eq!(findings.iter().filter(|f| f.code == "CODE001").count(), 10); // unwrap
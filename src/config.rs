@@ -3,10 +3,15 @@
 //! This module defines the configuration structures and logic for Cargo Dokita.
 //!
 //! ## Features
-//! - Loads configuration from a TOML file (`.cargo-dokita.toml`) in the project root.
+//! - Loads configuration from a TOML file (`.cargo-dokita.toml`) in the project root,
+//!   or from `[package.metadata.dokita]` in Cargo.toml when that file is absent.
+//!   In a workspace, a member's own `[package.metadata.dokita]` is layered on top of the
+//!   root configuration for findings in that member's files.
 //! - Strictly validates configuration fields using Serde's `deny_unknown_fields`.
 //! - Supports sections such as `[general]` and `[checks]` for extensible configuration.
 //! - Allows enabling/disabling specific checks by code (e.g., `MD001`).
+//! - Reads a small set of `DOKITA_*` environment variables via [`Config::from_env`].
+//! - Layers command-line and environment overrides on top of the file configuration via [`Config::merge`].
 //! - Provides default values if no configuration file is found.
 //! - Includes comprehensive tests for deserialization, error handling, and logic.
 //!
@@ -14,9 +19,22 @@
 //!
 //! ```toml
 //! [general]
+//! offline = false
+//! severity_threshold = "warning"
+//! fail_on = "error"
+//! jobs = 4
+//! exclude = ["src/generated"]
+//! extra_source_dirs = ["xtask"]
+//! registry_url = "https://crates.io/api/v1/crates"
+//! color = "auto"
+//! cache = true
+//! group_by = "category"
+//! collapse = false
 //!
 //! [checks]
 //! enabled = { "MD001" = true, "MD002" = false }
+//! ignore_prefixes = ["CODE"]
+//! outdated_severity = "warning"
 //! ```
 //!
 //! ## Usage
@@ -32,6 +50,19 @@
 //! }
 //! # Ok::<(), Box<dyn std::error::Error>>(())
 //! ```
+//!
+//! ## Environment variables
+//!
+//! | Variable                    | Setting              | Accepted values                  |
+//! | --------------------------- | -------------------- | -------------------------------- |
+//! | `DOKITA_OFFLINE`            | `offline`            | `1`, `0`, `true`, `false`        |
+//! | `DOKITA_SEVERITY_THRESHOLD` | `severity_threshold` | `error`, `warning`, `note`       |
+//! | `DOKITA_JOBS`               | `jobs`               | a positive integer               |
+//! | `DOKITA_REGISTRY_URL`       | `registry_url`       | base URL of a crates.io-like API |
+//! | `DOKITA_REGISTRY_TOKEN`     | `registry_token`     | bearer token for `registry_url`  |
+//!
+//! Precedence, from highest to lowest: command-line flags, environment variables,
+//! the project file, built-in defaults.
 
 //!
 //! # Config
@@ -39,14 +70,85 @@
 //! This module defines the configuration structure for Cargo Dokita.
 //! It uses TOML for configuration files and provides a way to load and validate the configuration.
 
-use serde::Deserialize;
-use std::collections::HashMap;
+use crate::crates_io_api::CRATES_IO_API_BASE;
+use crate::diagnostics::Severity;
+use serde::{Deserialize, Serialize, Serializer};
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use termcolor::ColorChoice;
 
 pub const CONFIG_FILE_NAME: &str = ".cargo-dokita.toml";
 
-#[derive(Deserialize, Debug, Default, Clone)]
+/// Lints whose `#[allow(...)]`/`#![allow(...)]` is discouraged unless configured otherwise.
+/// Crate-level allows of the clippy groups are reported as CODE031, everything else as CODE018.
+pub const DEFAULT_DISCOURAGED_ALLOWS: &[&str] = &[
+    "warnings",
+    "dead_code",
+    "unused",
+    "clippy::all",
+    "clippy::pedantic",
+];
+
+/// Prelude names whose top-level redefinition CODE021 reports unless configured otherwise.
+pub const DEFAULT_SHADOWED_PRELUDE_NAMES: &[&str] = &["Result", "Option", "Vec", "String", "Box"];
+
+/// File extensions scanned as Rust sources unless configured otherwise.
+pub const DEFAULT_SOURCE_EXTENSIONS: &[&str] = &["rs"];
+
+/// Parameter count above which CODE026 reports a function unless configured otherwise.
+pub const DEFAULT_MAX_FN_PARAMS: usize = 7;
+
+/// Pairs of crates serving the same purpose, reported by DP016 when both are dependencies.
+pub const DEFAULT_REDUNDANT_DEPENDENCY_PAIRS: &[(&str, &str)] = &[
+    ("lazy_static", "once_cell"),
+    ("serde_json", "simd-json"),
+    ("reqwest", "hyper"),
+    ("failure", "anyhow"),
+    ("structopt", "clap"),
+];
+
+/// Crates that belong in `[dev-dependencies]`, reported by DP019 when found in `[dependencies]`.
+pub const DEFAULT_DEV_ONLY_CRATES: &[&str] = &[
+    "criterion",
+    "proptest",
+    "quickcheck",
+    "tempfile",
+    "mockall",
+    "mockito",
+    "httpmock",
+    "wiremock",
+    "insta",
+    "pretty_assertions",
+    "assert_cmd",
+    "rstest",
+];
+
+/// Maximum number of simultaneous registry requests unless configured otherwise.
+pub const DEFAULT_NETWORK_CONCURRENCY: usize = 8;
+
+/// Size in KiB above which STRUCT013 reports a non-Rust file under `src/` unless configured.
+pub const DEFAULT_MAX_ASSET_SIZE_KIB: u64 = 1024;
+
+/// Latest stable Rust release ED007 compares pinned toolchains against unless configured.
+pub const DEFAULT_LATEST_RUST_VERSION: &str = "1.90";
+
+/// Issue reference CODE035 looks for after a TODO/FIXME unless configured: `#123`,
+/// tracker keys such as `JIRA-123`, or a URL.
+pub const DEFAULT_TODO_ISSUE_PATTERN: &str = r"#\d+|\b[A-Z][A-Z0-9]+-\d+\b|https?://";
+
+pub const ENV_OFFLINE: &str = "DOKITA_OFFLINE";
+pub const ENV_SEVERITY_THRESHOLD: &str = "DOKITA_SEVERITY_THRESHOLD";
+pub const ENV_JOBS: &str = "DOKITA_JOBS";
+pub const ENV_REGISTRY_URL: &str = "DOKITA_REGISTRY_URL";
+pub const ENV_REGISTRY_TOKEN: &str = "DOKITA_REGISTRY_TOKEN";
+/// Standard convention (<https://no-color.org>): any non-empty value disables color.
+pub const ENV_NO_COLOR: &str = "NO_COLOR";
+/// Standard convention: any non-empty value other than `0` forces color.
+pub const ENV_CLICOLOR_FORCE: &str = "CLICOLOR_FORCE";
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
 #[serde(deny_unknown_fields)] // Be strict about unknown config keys
 pub struct Config {
     #[serde(default)]
@@ -56,24 +158,216 @@ pub struct Config {
     // You could add more sections like 'thresholds', 'ignores', etc.
 }
 
-#[derive(Deserialize, Debug, Default, Clone)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
 #[serde(deny_unknown_fields)]
 pub struct GeneralConfig {
-    // Example: Minimum severity to report (Error, Warning, Note)
-    // pub report_severity_level: Option<String>, // Could map to Severity enum
+    /// Skip the crates.io version lookups used by the outdated-dependency check.
+    pub offline: Option<bool>,
+    /// Minimum severity to report. Findings below it are dropped.
+    pub severity_threshold: Option<Severity>,
+    /// Minimum severity that makes the run exit non-zero. Defaults to `Warning`.
+    pub fail_on: Option<Severity>,
+    /// Number of worker threads used by the parallel analysis phases.
+    pub jobs: Option<usize>,
+    /// Maximum number of registry version lookups in flight at once.
+    /// Defaults to [`DEFAULT_NETWORK_CONCURRENCY`]. Independent of `jobs`.
+    pub network_concurrency: Option<usize>,
+    /// Paths, relative to the project root, excluded from code pattern checks.
+    pub exclude: Option<Vec<String>>,
+    /// Base URL of the crates.io-compatible API used for version lookups.
+    pub registry_url: Option<String>,
+    /// Bearer token sent to a private `registry_url`. Only settable through the environment
+    /// or the command line so it never ends up in a committed config file.
+    #[serde(skip)]
+    pub registry_token: Option<String>,
+    /// When to colorize human-readable output. Defaults to `auto`.
+    pub color: Option<ColorMode>,
+    /// Reuse cached code-check results for unchanged files. Defaults to true.
+    pub cache: Option<bool>,
+    /// Run `cargo audit` for known vulnerabilities (SEC001, AUD*). Defaults to true.
+    pub vulnerability_scan: Option<bool>,
+    /// Skip the network and audit phase once a local check reports an error.
+    pub fail_fast: Option<bool>,
+    /// Group human-readable output into sections. Ungrouped by default.
+    pub group_by: Option<GroupBy>,
+    /// Collapse repeated codes within a file into one line in human-readable output.
+    pub collapse: Option<bool>,
+    /// Print the number of findings per check after the report, including checks that
+    /// found nothing and those that did not run.
+    pub summary_by_check: Option<bool>,
+    /// Prefix put in front of every code as the `rule_id` of JSON and JSON Lines output
+    /// (e.g. `"dokita/"`). The `code` field itself is unchanged. Empty by default.
+    pub rule_prefix: Option<String>,
+    /// Report at most this many findings. The exit code still considers all of them.
+    pub max_findings: Option<usize>,
+    /// Stop the analysis after this many seconds and report the findings collected so far,
+    /// plus a TIMEOUT001 warning. Unbounded by default.
+    pub deadline: Option<u64>,
+    /// Baseline file or previous `--format json` report. Only findings missing from it
+    /// are reported.
+    pub baseline: Option<String>,
+    /// How `baseline` is read. Defaults to `auto`.
+    pub baseline_format: Option<BaselineFormat>,
+    /// Write the findings of this run to this path as a versioned baseline file.
+    pub write_baseline: Option<String>,
+    /// Only run code pattern checks on files changed since this git ref.
+    pub since: Option<String>,
+    /// Extra paths skipped for a single run (`--ignore-path`). Combined with `exclude`
+    /// rather than replacing it.
+    pub ignore_paths: Option<Vec<String>>,
+    /// Directories, relative to the project root, scanned in addition to
+    /// `src`, `tests`, `examples` and `benches` (e.g. `xtask`, `fuzz`).
+    pub extra_source_dirs: Option<Vec<String>>,
+    /// File extensions, without the leading dot, scanned as Rust sources (e.g. `rs.in`).
+    /// Defaults to [`DEFAULT_SOURCE_EXTENSIONS`].
+    pub source_extensions: Option<Vec<String>>,
+}
+
+/// How human-readable output is sectioned.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum GroupBy {
+    /// Flat list, the same as leaving `group_by` unset.
+    None,
+    Category,
+    /// One section per file, findings sorted by line.
+    File,
 }
 
-#[derive(Deserialize, Debug, Default, Clone)]
+/// File format of `--baseline`.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum BaselineFormat {
+    /// A versioned baseline if the file has a `version` field, otherwise a report.
+    Auto,
+    /// A versioned baseline written with `--write-baseline`.
+    Baseline,
+    /// A report written with `--format json`.
+    Report,
+}
+
+/// Terminal color mode for human-readable output.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ColorMode {
+    Auto,
+    Always,
+    Never,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
 #[serde(deny_unknown_fields)]
 pub struct ChecksConfig {
     // Key: Check code (e.g., "MD001"), Value: enabled (true/false)
-    #[serde(default)]
+    #[serde(default, serialize_with = "serialize_sorted")]
     pub enabled: HashMap<String, bool>,
-    // Example: specific config for a check
-    // pub max_todo_comments: Option<usize>,
+    /// Lints whose blanket `allow` is flagged by CODE018.
+    /// Defaults to [`DEFAULT_DISCOURAGED_ALLOWS`].
+    pub discouraged_allows: Option<Vec<String>>,
+    /// Prelude names whose top-level redefinition is flagged by CODE021.
+    /// Defaults to [`DEFAULT_SHADOWED_PRELUDE_NAMES`].
+    pub shadowed_prelude_names: Option<Vec<String>>,
+    /// Functions with more parameters than this are flagged by CODE026.
+    /// Defaults to [`DEFAULT_MAX_FN_PARAMS`].
+    pub max_fn_params: Option<usize>,
+    /// Non-Rust files under `src/` larger than this many KiB are flagged by STRUCT013.
+    /// Defaults to [`DEFAULT_MAX_ASSET_SIZE_KIB`].
+    pub max_asset_size_kib: Option<u64>,
+    /// Latest stable Rust release, used by ED007 to spot outdated toolchain pins.
+    /// Defaults to [`DEFAULT_LATEST_RUST_VERSION`].
+    pub latest_rust_version: Option<String>,
+    /// Regex a TODO/FIXME comment must match to count as tracked by CODE035.
+    /// Defaults to [`DEFAULT_TODO_ISSUE_PATTERN`].
+    pub todo_issue_pattern: Option<String>,
+    /// Severity of DP002 (outdated dependency) findings. Defaults to `Note`.
+    pub outdated_severity: Option<Severity>,
+    /// Crates intentionally kept on an older version; DP002 never reports them.
+    pub outdated_ignore: Option<Vec<String>>,
+    /// Extra crate pairs reported by DP016, in addition to
+    /// [`DEFAULT_REDUNDANT_DEPENDENCY_PAIRS`].
+    pub redundant_dependency_pairs: Option<Vec<[String; 2]>>,
+    /// Extra crates reported by DP019, in addition to [`DEFAULT_DEV_ONLY_CRATES`].
+    pub dev_only_crates: Option<Vec<String>>,
+    /// Code prefixes (e.g. `"CODE"`, `"DP"`) whose findings are all dropped, unless a
+    /// code is explicitly set to `true` in `enabled`.
+    pub ignore_prefixes: Option<Vec<String>>,
+}
+
+// Printed configurations list check codes in a stable order
+fn serialize_sorted<S: Serializer>(
+    map: &HashMap<String, bool>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    map.iter().collect::<BTreeMap<_, _>>().serialize(serializer)
+}
+
+/// Commented `[general]` section of the `cargo dokita init` template. Every option is
+/// left commented out so the scaffolded file starts from the built-in defaults.
+const INIT_GENERAL_SECTION: &str = r#"[general]
+# offline = false                # Skip crates.io lookups for outdated dependencies
+# severity_threshold = "note"    # Only report findings at or above: error, warning, note
+# fail_on = "warning"            # Exit non-zero for findings at or above this severity
+# jobs = 4                       # Worker threads used for analysis
+# network_concurrency = 8        # Registry lookups in flight at once
+# exclude = ["src/generated"]    # Paths skipped by code checks
+# extra_source_dirs = ["xtask"]  # Scanned in addition to src, tests, examples and benches
+# source_extensions = ["rs"]     # File extensions scanned as Rust sources
+# registry_url = "https://crates.io/api/v1/crates"
+# color = "auto"                 # auto, always or never
+# cache = true                   # Reuse code-check results for unchanged files
+# vulnerability_scan = true      # Run cargo audit for known vulnerabilities
+# fail_fast = false              # Stop before network and audit checks after a local error
+# group_by = "category"          # Group human-readable output: none, category or file
+# collapse = false               # Collapse repeated codes per file in human-readable output
+# summary_by_check = false       # Print the number of findings per check after the report
+# max_findings = 100             # Report at most this many findings
+# deadline = 300                 # Stop after this many seconds and report partial findings
+# rule_prefix = "dokita/"        # Prefix of the rule_id in JSON output, for merged reports
+"#;
+
+/// Contents of the `.cargo-dokita.toml` written by `cargo dokita init`.
+///
+/// Lists every registered check with its description. Checks that run by default are
+/// set to `true`; opt-in checks are listed commented out so scaffolding a config does
+/// not change which checks run.
+pub fn init_template() -> String {
+    let mut template =
+        String::from("# Cargo Dokita configuration. See the README for every option.\n\n");
+    template.push_str(INIT_GENERAL_SECTION);
+    template.push_str("\n[checks.enabled]\n");
+    for check in crate::checks::CHECKS {
+        if check.enabled_by_default {
+            template.push_str(&format!(
+                "\"{}\" = true # {}\n",
+                check.code, check.description
+            ));
+        } else {
+            template.push_str(&format!(
+                "# \"{}\" = true # {}\n",
+                check.code, check.description
+            ));
+        }
+    }
+    template
+}
+
+/// Writes [`init_template`] to `.cargo-dokita.toml` in `project_root`.
+/// An existing file is only replaced when `force` is set.
+pub fn write_init_file(project_root: &Path, force: bool) -> Result<PathBuf, String> {
+    let config_path = project_root.join(CONFIG_FILE_NAME);
+    if config_path.exists() && !force {
+        return Err(format!(
+            "{config_path:?} already exists. Pass --force to overwrite it."
+        ));
+    }
+    fs::write(&config_path, init_template())
+        .map_err(|e| format!("Failed to write config file {config_path:?}: {e}"))?;
+    Ok(config_path)
 }
 
 impl Config {
+    /// Loads `.cargo-dokita.toml` from `project_root`. Without that file, falls back to the
+    /// `[package.metadata.dokita]` table of the project's Cargo.toml; the two are not merged.
     pub fn load_from_project_root(project_root: &Path) -> Result<Self, String> {
         let config_path = project_root.join(CONFIG_FILE_NAME);
         if config_path.exists() {
@@ -82,15 +376,443 @@ impl Config {
             toml::from_str(&content)
                 .map_err(|e| format!("Failed to parse config file {config_path:?}: {e}"))
         } else {
-            // Return default config if no file found
-            Ok(Config::default())
+            Self::load_from_manifest_metadata(project_root)
+        }
+    }
+
+    /// Reads `[package.metadata.dokita]`, which has the same layout as `.cargo-dokita.toml`.
+    /// A missing or unparsable Cargo.toml, or a missing table, yields the default config.
+    ///
+    /// Also used for each workspace member, whose table is layered on top of the root
+    /// configuration for findings in that member's files.
+    pub fn load_from_manifest_metadata(project_root: &Path) -> Result<Self, String> {
+        let manifest_path = project_root.join("Cargo.toml");
+        let table = fs::read_to_string(&manifest_path)
+            .ok()
+            .and_then(|content| toml::from_str::<toml::Value>(&content).ok())
+            .and_then(|manifest| {
+                manifest
+                    .get("package")?
+                    .get("metadata")?
+                    .get("dokita")
+                    .cloned()
+            });
+        match table {
+            Some(table) => table.try_into().map_err(|e| {
+                format!("Failed to parse [package.metadata.dokita] in {manifest_path:?}: {e}")
+            }),
+            None => Ok(Config::default()),
+        }
+    }
+
+    /// Layers `overrides` on top of `base`, field by field.
+    ///
+    /// Any setting present in `overrides` wins over the one in `base`; unset settings
+    /// fall through. Per-check `enabled` entries are merged key by key. Callers apply
+    /// the layers in increasing precedence: defaults, then the project file, then
+    /// environment variables, then command-line flags.
+    pub fn merge(base: Config, overrides: Config) -> Config {
+        let mut enabled = base.checks.enabled;
+        enabled.extend(overrides.checks.enabled);
+
+        Config {
+            general: GeneralConfig {
+                offline: overrides.general.offline.or(base.general.offline),
+                severity_threshold: overrides
+                    .general
+                    .severity_threshold
+                    .or(base.general.severity_threshold),
+                fail_on: overrides.general.fail_on.or(base.general.fail_on),
+                jobs: overrides.general.jobs.or(base.general.jobs),
+                network_concurrency: overrides
+                    .general
+                    .network_concurrency
+                    .or(base.general.network_concurrency),
+                exclude: overrides.general.exclude.or(base.general.exclude),
+                registry_url: overrides.general.registry_url.or(base.general.registry_url),
+                registry_token: overrides
+                    .general
+                    .registry_token
+                    .or(base.general.registry_token),
+                color: overrides.general.color.or(base.general.color),
+                cache: overrides.general.cache.or(base.general.cache),
+                vulnerability_scan: overrides
+                    .general
+                    .vulnerability_scan
+                    .or(base.general.vulnerability_scan),
+                fail_fast: overrides.general.fail_fast.or(base.general.fail_fast),
+                group_by: overrides.general.group_by.or(base.general.group_by),
+                collapse: overrides.general.collapse.or(base.general.collapse),
+                summary_by_check: overrides
+                    .general
+                    .summary_by_check
+                    .or(base.general.summary_by_check),
+                rule_prefix: overrides.general.rule_prefix.or(base.general.rule_prefix),
+                max_findings: overrides.general.max_findings.or(base.general.max_findings),
+                deadline: overrides.general.deadline.or(base.general.deadline),
+                baseline: overrides.general.baseline.or(base.general.baseline),
+                baseline_format: overrides
+                    .general
+                    .baseline_format
+                    .or(base.general.baseline_format),
+                write_baseline: overrides
+                    .general
+                    .write_baseline
+                    .or(base.general.write_baseline),
+                since: overrides.general.since.or(base.general.since),
+                ignore_paths: overrides.general.ignore_paths.or(base.general.ignore_paths),
+                extra_source_dirs: overrides
+                    .general
+                    .extra_source_dirs
+                    .or(base.general.extra_source_dirs),
+                source_extensions: overrides
+                    .general
+                    .source_extensions
+                    .or(base.general.source_extensions),
+            },
+            checks: ChecksConfig {
+                enabled,
+                discouraged_allows: overrides
+                    .checks
+                    .discouraged_allows
+                    .or(base.checks.discouraged_allows),
+                shadowed_prelude_names: overrides
+                    .checks
+                    .shadowed_prelude_names
+                    .or(base.checks.shadowed_prelude_names),
+                max_fn_params: overrides.checks.max_fn_params.or(base.checks.max_fn_params),
+                max_asset_size_kib: overrides
+                    .checks
+                    .max_asset_size_kib
+                    .or(base.checks.max_asset_size_kib),
+                latest_rust_version: overrides
+                    .checks
+                    .latest_rust_version
+                    .or(base.checks.latest_rust_version),
+                todo_issue_pattern: overrides
+                    .checks
+                    .todo_issue_pattern
+                    .or(base.checks.todo_issue_pattern),
+                outdated_severity: overrides
+                    .checks
+                    .outdated_severity
+                    .or(base.checks.outdated_severity),
+                outdated_ignore: overrides
+                    .checks
+                    .outdated_ignore
+                    .or(base.checks.outdated_ignore),
+                redundant_dependency_pairs: overrides
+                    .checks
+                    .redundant_dependency_pairs
+                    .or(base.checks.redundant_dependency_pairs),
+                dev_only_crates: overrides
+                    .checks
+                    .dev_only_crates
+                    .or(base.checks.dev_only_crates),
+                ignore_prefixes: overrides
+                    .checks
+                    .ignore_prefixes
+                    .or(base.checks.ignore_prefixes),
+            },
+        }
+    }
+
+    /// Builds the configuration layer contributed by `DOKITA_*` environment variables.
+    ///
+    /// Unset variables leave the corresponding setting unset. A variable that is set
+    /// to an invalid value is an error rather than being silently ignored.
+    pub fn from_env() -> Result<Self, String> {
+        Self::from_env_lookup(|name| std::env::var(name).ok())
+    }
+
+    fn from_env_lookup(lookup: impl Fn(&str) -> Option<String>) -> Result<Self, String> {
+        let mut config = Config::default();
+
+        if let Some(value) = lookup(ENV_OFFLINE) {
+            config.general.offline = Some(match value.trim().to_ascii_lowercase().as_str() {
+                "1" | "true" => true,
+                "0" | "false" => false,
+                _ => {
+                    return Err(format!(
+                        "Invalid value '{value}' for {ENV_OFFLINE}: expected 1, 0, true or false"
+                    ));
+                }
+            });
         }
+
+        if let Some(value) = lookup(ENV_SEVERITY_THRESHOLD) {
+            config.general.severity_threshold = Some(value.trim().parse().map_err(|_| {
+                format!(
+                    "Invalid value '{value}' for {ENV_SEVERITY_THRESHOLD}: expected error, warning or note"
+                )
+            })?);
+        }
+
+        if let Some(value) = lookup(ENV_JOBS) {
+            match value.trim().parse::<usize>() {
+                Ok(jobs) if jobs > 0 => config.general.jobs = Some(jobs),
+                _ => {
+                    return Err(format!(
+                        "Invalid value '{value}' for {ENV_JOBS}: expected a positive integer"
+                    ));
+                }
+            }
+        }
+
+        if let Some(value) = lookup(ENV_REGISTRY_URL) {
+            let url = value.trim().trim_end_matches('/');
+            if !(url.starts_with("http://") || url.starts_with("https://")) {
+                return Err(format!(
+                    "Invalid value '{value}' for {ENV_REGISTRY_URL}: expected an http(s) URL"
+                ));
+            }
+            config.general.registry_url = Some(url.to_string());
+        }
+
+        if let Some(value) = lookup(ENV_REGISTRY_TOKEN) {
+            let token = value.trim();
+            if !token.is_empty() {
+                config.general.registry_token = Some(token.to_string());
+            }
+        }
+
+        Ok(config)
+    }
+
+    /// Whether crates.io lookups should be skipped. Defaults to false.
+    pub fn is_offline(&self) -> bool {
+        self.general.offline.unwrap_or(false)
+    }
+
+    /// Every path skipped by code checks: configured `exclude` entries plus `--ignore-path` ones.
+    pub fn excluded_paths(&self) -> Vec<&str> {
+        self.general
+            .exclude
+            .iter()
+            .chain(self.general.ignore_paths.iter())
+            .flatten()
+            .map(String::as_str)
+            .collect()
+    }
+
+    /// Additional source directories to scan. Empty by default.
+    pub fn extra_source_dirs(&self) -> &[String] {
+        self.general
+            .extra_source_dirs
+            .as_deref()
+            .unwrap_or_default()
+    }
+
+    /// Whether code-check results may be served from `target/dokita-cache.json`.
+    /// Defaults to true.
+    pub fn use_cache(&self) -> bool {
+        self.general.cache.unwrap_or(true)
+    }
+
+    /// Maximum number of registry lookups in flight at once. Defaults to
+    /// [`DEFAULT_NETWORK_CONCURRENCY`]; zero is treated as one.
+    pub fn network_concurrency(&self) -> usize {
+        self.general
+            .network_concurrency
+            .unwrap_or(DEFAULT_NETWORK_CONCURRENCY)
+            .max(1)
+    }
+
+    /// Whether `cargo audit` runs as part of the analysis. Defaults to true.
+    pub fn vulnerability_scan(&self) -> bool {
+        self.general.vulnerability_scan.unwrap_or(true)
+    }
+
+    /// Whether the network and audit phase is skipped after a local error. Defaults to false.
+    pub fn fail_fast(&self) -> bool {
+        self.general.fail_fast.unwrap_or(false)
+    }
+
+    /// Wall-clock budget for the whole analysis, if `deadline` is set.
+    pub fn deadline(&self) -> Option<Duration> {
+        self.general.deadline.map(Duration::from_secs)
+    }
+
+    /// How the `--baseline` file is read. Defaults to [`BaselineFormat::Auto`].
+    pub fn baseline_format(&self) -> BaselineFormat {
+        self.general.baseline_format.unwrap_or(BaselineFormat::Auto)
+    }
+
+    /// Prefix of the `rule_id` in machine-readable output. Empty (no `rule_id`) by default.
+    pub fn rule_prefix(&self) -> &str {
+        self.general.rule_prefix.as_deref().unwrap_or_default()
+    }
+
+    /// Whether human-readable output collapses repeated codes per file. Defaults to false.
+    pub fn collapse(&self) -> bool {
+        self.general.collapse.unwrap_or(false)
+    }
+
+    /// Whether the per-check tally follows the report. Defaults to false.
+    pub fn summary_by_check(&self) -> bool {
+        self.general.summary_by_check.unwrap_or(false)
+    }
+
+    /// Minimum severity that fails the run. Defaults to `Warning`, so both
+    /// errors and warnings produce a non-zero exit code.
+    pub fn fail_on(&self) -> Severity {
+        self.general.fail_on.clone().unwrap_or(Severity::Warning)
+    }
+
+    /// Base URL used for crate version lookups. Defaults to the public crates.io API.
+    pub fn registry_url(&self) -> &str {
+        self.general
+            .registry_url
+            .as_deref()
+            .unwrap_or(CRATES_IO_API_BASE)
+    }
+
+    /// Color choice for the output stream.
+    ///
+    /// An explicit `always`/`never` wins. Otherwise `CLICOLOR_FORCE` forces color and
+    /// `NO_COLOR` disables it, falling back to terminal detection.
+    pub fn color_choice(&self) -> ColorChoice {
+        self.color_choice_with(|name| std::env::var(name).ok())
+    }
+
+    fn color_choice_with(&self, lookup: impl Fn(&str) -> Option<String>) -> ColorChoice {
+        match self.general.color {
+            Some(ColorMode::Always) => return ColorChoice::Always,
+            Some(ColorMode::Never) => return ColorChoice::Never,
+            Some(ColorMode::Auto) | None => {}
+        }
+
+        let is_set = |name: &str| lookup(name).is_some_and(|v| !v.is_empty());
+        if is_set(ENV_CLICOLOR_FORCE) && lookup(ENV_CLICOLOR_FORCE).as_deref() != Some("0") {
+            ColorChoice::Always
+        } else if is_set(ENV_NO_COLOR) {
+            ColorChoice::Never
+        } else {
+            ColorChoice::Auto
+        }
+    }
+
+    /// File extensions scanned as Rust sources, without the leading dot.
+    pub fn source_extensions(&self) -> Vec<&str> {
+        match &self.general.source_extensions {
+            Some(extensions) => extensions
+                .iter()
+                .map(|ext| ext.trim_start_matches('.'))
+                .collect(),
+            None => DEFAULT_SOURCE_EXTENSIONS.to_vec(),
+        }
+    }
+
+    /// Lints whose blanket `allow` attribute should be reported.
+    pub fn discouraged_allows(&self) -> Vec<&str> {
+        match &self.checks.discouraged_allows {
+            Some(lints) => lints.iter().map(String::as_str).collect(),
+            None => DEFAULT_DISCOURAGED_ALLOWS.to_vec(),
+        }
+    }
+
+    /// Prelude names that should not be redefined at the top level of a module.
+    pub fn shadowed_prelude_names(&self) -> Vec<&str> {
+        match &self.checks.shadowed_prelude_names {
+            Some(names) => names.iter().map(String::as_str).collect(),
+            None => DEFAULT_SHADOWED_PRELUDE_NAMES.to_vec(),
+        }
+    }
+
+    /// Maximum number of parameters a function may take before CODE026 reports it.
+    pub fn max_fn_params(&self) -> usize {
+        self.checks.max_fn_params.unwrap_or(DEFAULT_MAX_FN_PARAMS)
+    }
+
+    /// Size in KiB above which STRUCT013 reports a non-Rust file under `src/`.
+    pub fn max_asset_size_kib(&self) -> u64 {
+        self.checks
+            .max_asset_size_kib
+            .unwrap_or(DEFAULT_MAX_ASSET_SIZE_KIB)
+    }
+
+    /// Latest stable Rust release that toolchain pins are compared against.
+    pub fn latest_rust_version(&self) -> &str {
+        self.checks
+            .latest_rust_version
+            .as_deref()
+            .unwrap_or(DEFAULT_LATEST_RUST_VERSION)
+    }
+
+    /// Issue reference pattern CODE035 expects in TODO/FIXME comments.
+    pub fn todo_issue_pattern(&self) -> &str {
+        self.checks
+            .todo_issue_pattern
+            .as_deref()
+            .unwrap_or(DEFAULT_TODO_ISSUE_PATTERN)
+    }
+
+    /// Severity reported for outdated dependencies (DP002). Defaults to `Note`.
+    pub fn outdated_severity(&self) -> Severity {
+        self.checks
+            .outdated_severity
+            .clone()
+            .unwrap_or(Severity::Note)
+    }
+
+    /// Crates the outdated-dependency check (DP002) skips. Empty by default.
+    pub fn outdated_ignore(&self) -> &[String] {
+        self.checks.outdated_ignore.as_deref().unwrap_or_default()
+    }
+
+    /// Crate pairs DP016 reports: the built-in table plus any configured pairs.
+    pub fn redundant_dependency_pairs(&self) -> Vec<(&str, &str)> {
+        let configured = self
+            .checks
+            .redundant_dependency_pairs
+            .iter()
+            .flatten()
+            .map(|[a, b]| (a.as_str(), b.as_str()));
+        DEFAULT_REDUNDANT_DEPENDENCY_PAIRS
+            .iter()
+            .copied()
+            .chain(configured)
+            .collect()
+    }
+
+    /// Crates DP019 expects only in `[dev-dependencies]`: the built-in list plus any
+    /// configured ones.
+    pub fn dev_only_crates(&self) -> Vec<&str> {
+        let configured = self
+            .checks
+            .dev_only_crates
+            .iter()
+            .flatten()
+            .map(String::as_str);
+        DEFAULT_DEV_ONLY_CRATES
+            .iter()
+            .copied()
+            .chain(configured)
+            .collect()
     }
 
     /// Check if a specific check code is enabled.
-    /// Defaults to true if not specified in the config.
+    /// An explicit `enabled` entry wins; otherwise codes matching an `ignore_prefixes`
+    /// entry are disabled, and everything else defaults to true except opt-in checks
+    /// (see [`crate::checks::CheckInfo::enabled_by_default`]).
     pub fn is_check_enabled(&self, check_code: &str) -> bool {
-        self.checks.enabled.get(check_code).copied().unwrap_or(true)
+        self.checks
+            .enabled
+            .get(check_code)
+            .copied()
+            .unwrap_or_else(|| {
+                !self.is_ignored_by_prefix(check_code)
+                    && crate::checks::is_enabled_by_default(check_code)
+            })
+    }
+
+    /// Whether `check_code` starts with one of the configured `ignore_prefixes`.
+    pub fn is_ignored_by_prefix(&self, check_code: &str) -> bool {
+        self.checks
+            .ignore_prefixes
+            .iter()
+            .flatten()
+            .any(|prefix| !prefix.is_empty() && check_code.starts_with(prefix.as_str()))
     }
 }
 
@@ -106,6 +828,36 @@ mod tests {
         assert!(config.checks.enabled.is_empty());
     }
 
+    #[test]
+    fn test_init_template_parses_and_lists_every_check() {
+        let template = init_template();
+        let config: Config = toml::from_str(&template).unwrap();
+
+        for check in crate::checks::CHECKS {
+            assert!(template.contains(&format!("\"{}\"", check.code)));
+            assert_eq!(
+                config.is_check_enabled(check.code),
+                check.enabled_by_default,
+                "{}",
+                check.code
+            );
+        }
+        assert!(config.general.offline.is_none());
+    }
+
+    #[test]
+    fn test_write_init_file_refuses_to_overwrite() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join(CONFIG_FILE_NAME);
+        fs::write(&config_path, "[general]\n").unwrap();
+
+        assert!(write_init_file(temp_dir.path(), false).is_err());
+        assert_eq!(fs::read_to_string(&config_path).unwrap(), "[general]\n");
+
+        assert_eq!(write_init_file(temp_dir.path(), true).unwrap(), config_path);
+        assert_eq!(fs::read_to_string(&config_path).unwrap(), init_template());
+    }
+
     #[test]
     fn test_config_deserialize_empty() {
         let toml_content = "";
@@ -166,6 +918,26 @@ unknown_check_field = "value"
         assert!(config.is_check_enabled("any_check"));
     }
 
+    #[test]
+    fn test_is_check_enabled_opt_in_check() {
+        let mut config = Config::default();
+        assert!(!config.is_check_enabled("MD015"));
+
+        config.checks.enabled.insert("MD015".to_string(), true);
+        assert!(config.is_check_enabled("MD015"));
+    }
+
+    #[test]
+    fn test_is_check_enabled_ignore_prefixes() {
+        let mut config = Config::default();
+        config.checks.ignore_prefixes = Some(vec!["CODE".to_string()]);
+        config.checks.enabled.insert("CODE004".to_string(), true);
+
+        assert!(!config.is_check_enabled("CODE001"));
+        assert!(config.is_check_enabled("CODE004"));
+        assert!(config.is_check_enabled("MD001"));
+    }
+
     #[test]
     fn test_is_check_enabled_explicit_values() {
         let toml_content = r#"
@@ -210,6 +982,41 @@ enabled = { "MD001" = true, "MD002" = false }
         assert!(!config.is_check_enabled("MD002"));
     }
 
+    #[test]
+    fn test_load_from_manifest_metadata() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("Cargo.toml"),
+            r#"
+[package]
+name = "demo"
+version = "0.1.0"
+
+[package.metadata.dokita.general]
+offline = true
+
+[package.metadata.dokita.checks]
+enabled = { "MD002" = false }
+"#,
+        )
+        .unwrap();
+
+        let config = Config::load_from_project_root(temp_dir.path()).unwrap();
+        assert!(config.is_offline());
+        assert!(!config.is_check_enabled("MD002"));
+
+        // .cargo-dokita.toml takes precedence over the manifest table
+        fs::write(
+            temp_dir.path().join(CONFIG_FILE_NAME),
+            "[checks]\nenabled = { \"MD001\" = false }\n",
+        )
+        .unwrap();
+        let config = Config::load_from_project_root(temp_dir.path()).unwrap();
+        assert!(!config.is_offline());
+        assert!(config.is_check_enabled("MD002"));
+        assert!(!config.is_check_enabled("MD001"));
+    }
+
     #[test]
     fn test_load_from_project_root_invalid_toml() {
         let temp_dir = TempDir::new().unwrap();
@@ -257,6 +1064,223 @@ field = "value"
         assert!(result.unwrap_err().contains("Failed to read config file"));
     }
 
+    #[test]
+    fn test_config_deserialize_general_settings() {
+        let toml_content = r#"
+[general]
+offline = true
+severity_threshold = "warning"
+jobs = 2
+exclude = ["src/generated", "vendor"]
+"#;
+        let config: Config = toml::from_str(toml_content).unwrap();
+        assert_eq!(config.general.offline, Some(true));
+        assert_eq!(config.general.severity_threshold, Some(Severity::Warning));
+        assert_eq!(config.general.jobs, Some(2));
+        assert_eq!(
+            config.general.exclude,
+            Some(vec!["src/generated".to_string(), "vendor".to_string()])
+        );
+        assert!(config.is_offline());
+    }
+
+    #[test]
+    fn test_merge_overrides_win_on_conflict() {
+        let base: Config = toml::from_str(
+            r#"
+[general]
+offline = false
+severity_threshold = "note"
+fail_on = "note"
+jobs = 8
+exclude = ["vendor"]
+
+[checks]
+enabled = { "MD001" = true, "MD002" = false }
+"#,
+        )
+        .unwrap();
+        let overrides: Config = toml::from_str(
+            r#"
+[general]
+offline = true
+severity_threshold = "error"
+
+[checks]
+enabled = { "MD001" = false, "MD003" = false }
+"#,
+        )
+        .unwrap();
+
+        let merged = Config::merge(base, overrides);
+
+        assert!(merged.is_offline());
+        assert_eq!(merged.general.severity_threshold, Some(Severity::Error));
+        // Settings not present in the overrides fall through from the base
+        assert_eq!(merged.fail_on(), Severity::Note);
+        assert_eq!(merged.general.jobs, Some(8));
+        assert_eq!(merged.general.exclude, Some(vec!["vendor".to_string()]));
+        // Per-check entries merge key by key
+        assert!(!merged.is_check_enabled("MD001"));
+        assert!(!merged.is_check_enabled("MD002"));
+        assert!(!merged.is_check_enabled("MD003"));
+        assert!(merged.is_check_enabled("MD004"));
+    }
+
+    #[test]
+    fn test_merge_with_default_overrides_keeps_base() {
+        let base: Config = toml::from_str(
+            r#"
+[general]
+jobs = 3
+"#,
+        )
+        .unwrap();
+
+        let merged = Config::merge(base, Config::default());
+
+        assert_eq!(merged.general.jobs, Some(3));
+        assert!(!merged.is_offline());
+        assert!(merged.general.severity_threshold.is_none());
+    }
+
+    fn env_from(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        move |name| vars.get(name).cloned()
+    }
+
+    #[test]
+    fn test_from_env_no_variables() {
+        let config = Config::from_env_lookup(env_from(&[])).unwrap();
+        assert!(config.general.offline.is_none());
+        assert!(config.general.severity_threshold.is_none());
+        assert!(config.general.jobs.is_none());
+        assert!(config.general.registry_url.is_none());
+    }
+
+    #[test]
+    fn test_from_env_reads_supported_variables() {
+        let config = Config::from_env_lookup(env_from(&[
+            (ENV_OFFLINE, "1"),
+            (ENV_SEVERITY_THRESHOLD, "Error"),
+            (ENV_JOBS, "4"),
+            (
+                ENV_REGISTRY_URL,
+                "https://registry.example.com/api/v1/crates/",
+            ),
+            (ENV_REGISTRY_TOKEN, "s3cret"),
+        ]))
+        .unwrap();
+
+        assert!(config.is_offline());
+        assert_eq!(config.general.severity_threshold, Some(Severity::Error));
+        assert_eq!(config.general.jobs, Some(4));
+        assert_eq!(
+            config.registry_url(),
+            "https://registry.example.com/api/v1/crates"
+        );
+        assert_eq!(config.general.registry_token.as_deref(), Some("s3cret"));
+
+        // The token is never read from a config file
+        let from_file: Result<Config, _> = toml::from_str("[general]\nregistry_token = \"x\"\n");
+        assert!(from_file.is_err());
+    }
+
+    #[test]
+    fn test_from_env_rejects_invalid_values() {
+        let cases = [
+            (ENV_OFFLINE, "yes"),
+            (ENV_SEVERITY_THRESHOLD, "critical"),
+            (ENV_JOBS, "0"),
+            (ENV_JOBS, "many"),
+            (ENV_REGISTRY_URL, "registry.example.com"),
+        ];
+
+        for (name, value) in cases {
+            let result = Config::from_env_lookup(env_from(&[(name, value)]));
+            let err = result.unwrap_err();
+            assert!(err.contains(name), "error should name {name}: {err}");
+            assert!(err.contains(value), "error should quote '{value}': {err}");
+        }
+    }
+
+    #[test]
+    fn test_merge_precedence_cli_over_env_over_file() {
+        let file: Config = toml::from_str(
+            r#"
+[general]
+offline = false
+severity_threshold = "note"
+jobs = 8
+"#,
+        )
+        .unwrap();
+        let env = Config::from_env_lookup(env_from(&[
+            (ENV_OFFLINE, "true"),
+            (ENV_SEVERITY_THRESHOLD, "warning"),
+        ]))
+        .unwrap();
+        let mut cli = Config::default();
+        cli.general.severity_threshold = Some(Severity::Error);
+
+        let merged = Config::merge(Config::merge(file, env), cli);
+
+        assert_eq!(merged.general.severity_threshold, Some(Severity::Error));
+        assert!(merged.is_offline());
+        assert_eq!(merged.general.jobs, Some(8));
+        assert_eq!(merged.registry_url(), CRATES_IO_API_BASE);
+    }
+
+    #[test]
+    fn test_discouraged_allows_default_and_override() {
+        let config = Config::default();
+        assert_eq!(config.discouraged_allows(), DEFAULT_DISCOURAGED_ALLOWS);
+
+        let toml_content = r#"
+[checks]
+discouraged_allows = ["warnings", "clippy::too_many_arguments"]
+"#;
+        let config: Config = toml::from_str(toml_content).unwrap();
+        assert_eq!(
+            config.discouraged_allows(),
+            vec!["warnings", "clippy::too_many_arguments"]
+        );
+    }
+
+    #[test]
+    fn test_excluded_paths_combines_exclude_and_ignore_paths() {
+        let file: Config = toml::from_str("[general]\nexclude = [\"vendor\"]").unwrap();
+        let mut cli = Config::default();
+        cli.general.ignore_paths = Some(vec!["src/generated".to_string()]);
+
+        let merged = Config::merge(file, cli);
+        assert_eq!(merged.excluded_paths(), vec!["vendor", "src/generated"]);
+    }
+
+    #[test]
+    fn test_outdated_severity_default_and_override() {
+        assert_eq!(Config::default().outdated_severity(), Severity::Note);
+
+        let config: Config = toml::from_str("[checks]\noutdated_severity = \"warning\"").unwrap();
+        assert_eq!(config.outdated_severity(), Severity::Warning);
+    }
+
+    #[test]
+    fn test_shadowed_prelude_names_default_and_override() {
+        let config = Config::default();
+        assert_eq!(
+            config.shadowed_prelude_names(),
+            DEFAULT_SHADOWED_PRELUDE_NAMES
+        );
+
+        let config: Config =
+            toml::from_str("[checks]\nshadowed_prelude_names = [\"Error\"]").unwrap();
+        assert_eq!(config.shadowed_prelude_names(), vec!["Error"]);
+    }
+
     #[test]
     fn test_config_file_name_constant() {
         assert_eq!(CONFIG_FILE_NAME, ".cargo-dokita.toml");
@@ -289,4 +1313,39 @@ enabled = { "MD001" = true, "MD002" = false }
         assert!(debug_str.contains("general"));
         assert!(debug_str.contains("checks"));
     }
+
+    #[test]
+    fn test_color_choice_resolution() {
+        let auto = Config::default();
+        assert_eq!(auto.color_choice_with(env_from(&[])), ColorChoice::Auto);
+        assert_eq!(
+            auto.color_choice_with(env_from(&[(ENV_NO_COLOR, "1")])),
+            ColorChoice::Never
+        );
+        assert_eq!(
+            auto.color_choice_with(env_from(&[(ENV_NO_COLOR, "")])),
+            ColorChoice::Auto
+        );
+        assert_eq!(
+            auto.color_choice_with(env_from(&[(ENV_CLICOLOR_FORCE, "1"), (ENV_NO_COLOR, "1")])),
+            ColorChoice::Always
+        );
+        assert_eq!(
+            auto.color_choice_with(env_from(&[(ENV_CLICOLOR_FORCE, "0")])),
+            ColorChoice::Auto
+        );
+
+        let mut never = Config::default();
+        never.general.color = Some(ColorMode::Never);
+        assert_eq!(
+            never.color_choice_with(env_from(&[(ENV_CLICOLOR_FORCE, "1")])),
+            ColorChoice::Never
+        );
+
+        let always: Config = toml::from_str("[general]\ncolor = \"always\"").unwrap();
+        assert_eq!(
+            always.color_choice_with(env_from(&[(ENV_NO_COLOR, "1")])),
+            ColorChoice::Always
+        );
+    }
 }
@@ -8,7 +8,8 @@
 //!
 //! - Fetch the latest version of a crate from crates.io using its REST API
 //! - Handle API errors, network timeouts, and malformed responses gracefully
-//! - Configurable base URL for testing with mock servers
+//! - Configurable base URL for testing with mock servers and private registries
+//! - Optional bearer token for authenticated private registries (never sent to crates.io)
 //! - Proper User-Agent header following crates.io API guidelines
 //! - Comprehensive error handling and reporting
 //!
@@ -28,19 +29,19 @@
 //! This module is primarily intended for use in cargo-dokita's dependency analysis features.
 //! It may be useful for other tools or scripts that need to query crate versions as well.
 
+use reqwest::StatusCode;
 use reqwest::blocking::Client; // If using blocking client
 use serde::Deserialize;
 use std::time::Duration;
 
-const CRATES_IO_API_BASE: &str = "https://crates.io/api/v1/crates";
+pub const CRATES_IO_API_BASE: &str = "https://crates.io/api/v1/crates";
 const USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
 
 #[derive(Deserialize, Debug)]
 pub struct CratesIoCrate {
     #[serde(rename = "crate")] // The main data is under a "crate" key
     crate_data: CrateData,
-    #[allow(dead_code)]
-    versions: Vec<CrateVersion>, // List of all versions, useful but we mainly need newest
+    versions: Vec<CrateVersion>, // List of all versions, used to spot fully yanked crates
 }
 
 #[derive(Deserialize, Debug)]
@@ -48,46 +49,81 @@ struct CrateData {
     max_version: String, // The newest version string (stable)
 }
 
-// Not strictly needed if we only use max_version, but good for completeness
-#[derive(Deserialize, Debug)]
-struct CrateVersion {
-    #[allow(dead_code)]
-    num: String, // Version number string
-    #[allow(dead_code)]
-    yanked: bool,
+/// One published version of a crate.
+#[derive(Deserialize, Debug, Clone)]
+pub struct CrateVersion {
+    pub num: String, // Version number string
+    pub yanked: bool,
 }
 
-pub fn get_latest_versions_from_crates_io(
-    crate_name: &str,
-    client: &Client,
-) -> Result<String, String> {
-    let url = format!("{CRATES_IO_API_BASE}/{crate_name}");
+/// Why a registry lookup failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CratesIoError {
+    /// The registry has no crate by that name (HTTP 404).
+    NotFound,
+    /// The registry rejected the request for exceeding its rate limit (HTTP 429).
+    RateLimited,
+    /// The request did not complete within its timeout.
+    Timeout,
+    /// Any other unsuccessful HTTP status.
+    Http(StatusCode),
+    /// The response was not the expected JSON.
+    Parse(String),
+    /// The request could not be sent or the connection failed.
+    Network(String),
+}
 
-    let res = client
-        .get(&url)
-        .header(reqwest::header::USER_AGENT, USER_AGENT)
-        .timeout(Duration::from_secs(30))
-        .send()
-        .map_err(|e| format!("Failed to send request to crate.io for {crate_name}: {e}"));
-
-    let api_response: CratesIoCrate = match res {
-        Ok(res) => {
-            if !res.status().is_success() {
-                return Err(format!(
-                    "crates.io API request for {} failed with status: {}",
-                    crate_name,
-                    res.status()
-                ));
-            }
+impl CratesIoError {
+    /// Classifies a failed send or body read.
+    fn from_reqwest(e: reqwest::Error) -> Self {
+        if e.is_timeout() {
+            CratesIoError::Timeout
+        } else if e.is_decode() {
+            CratesIoError::Parse(e.to_string())
+        } else {
+            CratesIoError::Network(e.to_string())
+        }
+    }
+}
 
-            res.json().map_err(|e| {
-                format!("Failed to parse JSON response from crates.io for {crate_name}: {e}")
-            })?
+impl std::fmt::Display for CratesIoError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CratesIoError::NotFound => write!(f, "crate not found in the registry"),
+            CratesIoError::RateLimited => write!(f, "rate limited by the registry (HTTP 429)"),
+            CratesIoError::Timeout => write!(f, "request timed out"),
+            CratesIoError::Http(status) => {
+                write!(f, "registry request failed with status: {status}")
+            }
+            CratesIoError::Parse(e) => write!(f, "could not parse the registry response: {e}"),
+            CratesIoError::Network(e) => write!(f, "could not reach the registry: {e}"),
         }
-        Err(e) => return Err(format!("Something went wrong - {e}")),
-    };
+    }
+}
+
+impl std::error::Error for CratesIoError {}
+
+/// Version information for a crate, as returned by the registry API.
+#[derive(Debug, Clone)]
+pub struct CrateVersions {
+    /// The newest stable version.
+    pub max_version: String,
+    /// Every published version, including yanked ones.
+    pub versions: Vec<CrateVersion>,
+}
+
+impl CrateVersions {
+    /// Whether the crate has published versions and every one of them is yanked.
+    pub fn all_yanked(&self) -> bool {
+        !self.versions.is_empty() && self.versions.iter().all(|v| v.yanked)
+    }
+}
 
-    Ok(api_response.crate_data.max_version)
+pub fn get_latest_versions_from_crates_io(
+    crate_name: &str,
+    client: &Client,
+) -> Result<String, CratesIoError> {
+    get_latest_version_with_token(crate_name, client, CRATES_IO_API_BASE, None)
 }
 
 // First, add this testable version of your function to your main code:
@@ -95,34 +131,65 @@ pub fn get_latest_versions_from_crates_io_with_base_url(
     crate_name: &str,
     client: &reqwest::blocking::Client,
     base_url: &str,
-) -> Result<String, String> {
+) -> Result<String, CratesIoError> {
+    get_latest_version_with_token(crate_name, client, base_url, None)
+}
+
+/// Whether `base_url` points at the public crates.io API.
+pub fn is_crates_io(base_url: &str) -> bool {
+    reqwest::Url::parse(base_url)
+        .ok()
+        .and_then(|url| url.host_str().map(|host| host == "crates.io"))
+        .unwrap_or(false)
+}
+
+/// Like [`get_latest_versions_from_crates_io_with_base_url`], but sends `token` as a
+/// bearer `Authorization` header for private registries. The token is never sent to
+/// the public crates.io API.
+pub fn get_latest_version_with_token(
+    crate_name: &str,
+    client: &reqwest::blocking::Client,
+    base_url: &str,
+    token: Option<&str>,
+) -> Result<String, CratesIoError> {
+    get_crate_versions(crate_name, client, base_url, token)
+        .map(|crate_versions| crate_versions.max_version)
+}
+
+/// Fetches the newest version and the full version list of `crate_name` in one request.
+/// `token` is handled as in [`get_latest_version_with_token`].
+pub fn get_crate_versions(
+    crate_name: &str,
+    client: &reqwest::blocking::Client,
+    base_url: &str,
+    token: Option<&str>,
+) -> Result<CrateVersions, CratesIoError> {
     let url = format!("{base_url}/{crate_name}");
 
-    let res = client
+    let mut request = client
         .get(&url)
         .header(reqwest::header::USER_AGENT, USER_AGENT)
-        .timeout(Duration::from_secs(30))
-        .send()
-        .map_err(|e| format!("Failed to send request to crate.io for {crate_name}: {e}"));
-
-    let api_response: CratesIoCrate = match res {
-        Ok(res) => {
-            if !res.status().is_success() {
-                return Err(format!(
-                    "crates.io API request for {} failed with status: {}",
-                    crate_name,
-                    res.status()
-                ));
-            }
+        .timeout(Duration::from_secs(30));
+    if let Some(token) = token
+        && !is_crates_io(base_url)
+    {
+        request = request.bearer_auth(token);
+    }
 
-            res.json().map_err(|e| {
-                format!("Failed to parse JSON response from crates.io for {crate_name}: {e}")
-            })?
-        }
-        Err(e) => return Err(format!("Something went wrong - {e}")),
-    };
+    let res = request.send().map_err(CratesIoError::from_reqwest)?;
+    match res.status() {
+        StatusCode::NOT_FOUND => return Err(CratesIoError::NotFound),
+        StatusCode::TOO_MANY_REQUESTS => return Err(CratesIoError::RateLimited),
+        status if !status.is_success() => return Err(CratesIoError::Http(status)),
+        _ => {}
+    }
+
+    let api_response: CratesIoCrate = res.json().map_err(CratesIoError::from_reqwest)?;
 
-    Ok(api_response.crate_data.max_version)
+    Ok(CrateVersions {
+        max_version: api_response.crate_data.max_version,
+        versions: api_response.versions,
+    })
 }
 
 #[cfg(test)]
@@ -239,12 +306,7 @@ mod tests {
         );
 
         mock.assert();
-        assert!(result.is_err());
-        let error_msg = result.unwrap_err();
-        assert!(
-            error_msg
-                .contains("crates.io API request for nonexistent-crate failed with status: 404")
-        );
+        assert_eq!(result.unwrap_err(), CratesIoError::NotFound);
     }
 
     #[test]
@@ -265,8 +327,30 @@ mod tests {
 
         mock.assert();
         assert!(result.is_err());
-        let error_msg = result.unwrap_err();
-        assert!(error_msg.contains("crates.io API request for some-crate failed with status: 500"));
+        assert_eq!(
+            result.unwrap_err(),
+            CratesIoError::Http(StatusCode::INTERNAL_SERVER_ERROR)
+        );
+    }
+
+    #[test]
+    fn test_api_returns_429() {
+        let server = MockServer::start();
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/busy-crate");
+            then.status(429).body("Too Many Requests");
+        });
+
+        let client = create_test_client();
+        let result = get_latest_versions_from_crates_io_with_base_url(
+            "busy-crate",
+            &client,
+            &server.base_url(),
+        );
+
+        mock.assert();
+        assert_eq!(result.unwrap_err(), CratesIoError::RateLimited);
     }
 
     #[test]
@@ -289,11 +373,7 @@ mod tests {
 
         mock.assert();
         assert!(result.is_err());
-        let error_msg = result.unwrap_err();
-        assert!(
-            error_msg
-                .contains("Failed to parse JSON response from crates.io for invalid-json-crate")
-        );
+        assert!(matches!(result.unwrap_err(), CratesIoError::Parse(_)));
     }
 
     #[test]
@@ -324,11 +404,7 @@ mod tests {
 
         mock.assert();
         assert!(result.is_err());
-        let error_msg = result.unwrap_err();
-        assert!(
-            error_msg
-                .contains("Failed to parse JSON response from crates.io for missing-field-crate")
-        );
+        assert!(matches!(result.unwrap_err(), CratesIoError::Parse(_)));
     }
 
     #[test]
@@ -356,6 +432,68 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_registry_token_sent_only_to_private_registries() {
+        let server = MockServer::start();
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/private-crate")
+                .header("Authorization", "Bearer s3cret");
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(create_mock_crates_io_response());
+        });
+
+        let client = create_test_client();
+        let result = get_latest_version_with_token(
+            "private-crate",
+            &client,
+            &server.base_url(),
+            Some("s3cret"),
+        );
+
+        mock.assert();
+        assert_eq!(result.unwrap(), "1.2.3");
+
+        assert!(is_crates_io(CRATES_IO_API_BASE));
+        assert!(is_crates_io("https://crates.io/api/v1/crates/"));
+        assert!(!is_crates_io(&server.base_url()));
+        assert!(!is_crates_io("https://crates.io.example.com/api/v1/crates"));
+    }
+
+    #[test]
+    fn test_registry_token_not_sent_to_crates_io() {
+        let server = MockServer::start();
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/api/v1/crates/serde")
+                .matches(|req| {
+                    !req.headers
+                        .iter()
+                        .flatten()
+                        .any(|(name, _)| name.eq_ignore_ascii_case("authorization"))
+                });
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(create_mock_crates_io_response());
+        });
+
+        // Resolve crates.io to the mock so the base URL is treated as the public API
+        let client = Client::builder()
+            .resolve("crates.io", *server.address())
+            .build()
+            .unwrap();
+        let base_url = format!("http://crates.io:{}/api/v1/crates", server.port());
+        assert!(is_crates_io(&base_url));
+
+        let result = get_latest_version_with_token("serde", &client, &base_url, Some("s3cret"));
+
+        mock.assert();
+        assert_eq!(result.unwrap(), "1.2.3");
+    }
+
     #[test]
     fn test_crate_name_with_special_characters() {
         let server = MockServer::start();
@@ -6,28 +6,67 @@
 //! - Retrieving project metadata using `cargo_metadata`.
 //! - Checking for outdated dependencies by comparing current versions to the latest available on crates.io.
 //! - Auditing for known security vulnerabilities using `cargo-audit` and parsing its JSON output.
+//! - Exporting the direct dependency graph of workspace members (`--dependency-graph`).
 //! - Reporting findings as structured diagnostics for further processing or display.
 //!
 //! The module is intended for use in tools that lint, audit, or maintain Rust projects, helping users keep dependencies up-to-date and secure.
 
 use std::{path::Path, process::Command};
 
-use cargo_metadata::{Metadata, MetadataCommand, semver::Version};
+use cargo_metadata::{DependencyKind, Metadata, MetadataCommand, semver::Version};
+use rayon::prelude::*;
 use reqwest::blocking::Client;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{
-    crates_io_api,
+    crates_io_api::{self, CrateVersions, CratesIoError},
     diagnostics::{Finding, Severity},
+    manifest,
 };
 
 pub fn get_project_metadata(manifest_path: &Path) -> Result<Metadata, cargo_metadata::Error> {
     MetadataCommand::new().manifest_path(manifest_path).exec()
 }
 
-pub fn check_outdated_dependencies(metadata: &Metadata, http_client: &Client) -> Vec<Finding> {
+/// How [`check_outdated_dependencies`] reports outdated crates and which ones it leaves out.
+#[derive(Debug, Clone)]
+pub struct OutdatedOptions<'a> {
+    /// Severity of DP002 findings (see [`crate::config::Config::outdated_severity`]).
+    pub severity: Severity,
+    /// Crates overridden in `[patch.crates-io]`
+    /// (see [`crate::manifest::CargoManifest::crates_io_patches`]).
+    pub patched: &'a [String],
+    /// Crates never reported as outdated (see [`crate::config::Config::outdated_ignore`]).
+    pub ignored: &'a [String],
+}
+
+/// Compares each direct crates.io dependency against the latest version reported by
+/// the registry API at `registry_url` (see [`crate::config::Config::registry_url`]).
+/// Outdated dependencies are reported as DP002 with `options.severity`, except for
+/// crates in `options.ignored`. `registry_token`, if any, is sent to private registries
+/// only, and at most `concurrency` lookups run at once.
+///
+/// Dependencies whose every published version is yanked are reported as DP018 instead,
+/// ignored or not, and those the registry does not know at all (HTTP 404) as DP020.
+/// Other lookup failures are reported as API001.
+///
+/// Crates in `options.patched` resolve to a local override, so they are not looked up;
+/// each gets a DP017 note instead.
+pub fn check_outdated_dependencies(
+    metadata: &Metadata,
+    http_client: &Client,
+    registry_url: &str,
+    registry_token: Option<&str>,
+    concurrency: usize,
+    options: &OutdatedOptions,
+) -> Vec<Finding> {
     // pub fn check_outdated_dependencies(metadata: &Metadata, client: &Client) -> Vec<Finding> {
     let mut findings = Vec::new();
+    // (dependency name, resolved version, manifest line), looked up in one batch below
+    let mut lookups: Vec<(String, String, Option<usize>)> = Vec::new();
+    // Findings name the root Cargo.toml, so only its dependencies are given a line
+    let root_manifest_path = metadata.workspace_root.join("Cargo.toml");
+    let root_manifest = std::fs::read_to_string(&root_manifest_path).ok();
 
     for package_id in &metadata.workspace_members {
         let package = &metadata[package_id];
@@ -55,6 +94,25 @@ pub fn check_outdated_dependencies(metadata: &Metadata, http_client: &Client) ->
             }
 
             let dep_name = &dep.name;
+            let line = root_manifest
+                .as_deref()
+                .filter(|_| package.manifest_path == root_manifest_path)
+                .and_then(|source| dependency_line(source, dep));
+
+            if options.patched.contains(dep_name) {
+                findings.push(with_manifest_line(
+                    Finding::new(
+                        "DP017",
+                        format!(
+                            "Dependency '{dep_name}' is overridden in [patch.crates-io]; skipped the crates.io version comparison."
+                        ),
+                        Severity::Note,
+                        Some("Cargo.toml".to_string()),
+                    ),
+                    line,
+                ));
+                continue;
+            }
 
             let package_node_in_resolve = metadata
                 .resolve
@@ -67,53 +125,277 @@ pub fn check_outdated_dependencies(metadata: &Metadata, http_client: &Client) ->
                     p_node.deps.iter().find(|d| d.name.to_string() == *dep_name)
                 {
                     let resolved_dep_package = &metadata[&resolved_dep_link.pkg];
-                    let current_version_str = resolved_dep_package.version.to_string();
-
-                    match crates_io_api::get_latest_versions_from_crates_io(dep_name, http_client) {
-                        Ok(latest_version_str) => {
-                            let current_ver = Version::parse(&current_version_str);
-                            let latest_ver = Version::parse(&latest_version_str);
-
-                            if let (Ok(cur), Ok(latest)) = (current_ver, latest_ver) {
-                                if cur < latest {
-                                    findings.push(Finding::new(
-                                        "DP002", // Outdated Dependency
-                                        format!(
-                                            "Direct dependency '{dep_name}' is outdated. Current: {cur}, Latest: {latest}"                                        ),
-                                        Severity::Note, // Or Warning, depending on preference
-                                        Some("Cargo.toml".to_string()), // Or Cargo.lock
-                                    ));
-                                }
-                            } else {
-                                // Failed to parse versions, maybe log this
-                                eprintln!(
-                                    "Warning: Could not parse versions for {dep_name}: current '{current_version_str}', latest '{latest_version_str}'"
-                                );
-                            }
-                        }
-                        Err(e) => {
-                            eprintln!(
-                                "Warning: Could not fetch latest version for {dep_name}: {e:?}"
-                            );
-                            // Optionally create a finding for API fetch failures
-                            findings.push(Finding::new(
-                                "API001",
+                    lookups.push((
+                        dep_name.clone(),
+                        resolved_dep_package.version.to_string(),
+                        line,
+                    ));
+                }
+            }
+        }
+    }
+
+    let names: Vec<&str> = lookups.iter().map(|(name, _, _)| name.as_str()).collect();
+    let crate_versions = fetch_crate_versions(
+        &names,
+        http_client,
+        registry_url,
+        registry_token,
+        concurrency,
+    );
+
+    for ((dep_name, current_version_str, line), versions) in lookups.iter().zip(crate_versions) {
+        match versions {
+            // A withdrawn crate has no newer version to compare against
+            Ok(versions) if versions.all_yanked() => {
+                findings.push(with_manifest_line(
+                    Finding::new(
+                        "DP018",
+                        format!(
+                            "Every published version of dependency '{dep_name}' is yanked. The crate has been withdrawn; migrate to an alternative."
+                        ),
+                        Severity::Warning,
+                        Some("Cargo.toml".to_string()),
+                    ),
+                    *line,
+                ));
+            }
+            // Intentionally pinned: no DP002 noise
+            Ok(_) if options.ignored.contains(dep_name) => {}
+            Ok(versions) => {
+                let latest_version_str = versions.max_version;
+                let current_ver = Version::parse(current_version_str);
+                let latest_ver = Version::parse(&latest_version_str);
+
+                if let (Ok(cur), Ok(latest)) = (current_ver, latest_ver) {
+                    if cur < latest {
+                        findings.push(with_manifest_line(
+                            Finding::new(
+                                "DP002", // Outdated Dependency
                                 format!(
-                                    "Failed to fetch latest version for dependency '{dep_name}': {e}"
+                                    "Direct dependency '{dep_name}' is outdated. Current: {cur}, Latest: {latest}"
                                 ),
-                                Severity::Warning, // This is an issue with cargo-doctor itself or network
-                                None,
-                            ));
-                        }
+                                options.severity.clone(),
+                                Some("Cargo.toml".to_string()), // Or Cargo.lock
+                            ),
+                            *line,
+                        ));
                     }
+                } else {
+                    // Failed to parse versions, maybe log this
+                    eprintln!(
+                        "Warning: Could not parse versions for {dep_name}: current '{current_version_str}', latest '{latest_version_str}'"
+                    );
                 }
             }
+            // Still resolves locally (a rename, vendoring or a stale lock file) but is unknown upstream
+            Err(CratesIoError::NotFound) => {
+                findings.push(with_manifest_line(
+                    Finding::new(
+                        "DP020",
+                        format!(
+                            "Dependency '{dep_name}' was not found on crates.io. Check the crate name in [dependencies]; the entry may be misspelled or stale."
+                        ),
+                        Severity::Note,
+                        Some("Cargo.toml".to_string()),
+                    ),
+                    *line,
+                ));
+            }
+            Err(e) => {
+                eprintln!("Warning: Could not fetch latest version for {dep_name}: {e:?}");
+                let hint = match &e {
+                    CratesIoError::RateLimited => " Lower `network_concurrency` or retry later.",
+                    CratesIoError::Timeout | CratesIoError::Network(_) => {
+                        " Check the network connection or run with --offline."
+                    }
+                    _ => "",
+                };
+                findings.push(Finding::new(
+                    "API001",
+                    format!(
+                        "Failed to fetch latest version for dependency '{dep_name}': {e}.{hint}"
+                    ),
+                    Severity::Warning, // This is an issue with cargo-doctor itself or network
+                    None,
+                ));
+            }
         }
     }
 
     findings
 }
 
+/// Line of `dep`'s entry in the manifest `source`: its name (or rename) under the table
+/// for its kind, inside `[target.<cfg>]` for platform-specific dependencies.
+fn dependency_line(source: &str, dep: &cargo_metadata::Dependency) -> Option<usize> {
+    let table = match dep.kind {
+        DependencyKind::Development => "dev-dependencies",
+        DependencyKind::Build => "build-dependencies",
+        _ => "dependencies",
+    };
+    let key = dep.rename.as_deref().unwrap_or(&dep.name);
+    match &dep.target {
+        Some(target) => manifest::toml_line(source, &["target", &target.to_string(), table, key]),
+        None => manifest::toml_line(source, &[table, key]),
+    }
+}
+
+fn with_manifest_line(finding: Finding, line: Option<usize>) -> Finding {
+    match line {
+        Some(line) => finding.with_line(line),
+        None => finding,
+    }
+}
+
+/// Looks up the versions of each crate in `names`, with at most `concurrency`
+/// requests in flight at once. Results are returned in the order of `names`.
+///
+/// The requests run on a dedicated thread pool so network waits never occupy the
+/// pool used for file scanning (see `--jobs`).
+pub fn fetch_crate_versions(
+    names: &[&str],
+    http_client: &Client,
+    registry_url: &str,
+    registry_token: Option<&str>,
+    concurrency: usize,
+) -> Vec<Result<CrateVersions, CratesIoError>> {
+    let lookup = |name: &&str| {
+        crates_io_api::get_crate_versions(name, http_client, registry_url, registry_token)
+    };
+    match rayon::ThreadPoolBuilder::new()
+        .num_threads(concurrency.max(1))
+        .build()
+    {
+        Ok(pool) => pool.install(|| names.par_iter().map(lookup).collect()),
+        // Fall back to one request at a time rather than skipping the check
+        Err(_) => names.iter().map(lookup).collect(),
+    }
+}
+
+/// One edge of the `--dependency-graph` export: a workspace member depending directly
+/// on a crate.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DependencyEdge {
+    /// The depending workspace member.
+    pub from: String,
+    /// The dependency's package name.
+    pub to: String,
+    /// The version the dependency resolved to.
+    pub version: String,
+    /// The newest version on the registry, for crates.io dependencies that were looked up.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latest: Option<String>,
+    /// Whether `latest` is newer than `version`.
+    pub outdated: bool,
+}
+
+/// Direct dependencies of every workspace member, as printed by `--dependency-graph`.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct DependencyGraph {
+    pub edges: Vec<DependencyEdge>,
+}
+
+impl DependencyGraph {
+    /// Builds the graph from the resolved dependencies in `metadata`. Edges are sorted by
+    /// member and dependency name so the output is stable.
+    pub fn from_metadata(metadata: &Metadata) -> Self {
+        let mut edges = Vec::new();
+        let Some(resolve) = &metadata.resolve else {
+            return DependencyGraph { edges };
+        };
+        for package_id in &metadata.workspace_members {
+            let Some(node) = resolve.nodes.iter().find(|n| n.id == *package_id) else {
+                continue;
+            };
+            for dep in &node.deps {
+                let dep_package = &metadata[&dep.pkg];
+                edges.push(DependencyEdge {
+                    from: metadata[package_id].name.to_string(),
+                    to: dep_package.name.to_string(),
+                    version: dep_package.version.to_string(),
+                    latest: None,
+                    outdated: false,
+                });
+            }
+        }
+        edges.sort_by(|a, b| (&a.from, &a.to).cmp(&(&b.from, &b.to)));
+        edges.dedup();
+        DependencyGraph { edges }
+    }
+
+    /// Fills in `latest` and `outdated` for crates.io dependencies, with the same registry
+    /// lookups as [`check_outdated_dependencies`]. Failed lookups leave an edge as it is.
+    pub fn annotate_latest(
+        &mut self,
+        metadata: &Metadata,
+        http_client: &Client,
+        registry_url: &str,
+        registry_token: Option<&str>,
+        concurrency: usize,
+    ) {
+        let from_crates_io = |name: &str| {
+            metadata.packages.iter().any(|p| {
+                p.name.as_str() == name && p.source.as_ref().is_some_and(|s| s.is_crates_io())
+            })
+        };
+        let mut names: Vec<&str> = self
+            .edges
+            .iter()
+            .map(|edge| edge.to.as_str())
+            .filter(|name| from_crates_io(name))
+            .collect();
+        names.sort_unstable();
+        names.dedup();
+
+        let versions = fetch_crate_versions(
+            &names,
+            http_client,
+            registry_url,
+            registry_token,
+            concurrency,
+        );
+        let latest: Vec<(String, String)> = names
+            .iter()
+            .zip(versions)
+            .filter_map(|(name, versions)| Some((name.to_string(), versions.ok()?.max_version)))
+            .collect();
+
+        for edge in &mut self.edges {
+            let Some((_, max_version)) = latest.iter().find(|(name, _)| *name == edge.to) else {
+                continue;
+            };
+            edge.outdated = match (Version::parse(&edge.version), Version::parse(max_version)) {
+                (Ok(current), Ok(newest)) => current < newest,
+                _ => false,
+            };
+            edge.latest = Some(max_version.clone());
+        }
+    }
+
+    /// Renders the graph in Graphviz DOT. Edges are labelled with the resolved version,
+    /// and outdated dependencies are drawn in red with the latest version added.
+    pub fn to_dot(&self) -> String {
+        let quote = |name: &str| format!("\"{}\"", name.replace('"', "\\\""));
+        let mut dot = String::from("digraph dependencies {\n");
+        for edge in &self.edges {
+            let attributes = match (&edge.latest, edge.outdated) {
+                (Some(latest), true) => {
+                    format!("label=\"{} (latest {latest})\", color=red", edge.version)
+                }
+                _ => format!("label=\"{}\"", edge.version),
+            };
+            dot.push_str(&format!(
+                "    {} -> {} [{attributes}];\n",
+                quote(&edge.from),
+                quote(&edge.to)
+            ));
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
 pub fn check_vulnerability(project_path: &Path) -> Vec<Finding> {
     let mut findings = Vec::new();
 
@@ -257,6 +539,7 @@ pub fn check_vulnerability(project_path: &Path) -> Vec<Finding> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use httpmock::{Mock, prelude::*};
     use std::{
         env, fs,
         time::{SystemTime, UNIX_EPOCH},
@@ -322,4 +605,270 @@ mod tests {
         // assert_eq!(metadata.packages[0].name.to_string(), "test-project");
         // assert_eq!(metadata.packages[0].version.to_string(), "0.1.0");
     }
+
+    /// The `setup_rust_project` fixture on disk, removed when dropped. Findings read
+    /// their lines from the manifest, so it lives as long as the test needs them.
+    struct TempProject(std::path::PathBuf);
+
+    impl Drop for TempProject {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    /// Metadata of a project whose only dependency is `serde = "1.0"`.
+    fn test_metadata() -> (TempProject, Metadata) {
+        let temp_dir = create_temp_project_dir();
+        let manifest_path = setup_rust_project(&temp_dir, "Cargo.toml");
+        let metadata = get_project_metadata(&manifest_path).expect("Failed to parse metadata");
+        (TempProject(temp_dir), metadata)
+    }
+
+    /// Registry response for serde whose latest version is `999.0.0`.
+    fn serde_999() -> serde_json::Value {
+        serde_json::json!({
+            "crate": { "max_version": "999.0.0" },
+            "versions": [{ "num": "999.0.0", "yanked": false }]
+        })
+    }
+
+    /// Answers the registry lookup for serde with `status` and, unless `null`, a JSON `body`.
+    fn mock_serde(server: &MockServer, status: u16, body: serde_json::Value) -> Mock<'_> {
+        server.mock(|when, then| {
+            when.method(GET).path("/serde");
+            let then = then.status(status);
+            if !body.is_null() {
+                then.header("content-type", "application/json")
+                    .json_body(body);
+            }
+        })
+    }
+
+    /// Outdated options that report DP002 as a note, with nothing patched or ignored.
+    fn default_options() -> OutdatedOptions<'static> {
+        OutdatedOptions {
+            severity: Severity::Note,
+            patched: &[],
+            ignored: &[],
+        }
+    }
+
+    /// [`check_outdated_dependencies`] against the registry at `server`.
+    fn check_against(
+        server: &MockServer,
+        metadata: &Metadata,
+        options: &OutdatedOptions,
+    ) -> Vec<Finding> {
+        check_outdated_dependencies(
+            metadata,
+            &Client::new(),
+            &server.base_url(),
+            None,
+            2,
+            options,
+        )
+    }
+
+    #[test]
+    fn outdated_dependency_uses_configured_severity() {
+        let (_project, metadata) = test_metadata();
+        let server = MockServer::start();
+        mock_serde(&server, 200, serde_999());
+
+        for severity in [Severity::Note, Severity::Warning] {
+            let options = OutdatedOptions {
+                severity: severity.clone(),
+                ..default_options()
+            };
+            let findings = check_against(&server, &metadata, &options);
+            let dp002 = findings.iter().find(|f| f.code == "DP002").unwrap();
+            assert_eq!(dp002.severity, severity);
+            // The `serde = "1.0"` line of the root manifest
+            assert_eq!(dp002.line_number, Some(8));
+        }
+    }
+
+    #[test]
+    fn ignored_dependency_is_not_reported_outdated() {
+        let (_project, metadata) = test_metadata();
+        let server = MockServer::start();
+        mock_serde(&server, 200, serde_999());
+
+        // The same setup reports serde when it is not ignored
+        let findings = check_against(&server, &metadata, &default_options());
+        assert!(findings.iter().any(|f| f.code == "DP002"));
+
+        let ignored = ["serde".to_string()];
+        let options = OutdatedOptions {
+            ignored: &ignored,
+            ..default_options()
+        };
+        let findings = check_against(&server, &metadata, &options);
+        assert!(!findings.iter().any(|f| f.code == "DP002"));
+        assert!(!findings.iter().any(|f| f.code == "API001"));
+    }
+
+    #[test]
+    fn dependency_graph_dot_lists_direct_edges() {
+        let (_project, metadata) = test_metadata();
+
+        let mut graph = DependencyGraph::from_metadata(&metadata);
+        let serde_edge = graph.edges.iter().find(|e| e.to == "serde").unwrap();
+        assert_eq!(serde_edge.from, "test-project");
+        assert!(!serde_edge.outdated);
+        let version = serde_edge.version.clone();
+        assert!(graph.to_dot().contains(&format!(
+            "\"test-project\" -> \"serde\" [label=\"{version}\"];"
+        )));
+
+        let server = MockServer::start();
+        mock_serde(&server, 200, serde_999());
+        graph.annotate_latest(&metadata, &Client::new(), &server.base_url(), None, 2);
+        let dot = graph.to_dot();
+        assert!(dot.starts_with("digraph dependencies {\n"));
+        assert!(dot.contains(&format!(
+            "\"test-project\" -> \"serde\" [label=\"{version} (latest 999.0.0)\", color=red];"
+        )));
+    }
+
+    #[test]
+    fn fully_yanked_dependency_is_flagged() {
+        let (_project, metadata) = test_metadata();
+        let server = MockServer::start();
+        mock_serde(
+            &server,
+            200,
+            serde_json::json!({
+                "crate": { "max_version": "999.0.0" },
+                "versions": [
+                    { "num": "999.0.0", "yanked": true },
+                    { "num": "1.0.0", "yanked": true }
+                ]
+            }),
+        );
+
+        let findings = check_against(&server, &metadata, &default_options());
+        let dp018 = findings.iter().find(|f| f.code == "DP018").unwrap();
+        assert_eq!(dp018.severity, Severity::Warning);
+        assert!(dp018.message.contains("'serde'"));
+        assert!(!findings.iter().any(|f| f.code == "DP002"));
+    }
+
+    #[test]
+    fn unknown_crate_is_reported_not_found() {
+        let (_project, metadata) = test_metadata();
+        let server = MockServer::start();
+        mock_serde(&server, 404, serde_json::Value::Null);
+
+        let findings = check_against(&server, &metadata, &default_options());
+        let dp020 = findings.iter().find(|f| f.code == "DP020").unwrap();
+        assert_eq!(dp020.severity, Severity::Note);
+        assert!(dp020.message.contains("'serde'"));
+        assert!(!findings.iter().any(|f| f.code == "API001"));
+    }
+
+    #[test]
+    fn rate_limited_lookup_suggests_lower_concurrency() {
+        let (_project, metadata) = test_metadata();
+        let server = MockServer::start();
+        mock_serde(&server, 429, serde_json::Value::Null);
+
+        let findings = check_against(&server, &metadata, &default_options());
+        let api001 = findings.iter().find(|f| f.code == "API001").unwrap();
+        assert!(api001.message.contains("rate limited"));
+        assert!(api001.message.contains("network_concurrency"));
+    }
+
+    #[test]
+    fn patched_dependency_skips_outdated_lookup() {
+        use crate::manifest::CargoManifest;
+
+        let temp_dir = create_temp_project_dir();
+        let manifest_path = setup_rust_project(&temp_dir, "Cargo.toml");
+        let local_serde = temp_dir.join("serde-local");
+        fs::create_dir_all(local_serde.join("src")).unwrap();
+        fs::write(local_serde.join("src/lib.rs"), "").unwrap();
+        fs::write(
+            local_serde.join("Cargo.toml"),
+            "[package]\nname = \"serde\"\nversion = \"1.0.0\"\nedition = \"2021\"\n",
+        )
+        .unwrap();
+        let mut toml_content = fs::read_to_string(&manifest_path).unwrap();
+        toml_content.push_str("\n[patch.crates-io]\nserde = { path = \"serde-local\" }\n");
+        fs::write(&manifest_path, toml_content).unwrap();
+
+        let _project = TempProject(temp_dir);
+        let patched = CargoManifest::parse(&manifest_path)
+            .unwrap()
+            .crates_io_patches();
+        let metadata = get_project_metadata(&manifest_path).expect("Failed to parse metadata");
+        assert_eq!(patched, vec!["serde".to_string()]);
+
+        let server = MockServer::start();
+        let lookup = mock_serde(&server, 200, serde_999());
+
+        let options = OutdatedOptions {
+            patched: &patched,
+            ..default_options()
+        };
+        let findings = check_against(&server, &metadata, &options);
+        assert!(!findings.iter().any(|f| f.code == "DP002"));
+        let dp017 = findings.iter().find(|f| f.code == "DP017").unwrap();
+        assert_eq!(dp017.severity, Severity::Note);
+        assert!(dp017.message.contains("'serde'"));
+        lookup.assert_hits(0);
+    }
+
+    #[test]
+    fn version_lookups_respect_network_concurrency() {
+        use std::io::{BufRead, BufReader, Write};
+        use std::net::TcpListener;
+        use std::sync::Arc;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::time::Duration;
+
+        // A registry that records how many requests it is serving at the same time
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let max_in_flight = Arc::new(AtomicUsize::new(0));
+        {
+            let (in_flight, max_in_flight) = (in_flight.clone(), max_in_flight.clone());
+            std::thread::spawn(move || {
+                for stream in listener.incoming().flatten() {
+                    let (in_flight, max_in_flight) = (in_flight.clone(), max_in_flight.clone());
+                    std::thread::spawn(move || {
+                        let mut reader = BufReader::new(&stream);
+                        let mut line = String::new();
+                        while reader.read_line(&mut line).is_ok_and(|n| n > 2) {
+                            line.clear();
+                        }
+                        let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                        max_in_flight.fetch_max(now, Ordering::SeqCst);
+                        std::thread::sleep(Duration::from_millis(100));
+                        in_flight.fetch_sub(1, Ordering::SeqCst);
+                        let body = r#"{"crate":{"max_version":"1.0.0"},"versions":[]}"#;
+                        let _ = write!(
+                            &stream,
+                            "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                            body.len()
+                        );
+                    });
+                }
+            });
+        }
+
+        let names = ["a", "b", "c", "d", "e", "f", "g", "h"];
+        let results = fetch_crate_versions(&names, &Client::new(), &base_url, None, 3);
+
+        assert_eq!(results.len(), names.len());
+        assert!(
+            results
+                .iter()
+                .all(|r| r.as_ref().is_ok_and(|v| v.max_version == "1.0.0"))
+        );
+        let max = max_in_flight.load(Ordering::SeqCst);
+        assert!(max <= 3, "{max} requests were in flight at once");
+        assert!(max > 1, "lookups should run concurrently");
+    }
 }
@@ -5,7 +5,7 @@
 //!
 //! ## Features
 //! - **Severity Levels**: Categorizes findings as `Error`, `Warning`, or `Note`
-//! - **Structured Findings**: Each finding includes a unique code, message, severity, and optional location information
+//! - **Structured Findings**: Each finding includes a unique code, category, message, severity, and optional location information
 //! - **Serialization Support**: All structures can be serialized/deserialized for output formatting or persistence
 //! - **Builder Pattern**: Convenient methods for creating findings with optional line numbers
 //!
@@ -35,44 +35,402 @@
 //! ).with_line(15);
 //! ```
 //!
+//! ## JSON Output
+//! Machine-readable output wraps the findings in a [`JsonReport`] envelope that carries
+//! a `schema_version` (bumped only on breaking output changes), the tool version, and the
+//! `exit_code`/`passed` outcome of the `fail_on` gate.
+//! Streaming output uses [`JsonLine`]: one finding per line, then a summary line, each
+//! tagged with a `type` field.
+//!
+//! [`json_schema`] describes the [`JsonReport`] envelope as a JSON Schema so consumers
+//! can validate the output.
+//!
 //! ## Finding Codes
 //! Finding codes follow a pattern where:
 //! - **MD**: Metadata-related issues (e.g., Cargo.toml problems)
 //! - **ML**: Missing license or legal issues
 //! - Additional prefixes may be added for different check categories
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::path::Path;
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+/// Number of digest bytes kept in a fingerprint (16 hex characters).
+const FINGERPRINT_BYTES: usize = 8;
+
+/// How serious a finding is. Ordered by seriousness: `Error > Warning > Note`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
 pub enum Severity {
-    Error,   // Must fix
+    #[serde(alias = "error")]
+    Error, // Must fix
+    #[serde(alias = "warning")]
     Warning, // Should fix
-    Note,    // Informational / Best practice
+    #[serde(alias = "note")]
+    Note, // Informational / Best practice
+}
+
+impl Severity {
+    // Declaration order is most severe first, so the ordering is spelled out here
+    fn rank(&self) -> u8 {
+        match self {
+            Severity::Note => 0,
+            Severity::Warning => 1,
+            Severity::Error => 2,
+        }
+    }
+}
+
+impl Ord for Severity {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.rank().cmp(&other.rank())
+    }
+}
+
+impl PartialOrd for Severity {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl std::fmt::Display for Severity {
+    /// Lowercase name, as accepted by [`Severity::from_str`](std::str::FromStr).
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+            Severity::Note => "note",
+        })
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl std::str::FromStr for Severity {
+    type Err = String;
+
+    /// Parses `error`, `warning` or `note`, ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "error" => Ok(Severity::Error),
+            "warning" => Ok(Severity::Warning),
+            "note" => Ok(Severity::Note),
+            _ => Err(format!(
+                "Invalid severity '{s}': expected error, warning or note"
+            )),
+        }
+    }
+}
+
+/// Broad area a finding belongs to. Derived from the check registry (see [`crate::checks`]).
+#[derive(
+    Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash, JsonSchema,
+)]
+pub enum Category {
+    Metadata,
+    CodeQuality,
+    Structure,
+    Dependency,
+    Security,
+    Lint,
+    Edition,
+    Io,
+    Audit,
+}
+
+impl Category {
+    /// Human-readable name used as a section header in grouped text output.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Category::Metadata => "Metadata",
+            Category::CodeQuality => "Code Quality",
+            Category::Structure => "Project Structure",
+            Category::Dependency => "Dependencies",
+            Category::Security => "Security",
+            Category::Lint => "Lint Configuration",
+            Category::Edition => "Edition",
+            Category::Io => "I/O",
+            Category::Audit => "Audit",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub struct Finding {
     pub code: String, // A unique code for the type of finding, e.g., "MD001" for missing license
+    pub category: Category,
     pub message: String,
     pub severity: Severity,
     pub file_path: Option<String>,  // e.g., "Cargo.toml"
     pub line_number: Option<usize>, // Optional: for more precise location (harder for TOML)
+    /// `code` behind the configured `rule_prefix` (e.g. `dokita/CODE001`), for merging
+    /// with other tools' output. Only set by the JSON renderers, never by checks.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rule_id: Option<String>,
+}
+
+/// Reports and baselines written before `category` existed leave it out; it is then
+/// derived from the code, as [`Finding::new`] does.
+impl<'de> Deserialize<'de> for Finding {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct SavedFinding {
+            code: String,
+            #[serde(default)]
+            category: Option<Category>,
+            message: String,
+            severity: Severity,
+            file_path: Option<String>,
+            line_number: Option<usize>,
+            #[serde(default)]
+            rule_id: Option<String>,
+        }
+
+        let saved = SavedFinding::deserialize(deserializer)?;
+        Ok(Finding {
+            category: saved
+                .category
+                .unwrap_or_else(|| crate::checks::category_for(&saved.code)),
+            code: saved.code,
+            message: saved.message,
+            severity: saved.severity,
+            file_path: saved.file_path,
+            line_number: saved.line_number,
+            rule_id: saved.rule_id,
+        })
+    }
 }
 
 impl Finding {
     pub fn new(code: &str, message: String, severity: Severity, file_path: Option<String>) -> Self {
         Finding {
             code: code.to_string(),
+            category: crate::checks::category_for(code),
             message,
             severity,
             file_path,
             line_number: None, // Keep it simple for now
+            rule_id: None,
         }
     }
     pub fn with_line(mut self, line: usize) -> Self {
         self.line_number = Some(line);
         self
     }
+
+    /// Stable identity of the finding across runs: a short hex digest of the code, file
+    /// and message. The line number is left out so the fingerprint survives edits that
+    /// only shift code around; use [`Finding::fingerprint_with_line`] to include it.
+    /// The file is taken relative to `project_root`, so the fingerprint does not depend
+    /// on where the project is checked out.
+    pub fn fingerprint(&self, project_root: &Path) -> String {
+        self.digest(project_root, false)
+    }
+
+    /// Like [`Finding::fingerprint`], but also distinguishes findings by line number.
+    pub fn fingerprint_with_line(&self, project_root: &Path) -> String {
+        self.digest(project_root, true)
+    }
+
+    /// `file_path` relative to `project_root`, with `/` separators. Paths outside the
+    /// root, and paths that are already relative, are returned unchanged.
+    pub fn relative_file_path(&self, project_root: &Path) -> Option<String> {
+        let file_path = self.file_path.as_deref()?;
+        let Ok(relative) = Path::new(file_path).strip_prefix(project_root) else {
+            return Some(file_path.to_string());
+        };
+        Some(
+            relative
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/"),
+        )
+    }
+
+    fn digest(&self, project_root: &Path, with_line: bool) -> String {
+        let mut hasher = Sha256::new();
+        let file_path = self.relative_file_path(project_root);
+        // NUL separators keep ("ab", "c") and ("a", "bc") apart
+        for part in [
+            self.code.as_str(),
+            file_path.as_deref().unwrap_or_default(),
+            self.message.as_str(),
+        ] {
+            hasher.update(part.as_bytes());
+            hasher.update([0]);
+        }
+        if with_line && let Some(line) = self.line_number {
+            hasher.update(line.to_string().as_bytes());
+        }
+        hasher.finalize()[..FINGERPRINT_BYTES]
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect()
+    }
+}
+
+/// Version of the JSON output schema. Bump only on breaking changes to the output shape.
+pub const JSON_SCHEMA_VERSION: u32 = 1;
+
+/// Process exit code of a run whose findings fail the `fail_on` gate.
+pub const FAILING_EXIT_CODE: i32 = 1;
+
+/// Top-level envelope for JSON output.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct JsonReport {
+    pub schema_version: u32,
+    pub tool: String,
+    pub tool_version: String,
+    /// Exit code the process ends with for these findings under the current `fail_on`.
+    #[serde(default)]
+    pub exit_code: i32,
+    /// Whether the findings pass the `fail_on` gate, i.e. `exit_code` is 0.
+    #[serde(default = "default_passed")]
+    pub passed: bool,
+    pub findings: Vec<Finding>,
+}
+
+// Reports written before `passed` existed are read back as passing
+fn default_passed() -> bool {
+    true
+}
+
+impl JsonReport {
+    /// A report for `findings` that passed the gate. Use [`JsonReport::with_outcome`]
+    /// to record a failing run.
+    pub fn new(findings: Vec<Finding>) -> Self {
+        JsonReport {
+            schema_version: JSON_SCHEMA_VERSION,
+            tool: env!("CARGO_PKG_NAME").to_string(),
+            tool_version: env!("CARGO_PKG_VERSION").to_string(),
+            exit_code: 0,
+            passed: true,
+            findings,
+        }
+    }
+
+    /// Records whether the run fails the `fail_on` gate.
+    pub fn with_outcome(mut self, fails: bool) -> Self {
+        self.passed = !fails;
+        self.exit_code = if fails { FAILING_EXIT_CODE } else { 0 };
+        self
+    }
+
+    /// Parses a saved `--format json` report. Status lines printed before the report and
+    /// any text after it are ignored, so redirected stdout can be read back as is.
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let start = content.find('{').unwrap_or(0);
+        serde_json::Deserializer::from_str(&content[start..])
+            .into_iter::<JsonReport>()
+            .next()
+            .ok_or_else(|| "no JSON report found".to_string())?
+            .map_err(|e| e.to_string())
+    }
+}
+
+/// JSON Schema for the `--format json` output envelope, as printed by `--print-schema`.
+pub fn json_schema() -> serde_json::Value {
+    serde_json::to_value(schemars::schema_for!(JsonReport)).unwrap_or_default()
+}
+
+/// Number of line numbers listed for a collapsed finding before eliding the rest.
+const COLLAPSED_LINES_SHOWN: usize = 10;
+
+/// Repeated occurrences of one code in one file, as shown by `--collapse`.
+#[derive(Debug)]
+pub struct CollapsedFinding<'a> {
+    /// First occurrence; supplies the code, severity, message and file.
+    pub first: &'a Finding,
+    pub count: usize,
+    pub lines: Vec<usize>,
+}
+
+impl CollapsedFinding<'_> {
+    /// A single finding summarizing the group, e.g.
+    /// `... (42 occurrences: lines 3, 9, 14, ...)`. Single occurrences are returned as-is.
+    pub fn summary(&self) -> Finding {
+        let mut summary = self.first.clone();
+        if self.count == 1 {
+            return summary;
+        }
+        let mut lines: Vec<String> = self
+            .lines
+            .iter()
+            .take(COLLAPSED_LINES_SHOWN)
+            .map(usize::to_string)
+            .collect();
+        if self.lines.len() > COLLAPSED_LINES_SHOWN {
+            lines.push("...".to_string());
+        }
+        summary.message = if lines.is_empty() {
+            format!("{} ({} occurrences)", summary.message, self.count)
+        } else {
+            format!(
+                "{} ({} occurrences: lines {})",
+                summary.message,
+                self.count,
+                lines.join(", ")
+            )
+        };
+        summary.line_number = None;
+        summary
+    }
+}
+
+/// Groups findings sharing a file and code, in order of first occurrence.
+/// Only affects human-readable rendering; JSON output keeps every occurrence.
+pub fn collapse_findings<'a>(
+    findings: impl IntoIterator<Item = &'a Finding>,
+) -> Vec<CollapsedFinding<'a>> {
+    let mut groups: Vec<CollapsedFinding<'a>> = Vec::new();
+    let mut index: HashMap<(Option<&str>, &str), usize> = HashMap::new();
+    for finding in findings {
+        let key = (finding.file_path.as_deref(), finding.code.as_str());
+        match index.get(&key) {
+            Some(&i) => {
+                groups[i].count += 1;
+                groups[i].lines.extend(finding.line_number);
+            }
+            None => {
+                index.insert(key, groups.len());
+                groups.push(CollapsedFinding {
+                    first: finding,
+                    count: 1,
+                    lines: finding.line_number.into_iter().collect(),
+                });
+            }
+        }
+    }
+    groups
+}
+
+/// One line of `jsonl` output: each finding on its own line, followed by a final summary.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum JsonLine<'a> {
+    Finding(&'a Finding),
+    Summary {
+        schema_version: u32,
+        tool_version: &'static str,
+        total: usize,
+        errors: usize,
+        warnings: usize,
+        notes: usize,
+    },
+}
+
+impl<'a> JsonLine<'a> {
+    pub fn summary(findings: &[Finding]) -> Self {
+        let count = |severity: Severity| findings.iter().filter(|f| f.severity == severity).count();
+        JsonLine::Summary {
+            schema_version: JSON_SCHEMA_VERSION,
+            tool_version: env!("CARGO_PKG_VERSION"),
+            total: findings.len(),
+            errors: count(Severity::Error),
+            warnings: count(Severity::Warning),
+            notes: count(Severity::Note),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -99,4 +457,210 @@ mod tests {
         let finding = Finding::new("ML002", "Test message".to_string(), Severity::Warning, None);
         assert_eq!(None, finding.file_path);
     }
+
+    #[test]
+    fn fingerprint_is_stable_and_ignores_line_by_default() {
+        let root = Path::new("/work/demo");
+        let finding = || {
+            Finding::new(
+                "CODE001",
+                "unwrap".to_string(),
+                Severity::Warning,
+                Some("src/lib.rs".to_string()),
+            )
+        };
+
+        let a = finding().with_line(3);
+        let b = finding().with_line(3);
+        assert_eq!(a.fingerprint(root), b.fingerprint(root));
+        assert_eq!(a.fingerprint(root).len(), 16);
+        assert!(a.fingerprint(root).chars().all(|c| c.is_ascii_hexdigit()));
+
+        let moved = finding().with_line(9);
+        assert_eq!(a.fingerprint(root), moved.fingerprint(root));
+        assert_ne!(
+            a.fingerprint_with_line(root),
+            moved.fingerprint_with_line(root)
+        );
+
+        let mut reworded = finding().with_line(3);
+        reworded.message = "expect".to_string();
+        assert_ne!(a.fingerprint(root), reworded.fingerprint(root));
+    }
+
+    #[test]
+    fn fingerprint_does_not_depend_on_checkout_location() {
+        let finding_under = |root: &str| {
+            Finding::new(
+                "CODE001",
+                "unwrap".to_string(),
+                Severity::Warning,
+                Some(format!("{root}/src/lib.rs")),
+            )
+        };
+
+        let here = finding_under("/home/dev/demo");
+        let ci = finding_under("/builds/ci/demo");
+        assert_eq!(
+            here.fingerprint(Path::new("/home/dev/demo")),
+            ci.fingerprint(Path::new("/builds/ci/demo"))
+        );
+        assert_eq!(
+            here.relative_file_path(Path::new("/home/dev/demo"))
+                .as_deref(),
+            Some("src/lib.rs")
+        );
+        // Same relative file as a finding that was reported relative to begin with
+        let relative = Finding::new(
+            "CODE001",
+            "unwrap".to_string(),
+            Severity::Warning,
+            Some("src/lib.rs".to_string()),
+        );
+        assert_eq!(
+            here.fingerprint(Path::new("/home/dev/demo")),
+            relative.fingerprint(Path::new("/home/dev/demo"))
+        );
+    }
+
+    #[test]
+    fn severity_ordering_and_round_trip() {
+        assert!(Severity::Error > Severity::Warning);
+        assert!(Severity::Warning > Severity::Note);
+        assert_eq!(
+            [Severity::Warning, Severity::Error, Severity::Note]
+                .iter()
+                .max(),
+            Some(&Severity::Error)
+        );
+
+        for severity in [Severity::Error, Severity::Warning, Severity::Note] {
+            assert_eq!(severity.to_string().parse::<Severity>(), Ok(severity));
+        }
+        assert_eq!("WARNING".parse::<Severity>(), Ok(Severity::Warning));
+        assert!("critical".parse::<Severity>().is_err());
+    }
+
+    #[test]
+    fn json_report_carries_schema_and_tool_version() {
+        let finding = Finding::new(
+            "MD001",
+            "Missing description".to_string(),
+            Severity::Warning,
+            None,
+        );
+        let report = JsonReport::new(vec![finding]);
+
+        let value: serde_json::Value = serde_json::to_value(&report).unwrap();
+
+        assert_eq!(value["schema_version"], JSON_SCHEMA_VERSION);
+        assert_eq!(value["tool"], env!("CARGO_PKG_NAME"));
+        assert_eq!(value["tool_version"], env!("CARGO_PKG_VERSION"));
+        assert_eq!(value["findings"][0]["code"], "MD001");
+        assert_eq!(value["findings"][0]["category"], "Metadata");
+    }
+
+    #[test]
+    fn finding_without_category_takes_it_from_the_code() {
+        let saved = r#"{"code":"DP002","message":"Outdated","severity":"Warning","file_path":"Cargo.toml","line_number":8}"#;
+        let finding: Finding = serde_json::from_str(saved).unwrap();
+        assert_eq!(finding.category, Category::Dependency);
+        assert_eq!(finding.line_number, Some(8));
+
+        let saved = r#"{"code":"DP002","category":"Security","message":"Outdated","severity":"Warning","file_path":null,"line_number":null}"#;
+        let finding: Finding = serde_json::from_str(saved).unwrap();
+        assert_eq!(finding.category, Category::Security);
+    }
+
+    #[test]
+    fn finding_category_comes_from_registry() {
+        let finding = Finding::new("CODE001", "unwrap".to_string(), Severity::Warning, None);
+        assert_eq!(finding.category, Category::CodeQuality);
+
+        let finding = Finding::new("AUD004", "audit".to_string(), Severity::Warning, None);
+        assert_eq!(finding.category, Category::Audit);
+    }
+
+    #[test]
+    fn json_lines_are_tagged_by_type() {
+        let findings = vec![
+            Finding::new(
+                "MD001",
+                "Missing description".to_string(),
+                Severity::Warning,
+                None,
+            ),
+            Finding::new("CODE004", "TODO".to_string(), Severity::Note, None).with_line(3),
+        ];
+
+        let line = serde_json::to_string(&JsonLine::Finding(&findings[1])).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&line).unwrap();
+        assert_eq!(value["type"], "finding");
+        assert_eq!(value["code"], "CODE004");
+        assert_eq!(value["line_number"], 3);
+
+        let summary = serde_json::to_string(&JsonLine::summary(&findings)).unwrap();
+        assert!(!summary.contains('\n'));
+        let value: serde_json::Value = serde_json::from_str(&summary).unwrap();
+        assert_eq!(value["type"], "summary");
+        assert_eq!(value["total"], 2);
+        assert_eq!(value["warnings"], 1);
+        assert_eq!(value["notes"], 1);
+        assert_eq!(value["errors"], 0);
+    }
+
+    #[test]
+    fn test_json_schema_describes_report() {
+        let schema = json_schema();
+
+        assert_eq!(schema["title"], "JsonReport");
+        let required = schema["required"].as_array().unwrap();
+        for field in ["schema_version", "tool", "tool_version", "findings"] {
+            assert!(required.iter().any(|r| r == field), "missing {field}");
+        }
+
+        let finding = &schema["$defs"]["Finding"];
+        let finding_required = finding["required"].as_array().unwrap();
+        assert!(finding_required.iter().any(|r| r == "code"));
+        assert!(!finding_required.iter().any(|r| r == "line_number"));
+        assert!(finding["properties"]["file_path"].is_object());
+    }
+
+    #[test]
+    fn collapse_groups_repeated_codes_per_file() {
+        let unwrap = |file: &str, line| {
+            Finding::new(
+                "CODE001",
+                "unwrap used".to_string(),
+                Severity::Warning,
+                Some(file.to_string()),
+            )
+            .with_line(line)
+        };
+        let mut findings: Vec<Finding> = (1..=12).map(|line| unwrap("src/a.rs", line)).collect();
+        findings.push(unwrap("src/b.rs", 7));
+        findings.push(Finding::new(
+            "MD001",
+            "desc".to_string(),
+            Severity::Warning,
+            None,
+        ));
+
+        let groups = collapse_findings(&findings);
+        assert_eq!(groups.len(), 3);
+        assert_eq!(groups[0].count, 12);
+
+        let summary = groups[0].summary();
+        assert_eq!(
+            summary.message,
+            "unwrap used (12 occurrences: lines 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, ...)"
+        );
+        assert_eq!(summary.line_number, None);
+
+        // Single occurrences render unchanged
+        let single = groups[1].summary();
+        assert_eq!(single.message, "unwrap used");
+        assert_eq!(single.line_number, Some(7));
+        assert_eq!(groups[2].first.code, "MD001");
+    }
 }
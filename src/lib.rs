@@ -33,14 +33,29 @@
 //! - [`crates_io_api`] - Integration with crates.io API
 //! - [`code_checks`] - Static code analysis and pattern detection
 //! - [`config`] - Configuration file handling and settings
+//! - [`cache`] - Incremental caching of code-check results
+//! - [`checks`] - Registry of every check code and its category
+//! - [`render`] - Output formats and their renderers
+//! - [`baseline`] - Comparison against a baseline report
 
 // filepath: /home/sally-nwamama/Desktop/rust_projects/cargo-dokita/src/lib.rs
+use config::Config;
 use dependency_analysis::check_vulnerability;
-use diagnostics::{Finding, Severity};
+use diagnostics::{Finding, JsonReport, Severity};
+use render::OutputFormat;
 use reqwest::blocking::Client as HttpClient;
-use std::io::Write; // For termcolor
-use std::{fs, path::Path, process};
-use termcolor::{Color, ColorChoice, ColorSpec, StandardStream, WriteColor};
+use serde::Serialize;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process,
+    sync::{
+        Arc, Mutex, PoisonError,
+        mpsc::{self, RecvTimeoutError},
+    },
+    thread,
+};
+use termcolor::StandardStream;
 
 /// Cargo.toml manifest parsing and validation functionality.
 pub mod manifest;
@@ -60,6 +75,18 @@ pub mod code_checks;
 /// Configuration file handling and project settings management.
 pub mod config;
 
+/// Per-file cache of code-check results for incremental runs.
+pub mod cache;
+
+/// Central registry of check codes, categories and default severities.
+pub mod checks;
+
+/// Output formats and their renderers.
+pub mod render;
+
+/// Comparison against a baseline report to show only new findings.
+pub mod baseline;
+
 /// Error types that can occur during project analysis.
 #[derive(Debug)]
 pub enum MyError {
@@ -67,10 +94,48 @@ pub enum MyError {
     NotRustProject,
     /// The provided project path could not be resolved or canonicalized.
     UnresolvableProjectPath,
+    /// A configuration source (e.g. a `DOKITA_*` environment variable) held an invalid value.
+    InvalidConfig(String),
+    /// `cargo dokita init` could not write the configuration file.
+    InitFailed(String),
+    /// The `--baseline` report could not be read or parsed.
+    BaselineLoadFailed(String),
+    /// The `--write-baseline` file could not be written.
+    BaselineSaveFailed(String),
+    /// The `--input` report could not be read or parsed.
+    InputLoadFailed(String),
+    /// `cargo metadata` failed, so the dependency graph could not be built.
+    MetadataFailed(String),
+    /// `--since` was given but the changed files could not be determined with git.
+    GitDiffFailed(String),
+    /// `cargo dokita check` was given a code that is not in the check registry.
+    UnknownCheck(String),
     /// Analysis completed but found issues. Contains the list of findings for test purposes.
     HasIssues(Vec<Finding>), // For test purposes
 }
 
+impl std::fmt::Display for MyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MyError::NotRustProject => {
+                write!(f, "not a Rust project (no Cargo.toml found)")
+            }
+            MyError::UnresolvableProjectPath => write!(f, "could not resolve the project path"),
+            MyError::InvalidConfig(e) => write!(f, "invalid configuration: {e}"),
+            MyError::InitFailed(e) => write!(f, "could not write configuration file: {e}"),
+            MyError::BaselineLoadFailed(e) => write!(f, "could not load baseline: {e}"),
+            MyError::BaselineSaveFailed(e) => write!(f, "could not write baseline: {e}"),
+            MyError::InputLoadFailed(e) => write!(f, "could not load input report: {e}"),
+            MyError::MetadataFailed(e) => write!(f, "could not read cargo metadata: {e}"),
+            MyError::GitDiffFailed(e) => write!(f, "could not determine changed files: {e}"),
+            MyError::UnknownCheck(code) => write!(f, "unknown check code '{code}'"),
+            MyError::HasIssues(findings) => write!(f, "analysis found {} issues", findings.len()),
+        }
+    }
+}
+
+impl std::error::Error for MyError {}
+
 /// Analyzes a Rust project for potential issues and vulnerabilities.
 ///
 /// This function performs a comprehensive analysis of a Rust project, including:
@@ -83,7 +148,8 @@ pub enum MyError {
 /// # Arguments
 ///
 /// * `project_path` - Path to the root directory of the Rust project to analyze
-/// * `output_format` - Output format for results ("json" for JSON output, anything else for human-readable text)
+/// * `output_format` - Output format for results ("json" for a JSON report, "jsonl" for one JSON
+///   object per line, anything else for human-readable text)
 ///
 /// # Returns
 ///
@@ -96,7 +162,8 @@ pub enum MyError {
 ///
 /// - If no issues are found, prints a success message in green
 /// - If issues are found, outputs them according to the specified format
-/// - Calls `process::exit(1)` if any errors or warnings are found
+/// - Calls `process::exit(1)` if any finding reaches the `fail_on` severity
+///   (errors or warnings by default)
 /// - Supports parallel execution of some analysis phases for improved performance
 ///
 /// # Examples
@@ -116,160 +183,441 @@ pub enum MyError {
 /// This function may panic if there are issues with terminal color output,
 /// but such panics are handled gracefully with `unwrap_or_default()`.
 pub fn analyze_project(project_path: &str, output_format: &str) -> Result<(), MyError> {
-    let mut findings: Vec<Finding> = Vec::new();
-    let project_path = match fs::canonicalize(project_path) {
-        Ok(path) => path,
-        Err(e) => {
-            eprint!("Error Could not resolve project path - {e:?}");
-            return Err(MyError::UnresolvableProjectPath);
-        }
-    };
+    analyze_project_with_overrides(project_path, output_format, Config::default())
+}
 
-    let mut stdout = StandardStream::stdout(ColorChoice::Auto);
+/// Same as [`analyze_project`], but layers `overrides` (typically derived from
+/// command-line flags) on top of the project's configuration file.
+///
+/// Precedence is command-line overrides, then `DOKITA_*` environment variables
+/// (see [`Config::from_env`]), then the project file, then defaults.
+/// See [`Config::merge`].
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use cargo_dokita::{analyze_project_with_overrides, config::Config};
+///
+/// let mut overrides = Config::default();
+/// overrides.general.offline = Some(true);
+/// analyze_project_with_overrides("./my-project", "human", overrides).unwrap();
+/// ```
+pub fn analyze_project_with_overrides(
+    project_path: &str,
+    output_format: &str,
+    overrides: Config,
+) -> Result<(), MyError> {
+    let (project_path, layers) = resolve_layers(project_path, overrides)?;
+    let members = MemberConfigs::load(&project_path, &layers);
+    report_findings(
+        &project_path,
+        &layers.merged(),
+        &members,
+        output_format,
+        None,
+    )
+}
 
-    let config = match config::Config::load_from_project_root(&project_path) {
-        Ok(cfg) => {
-            if project_path.join(config::CONFIG_FILE_NAME).exists() {
-                println!("Loaded configuration from {}", config::CONFIG_FILE_NAME);
-            }
-            cfg
-        }
-        Err(e) => {
-            println!(
-                "Warning: Could not load or parse {}: {}. Using default configuration.",
-                config::CONFIG_FILE_NAME,
-                e
-            );
-            // Optionally add a Finding for bad config
-            config::Config::default()
-        }
-    };
+/// Runs a single check against the project and reports only its findings
+/// (`cargo dokita check <CODE>`), using the same output formats and exit code as a full run.
+///
+/// The check is enabled even if it is opt-in or disabled in the configuration, and
+/// crates.io lookups and the vulnerability scan are skipped unless the check needs them.
+/// Returns [`MyError::UnknownCheck`] if `code` is not in [`checks::CHECKS`].
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use cargo_dokita::{config::Config, run_check};
+///
+/// run_check("./my-project", "MD001", "json", Config::default()).unwrap();
+/// ```
+pub fn run_check(
+    project_path: &str,
+    code: &str,
+    output_format: &str,
+    mut overrides: Config,
+) -> Result<(), MyError> {
+    let check = checks::lookup(&code.to_ascii_uppercase())
+        .ok_or_else(|| MyError::UnknownCheck(code.to_string()))?;
+
+    overrides
+        .checks
+        .enabled
+        .insert(check.code.to_string(), true);
+    if !checks::OUTDATED_CHECK_CODES.contains(&check.code) {
+        overrides.general.offline = Some(true);
+    }
+    if !check.code.starts_with("SEC") && !check.code.starts_with("AUD") {
+        overrides.general.vulnerability_scan = Some(false);
+    }
+
+    let (project_path, layers) = resolve_layers(project_path, overrides)?;
+    let members = MemberConfigs::load(&project_path, &layers);
+    report_findings(
+        &project_path,
+        &layers.merged(),
+        &members,
+        output_format,
+        Some(check.code),
+    )
+}
+
+/// Renders the findings of a saved `--format json` report in `output_format` without
+/// analyzing anything (`--input`). The configuration still applies to the rendering
+/// (`rule_prefix`, `group_by`, `--max-findings`, color), and the JSON envelope records
+/// the `fail_on` outcome, but the exit code is always zero once the report is written.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use cargo_dokita::{config::Config, render_input};
+///
+/// render_input("./", "dokita.json", "markdown", Config::default()).unwrap();
+/// ```
+pub fn render_input(
+    project_path: &str,
+    input: &str,
+    output_format: &str,
+    overrides: Config,
+) -> Result<(), MyError> {
+    let (project_path, layers) = resolve_layers(project_path, overrides)?;
+    let config = layers.merged();
+    let mut findings = fs::read_to_string(input)
+        .map_err(|e| format!("Failed to read {input:?}: {e}"))
+        .and_then(|content| {
+            JsonReport::parse(&content).map_err(|e| format!("Failed to parse {input:?}: {e}"))
+        })
+        .map_err(|e| {
+            eprintln!("Error: {e}");
+            MyError::InputLoadFailed(e)
+        })?
+        .findings;
 
-    let rust_files = code_checks::collect_rust_files(project_path.as_path());
-    findings.extend(code_checks::check_code_patterns(&rust_files, &project_path));
+    let fails = MemberConfigs::load(&project_path, &layers).fails_gate(&findings, &config);
+    let omitted = config
+        .general
+        .max_findings
+        .map_or(0, |max| truncate_findings(&mut findings, max));
+    let format = OutputFormat::from_name(output_format);
+    let mut stdout = StandardStream::stdout(config.color_choice());
+    if let Err(e) = format
+        .renderer(&config, omitted, fails)
+        .render(&findings, &mut stdout)
+    {
+        eprintln!("Error writing output: {e:?}");
+        process::exit(1);
+    }
+    Ok(())
+}
 
+/// Prints the direct dependencies of every workspace member as a Graphviz DOT graph, or
+/// as JSON when `graph_format` is `json`, then returns without analyzing
+/// (`--dependency-graph`). Unless offline, each crates.io dependency is annotated with
+/// its latest version and whether it is outdated.
+pub fn print_dependency_graph(
+    project_path: &str,
+    graph_format: &str,
+    overrides: Config,
+) -> Result<(), MyError> {
+    let (project_path, config) = resolve_config(project_path, overrides)?;
     if !is_rust_project(&project_path) {
         eprintln!("This is not a rust project");
         return Err(MyError::NotRustProject);
     }
+    let metadata = dependency_analysis::get_project_metadata(&project_path.join("Cargo.toml"))
+        .map_err(|e| {
+            eprintln!("Error: {e}");
+            MyError::MetadataFailed(e.to_string())
+        })?;
 
-    let cargo_toml_path = project_path.join("Cargo.toml");
+    let mut graph = dependency_analysis::DependencyGraph::from_metadata(&metadata);
+    if !config.is_offline() {
+        graph.annotate_latest(
+            &metadata,
+            &HttpClient::new(),
+            config.registry_url(),
+            config.general.registry_token.as_deref(),
+            config.network_concurrency(),
+        );
+    }
+    if graph_format == "json" {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&graph).unwrap_or_default()
+        );
+    } else {
+        print!("{}", graph.to_dot());
+    }
+    Ok(())
+}
 
-    let cargo_manifest = manifest::CargoManifest::parse(cargo_toml_path.as_path());
+/// Collects findings, applies the baseline and `--max-findings`, renders them in
+/// `output_format` and exits with [`diagnostics::FAILING_EXIT_CODE`] if the gate fails.
+/// When `only_code` is set, every other finding is dropped before reporting.
+fn report_findings(
+    project_path: &Path,
+    config: &Config,
+    members: &MemberConfigs,
+    output_format: &str,
+    only_code: Option<&str>,
+) -> Result<(), MyError> {
+    let mut stdout = StandardStream::stdout(config.color_choice());
 
-    if let Ok(data) = &cargo_manifest {
-        findings.extend(code_checks::check_project_structure(
-            &project_path,
-            Some(data),
-        ));
+    let mut findings = match collect_findings(project_path, config, members) {
+        Ok(findings) => findings,
+        Err(MyError::NotRustProject) => {
+            eprintln!("This is not a rust project");
+            return Err(MyError::NotRustProject);
+        }
+        Err(MyError::GitDiffFailed(e)) => {
+            eprintln!("Error: {e}");
+            return Err(MyError::GitDiffFailed(e));
+        }
+        Err(e) => return Err(e),
+    };
+    // Decided on the whole analysis, before any finding is filtered out for reporting
+    let skipped = checks::skipped_checks(&findings, config);
+    if let Some(code) = only_code {
+        findings.retain(|f| f.code == code);
+    }
+    // The written baseline accepts everything found, before any earlier baseline applies
+    if let Some(write_path) = &config.general.write_baseline {
+        baseline::Baseline::from_findings(&findings, project_path)
+            .save(Path::new(write_path))
+            .map_err(|e| {
+                eprintln!("Error: {e}");
+                MyError::BaselineSaveFailed(e)
+            })?;
+    }
+    // In baseline mode only new findings are reported and can fail the run
+    let mut fixed_since_baseline = None;
+    if let Some(baseline_path) = &config.general.baseline {
+        let baseline = baseline::load_baseline(
+            Path::new(baseline_path),
+            config.baseline_format(),
+            project_path,
+        )
+        .map_err(|e| {
+            eprintln!("Error: {e}");
+            MyError::BaselineLoadFailed(e)
+        })?;
+        let diff = baseline::diff_against_baseline(findings, &baseline, project_path);
+        findings = diff.new;
+        fixed_since_baseline = Some(diff.fixed);
     }
+    // The exit code is decided on every finding, even those cut by --max-findings
+    let fails = members.fails_gate(&findings, config);
+    let check_summary = config
+        .summary_by_check()
+        .then(|| render::check_summary(&findings, config, &skipped));
+    let omitted = config
+        .general
+        .max_findings
+        .map_or(0, |max| truncate_findings(&mut findings, max));
 
-    let http_client = HttpClient::new();
+    let format = OutputFormat::from_name(output_format);
+    if let Err(e) = format
+        .renderer(config, omitted, fails)
+        .render(&findings, &mut stdout)
+    {
+        eprintln!("Error writing output: {e:?}");
+        process::exit(1);
+    }
+    if omitted > 0 && !format.reports_omitted() {
+        // Keep machine-readable stdout parseable
+        eprintln!("... and {omitted} more (limit reached)");
+    }
+    if let Some(summary) = check_summary {
+        if format.is_machine_readable() {
+            eprint!("\n{summary}");
+        } else {
+            print!("\n{summary}");
+        }
+    }
+    if let Some(fixed) = fixed_since_baseline {
+        let note = format!(
+            "{} new finding(s), {fixed} fixed since baseline",
+            findings.len() + omitted
+        );
+        if format.is_machine_readable() {
+            eprintln!("{note}");
+        } else {
+            println!("{note}");
+        }
+    }
 
-    let (manifest_findings, dep_findings) = rayon::join(
-        || {
-            let mut f = Vec::new();
-            // md is Option<CargoManifest>
-            if let Ok(md) = cargo_manifest {
-                f.extend(manifest::check_missing_metadata(&md, &config));
-                f.extend(manifest::check_dependency_versions(&md, &config));
-                f.extend(manifest::check_rust_edition(&md));
-            }
-            f
-        },
-        || {
-            let mut f = Vec::new();
-            match dependency_analysis::get_project_metadata(cargo_toml_path.as_path()) {
-                Ok(metadata) => {
-                    let outdated_dependencies_findings =
-                        dependency_analysis::check_outdated_dependencies(&metadata, &http_client);
-                    f.extend(outdated_dependencies_findings);
-                }
-                Err(e) => {
-                    println!("{e:?}");
-                }
-            }
-            let vulnerability_findings = check_vulnerability(project_path.as_path());
-            f.extend(vulnerability_findings);
-            f
-        },
-    );
+    if fails {
+        process::exit(diagnostics::FAILING_EXIT_CODE);
+    }
 
-    findings.extend(manifest_findings);
-    findings.extend(dep_findings);
+    Ok(())
+}
 
-    findings.extend(code_checks::check_missing_denied_lints(
-        project_path.as_path(),
-        &config,
-    ));
+/// Canonicalizes `project_path` and builds the effective configuration: the project
+/// file, then `DOKITA_*` environment variables, then `overrides`.
+fn resolve_config(project_path: &str, overrides: Config) -> Result<(PathBuf, Config), MyError> {
+    let (project_path, layers) = resolve_layers(project_path, overrides)?;
+    Ok((project_path, layers.merged()))
+}
 
-    if findings.is_empty() {
-        stdout
-            .set_color(ColorSpec::new().set_fg(Some(Color::Green)))
-            .unwrap_or_default();
-        writeln!(
-            &mut stdout,
-            "No issues found. Your project looks healthy (based on current checks)!"
+/// The configuration of a run, kept in layers so workspace member metadata can be
+/// slotted in between the project file and the environment (see [`MemberConfigs`]).
+struct ConfigLayers {
+    /// The project's `.cargo-dokita.toml` or `[package.metadata.dokita]`.
+    file: Config,
+    /// `DOKITA_*` environment variables with the command-line overrides on top.
+    overlay: Config,
+}
+
+impl ConfigLayers {
+    /// The effective configuration: `overlay` over `file`.
+    fn merged(&self) -> Config {
+        Config::merge(self.file.clone(), self.overlay.clone())
+    }
+
+    /// The effective configuration of a workspace member: its `member` metadata over
+    /// the project file, under the environment and command line.
+    fn with_member(&self, member: Config) -> Config {
+        Config::merge(
+            Config::merge(self.file.clone(), member),
+            self.overlay.clone(),
         )
-        .unwrap_or_default();
-        stdout.reset().unwrap_or_default();
-    } else {
-        if output_format == "json" {
-            match serde_json::to_string_pretty(&findings) {
-                Ok(json_output) => println!("{json_output}",),
-                Err(e) => {
-                    eprintln!("Error serializing findings to JSON: {e:?}");
-                    process::exit(1);
-                }
-            }
-        } else {
-            for finding in &findings {
-                // Basic output, can be improved with termcolor later
-                let severity_str = match finding.severity {
-                    Severity::Error => "ERROR",
-                    Severity::Warning => "WARNING",
-                    Severity::Note => "NOTE",
-                };
-
-                stdout
-                    .set_color(ColorSpec::new().set_fg(Some(Color::Yellow)).set_bold(true))
-                    .unwrap_or_default();
-                write!(&mut stdout, "[{severity_str}]").unwrap_or_default();
-                stdout.reset().unwrap_or_default();
-
-                let file_info = finding.file_path.as_deref().unwrap_or("N/A");
-                let line_info = finding
-                    .line_number
-                    .map_or("".to_string(), |l| format!("{l}"));
-
-                stdout
-                    .set_color(ColorSpec::new().set_fg(Some(Color::Magenta)))
-                    .unwrap_or_default();
-                write!(&mut stdout, " ({})", finding.code).unwrap_or_default();
-                stdout.reset().unwrap_or_default();
-
-                writeln!(
-                    &mut stdout,
-                    ": {} [{}{}]",
-                    finding.message, file_info, line_info
-                )
-                .unwrap_or_default();
-            }
+    }
+}
+
+/// Like [`resolve_config`], but returns the layers the configuration is merged from.
+fn resolve_layers(
+    project_path: &str,
+    overrides: Config,
+) -> Result<(PathBuf, ConfigLayers), MyError> {
+    let project_path = match fs::canonicalize(project_path) {
+        Ok(path) => path,
+        Err(e) => {
+            eprint!("Error Could not resolve project path - {e:?}");
+            return Err(MyError::UnresolvableProjectPath);
         }
+    };
 
-        writeln!(&mut stdout, "\nFound {} issues:", findings.len()).unwrap_or_default();
+    let file_config = match Config::load_from_project_root(&project_path) {
+        Ok(cfg) => {
+            if project_path.join(config::CONFIG_FILE_NAME).exists() {
+                // Status goes to stderr so machine-readable stdout stays parseable
+                eprintln!("Loaded configuration from {}", config::CONFIG_FILE_NAME);
+            }
+            cfg
+        }
+        Err(e) => {
+            eprintln!("Warning: Could not load configuration: {e}. Using default configuration.");
+            // Optionally add a Finding for bad config
+            Config::default()
+        }
+    };
+    let env_config = match Config::from_env() {
+        Ok(cfg) => cfg,
+        Err(e) => {
+            eprintln!("Error: {e}");
+            return Err(MyError::InvalidConfig(e));
+        }
+    };
+    let layers = ConfigLayers {
+        file: file_config,
+        overlay: Config::merge(env_config, overrides),
+    };
+    let config = layers.merged();
+    if config.is_check_enabled("CODE035")
+        && let Err(e) = regex::Regex::new(config.todo_issue_pattern())
+    {
+        eprintln!(
+            "Warning: Invalid todo_issue_pattern '{}': {e}. Using the default pattern.",
+            config.todo_issue_pattern()
+        );
     }
+    Ok((project_path, layers))
+}
 
-    if findings
-        .iter()
-        .any(|f| matches!(f.severity, Severity::Error | Severity::Warning))
-    {
-        process::exit(1);
+/// Prints every registered check with its enabled state and effective severity under
+/// the project's configuration, without running any analysis (`--show-enabled`).
+pub fn show_enabled_checks(project_path: &str, overrides: Config) -> Result<(), MyError> {
+    let (_, config) = resolve_config(project_path, overrides)?;
+    for check in checks::CHECKS {
+        println!(
+            "{:<10} {:<18} {:?}",
+            check.code,
+            checks::CheckState::resolve(check.code, &config).label(),
+            checks::effective_severity(check, &config)
+        );
     }
+    Ok(())
+}
+
+/// Settings resolved from the configuration and command line for a run, shown by
+/// `--print-config` next to the merged [`Config`].
+#[derive(Serialize)]
+struct RuntimeOptions<'a> {
+    project_path: String,
+    format: &'a str,
+    offline: bool,
+    jobs: usize,
+    network_concurrency: usize,
+    cache: bool,
+    vulnerability_scan: bool,
+    fail_on: Severity,
+    registry_url: &'a str,
+}
+
+/// Layout of `--print-config` output.
+#[derive(Serialize)]
+struct PrintedConfig<'a> {
+    runtime: RuntimeOptions<'a>,
+    general: &'a config::GeneralConfig,
+    checks: &'a config::ChecksConfig,
+}
 
+/// Prints the effective configuration (project file, `DOKITA_*` environment variables
+/// and `overrides` merged) with the resolved runtime options, then returns without
+/// analyzing (`--print-config`). JSON for the `json`/`jsonl` formats, TOML otherwise.
+pub fn print_config(
+    project_path: &str,
+    output_format: &str,
+    overrides: Config,
+) -> Result<(), MyError> {
+    let (project_path, config) = resolve_config(project_path, overrides)?;
+    println!("{}", render_config(&project_path, &config, output_format)?);
     Ok(())
 }
 
+fn render_config(
+    project_path: &Path,
+    config: &Config,
+    output_format: &str,
+) -> Result<String, MyError> {
+    let printed = PrintedConfig {
+        runtime: RuntimeOptions {
+            project_path: project_path.display().to_string(),
+            format: output_format,
+            offline: config.is_offline(),
+            jobs: config
+                .general
+                .jobs
+                .unwrap_or_else(rayon::current_num_threads),
+            network_concurrency: config.network_concurrency(),
+            cache: config.use_cache(),
+            vulnerability_scan: config.vulnerability_scan(),
+            fail_on: config.fail_on(),
+            registry_url: config.registry_url(),
+        },
+        general: &config.general,
+        checks: &config.checks,
+    };
+    let rendered = match output_format {
+        "json" => serde_json::to_string_pretty(&printed).map_err(|e| e.to_string()),
+        "jsonl" => serde_json::to_string(&printed).map_err(|e| e.to_string()),
+        _ => toml::to_string(&printed).map_err(|e| e.to_string()),
+    };
+    rendered.map_err(MyError::InvalidConfig)
+}
+
 /// Test-friendly version of [`analyze_project`] that returns findings instead of calling `process::exit`.
 ///
 /// This function performs the same analysis as [`analyze_project`] but is designed for use in tests
@@ -313,7 +661,6 @@ pub fn analyze_project_for_test(
     project_path: &str,
     _output_format: &str,
 ) -> Result<Vec<Finding>, MyError> {
-    let mut findings: Vec<Finding> = Vec::new();
     let project_path = match fs::canonicalize(project_path) {
         Ok(path) => path,
         Err(e) => {
@@ -322,63 +669,428 @@ pub fn analyze_project_for_test(
         }
     };
 
-    let config = config::Config::load_from_project_root(&project_path).unwrap_or_default();
+    let layers = ConfigLayers {
+        file: Config::load_from_project_root(&project_path).unwrap_or_default(),
+        overlay: Config::default(),
+    };
+    let members = MemberConfigs::load(&project_path, &layers);
 
-    // Code checks first (before checking if it's a Rust project)
-    let rust_files = code_checks::collect_rust_files(project_path.as_path());
-    findings.extend(code_checks::check_code_patterns(&rust_files, &project_path));
+    collect_findings(&project_path, &layers.merged(), &members)
+}
 
-    if !is_rust_project(&project_path) {
+/// Runs every analysis phase against an already-canonicalized project path.
+///
+/// Shared by [`analyze_project_with_overrides`] and [`analyze_project_for_test`] so the
+/// effective configuration is applied in exactly one place.
+///
+/// With a `deadline`, the findings collected when it passes are returned with a
+/// TIMEOUT001 warning instead of waiting for the remaining phases. Either way the
+/// findings are filtered under `members` (see [`filter_reported`]).
+fn collect_findings(
+    project_path: &Path,
+    config: &Config,
+    members: &MemberConfigs,
+) -> Result<Vec<Finding>, MyError> {
+    let Some(deadline) = config.deadline() else {
+        return run_checks_in_pool(project_path, config, &Mutex::new(Vec::new()))
+            .map(|findings| filter_reported(findings, members, config));
+    };
+
+    // The analysis runs on its own thread so it can be abandoned once the deadline passes
+    let partial = Arc::new(Mutex::new(Vec::new()));
+    let (sender, receiver) = mpsc::channel();
+    let analysis = {
+        let project_path = project_path.to_path_buf();
+        let config = config.clone();
+        let partial = Arc::clone(&partial);
+        thread::spawn(move || {
+            let _ = sender.send(run_checks_in_pool(&project_path, &config, &partial));
+        })
+    };
+    match receiver.recv_timeout(deadline) {
+        Ok(result) => result.map(|findings| filter_reported(findings, members, config)),
+        Err(RecvTimeoutError::Timeout) => {
+            let mut findings =
+                std::mem::take(&mut *partial.lock().unwrap_or_else(PoisonError::into_inner));
+            findings.push(Finding::new(
+                "TIMEOUT001",
+                format!(
+                    "Analysis stopped at the {}s deadline; findings from unfinished phases are missing.",
+                    deadline.as_secs()
+                ),
+                Severity::Warning,
+                None,
+            ));
+            Ok(filter_reported(findings, members, config))
+        }
+        // The sender only goes away without sending when the analysis thread panicked
+        Err(RecvTimeoutError::Disconnected) => match analysis.join() {
+            Err(panic) => std::panic::resume_unwind(panic),
+            Ok(()) => unreachable!("analysis thread exited without a result"),
+        },
+    }
+}
+
+/// Runs [`run_checks`] on a pool of `jobs` threads when set, otherwise on the global pool.
+fn run_checks_in_pool(
+    project_path: &Path,
+    config: &Config,
+    partial: &Mutex<Vec<Finding>>,
+) -> Result<Vec<Finding>, MyError> {
+    if let Some(jobs) = config.general.jobs
+        && let Ok(pool) = rayon::ThreadPoolBuilder::new().num_threads(jobs).build()
+    {
+        return pool.install(|| run_checks(project_path, config, partial));
+    }
+    run_checks(project_path, config, partial)
+}
+
+/// Runs the local code and manifest checks, then the network and audit phase
+/// (skipped under `fail_fast` once a local check reported an error).
+///
+/// The findings of each finished phase are copied into `partial`, which is what gets
+/// reported if the `deadline` passes before the analysis completes.
+fn run_checks(
+    project_path: &Path,
+    config: &Config,
+    partial: &Mutex<Vec<Finding>>,
+) -> Result<Vec<Finding>, MyError> {
+    if !is_rust_project(project_path) {
         return Err(MyError::NotRustProject);
     }
 
+    let mut findings: Vec<Finding> = Vec::new();
+
+    let rust_files: Vec<PathBuf> = code_checks::collect_source_files(
+        project_path,
+        config.extra_source_dirs(),
+        &config.source_extensions(),
+    )
+    .into_iter()
+    .filter(|file| !is_excluded(file, project_path, config))
+    .collect();
+    // Per-line pattern checks only look at changed files; crate-wide checks still see everything
+    let pattern_files: Vec<PathBuf> = match &config.general.since {
+        Some(git_ref) => {
+            let changed = changed_files_since(project_path, git_ref)?;
+            rust_files
+                .iter()
+                .filter(|file| changed.contains(file))
+                .cloned()
+                .collect()
+        }
+        None => rust_files.clone(),
+    };
+    // A --since run only sees a slice of the files, so it must not rewrite the cache
+    if config.use_cache() && config.general.since.is_none() {
+        findings.extend(cache::check_code_patterns_cached(
+            &pattern_files,
+            project_path,
+            config,
+        ));
+    } else {
+        findings.extend(code_checks::check_code_patterns(
+            &pattern_files,
+            project_path,
+            config,
+        ));
+    }
+    record_partial(partial, &findings);
+
     let cargo_toml_path = project_path.join("Cargo.toml");
     let cargo_manifest = manifest::CargoManifest::parse(cargo_toml_path.as_path());
 
     if let Ok(data) = &cargo_manifest {
         findings.extend(code_checks::check_project_structure(
-            &project_path,
+            project_path,
             Some(data),
         ));
+        findings.extend(code_checks::check_workspace_members(
+            project_path,
+            data,
+            config,
+        ));
+        findings.extend(code_checks::check_workspace_editions(
+            project_path,
+            data,
+            config,
+        ));
+        findings.extend(code_checks::check_binary_name_collisions(
+            project_path,
+            data,
+            config,
+        ));
+        // no_std crates commonly use embedded executors that CODE019 does not know about
+        if !code_checks::is_no_std_crate(project_path) {
+            findings.extend(code_checks::check_async_runtime(&rust_files, data, config));
+        }
+        findings.extend(code_checks::check_println_with_logging_crate(
+            &rust_files,
+            project_path,
+            data,
+            config,
+        ));
+        findings.extend(code_checks::check_async_main_runtime(
+            project_path,
+            data,
+            config,
+        ));
     }
 
-    let http_client = HttpClient::new();
-
-    let (manifest_findings, dep_findings) = rayon::join(
-        || {
-            let mut f = Vec::new();
-            if let Ok(md) = cargo_manifest {
-                f.extend(manifest::check_missing_metadata(&md, &config));
-                f.extend(manifest::check_dependency_versions(&md, &config));
-                f.extend(manifest::check_rust_edition(&md));
-            }
-            f
-        },
-        || {
-            let mut f = Vec::new();
-            if let Ok(metadata) =
-                dependency_analysis::get_project_metadata(cargo_toml_path.as_path())
-            {
-                let outdated_dependencies_findings =
-                    dependency_analysis::check_outdated_dependencies(&metadata, &http_client);
-                f.extend(outdated_dependencies_findings);
-            }
-            let vulnerability_findings = check_vulnerability(project_path.as_path());
-            f.extend(vulnerability_findings);
-            f
-        },
-    );
-
-    findings.extend(manifest_findings);
-    findings.extend(dep_findings);
+    if let Ok(md) = &cargo_manifest {
+        findings.extend(manifest::check_missing_metadata(md, config));
+        findings.extend(manifest::check_dependency_versions(md, config));
+        findings.extend(manifest::check_binary_without_lockfile(
+            md,
+            project_path,
+            config,
+        ));
+        findings.extend(manifest::check_default_features(md, config));
+        findings.extend(manifest::check_features(md, config));
+        if let Ok(content) = fs::read_to_string(&cargo_toml_path) {
+            findings.extend(manifest::check_sorted_dependencies(&content, config));
+        }
+        findings.extend(manifest::check_patch_and_replace(md, config));
+        findings.extend(manifest::check_redundant_dependencies(md, config));
+        findings.extend(manifest::check_dev_only_dependencies(md, config));
+        findings.extend(manifest::check_rust_edition(md));
+        findings.extend(manifest::check_edition_rust_version(md, config));
+        findings.extend(manifest::check_library_rust_version(
+            md,
+            project_path,
+            config,
+        ));
+    }
     findings.extend(code_checks::check_missing_denied_lints(
-        project_path.as_path(),
-        &config,
+        project_path,
+        config,
+    ));
+    findings.extend(code_checks::check_rust_toolchain(project_path, config));
+    findings.extend(code_checks::check_test_files_without_tests(
+        &rust_files,
+        project_path,
+        config,
+    ));
+    findings.extend(code_checks::check_build_script_rerun(project_path, config));
+    findings.extend(code_checks::check_no_std_usage(
+        &rust_files,
+        project_path,
+        config,
+    ));
+    findings.extend(code_checks::check_forbid_unsafe_code(
+        &rust_files,
+        project_path,
+        config,
     ));
+    findings.extend(code_checks::check_line_endings(&rust_files, config));
+    findings.extend(code_checks::check_duplicate_test_names(
+        project_path,
+        &rust_files,
+        config,
+    ));
+    findings.extend(code_checks::check_large_source_assets(project_path, config));
+
+    record_partial(partial, &findings);
+
+    // The network and audit phase runs last so --fail-fast can skip it after a local error
+    if !checks::stops_before_network_phase(&findings, config) {
+        let http_client = HttpClient::new();
+        let patched = cargo_manifest
+            .as_ref()
+            .map(manifest::CargoManifest::crates_io_patches)
+            .unwrap_or_default();
+
+        let (outdated_findings, vulnerability_findings) = rayon::join(
+            || {
+                if config.is_offline() {
+                    return Vec::new();
+                }
+                match dependency_analysis::get_project_metadata(cargo_toml_path.as_path()) {
+                    Ok(metadata) => dependency_analysis::check_outdated_dependencies(
+                        &metadata,
+                        &http_client,
+                        config.registry_url(),
+                        config.general.registry_token.as_deref(),
+                        config.network_concurrency(),
+                        &dependency_analysis::OutdatedOptions {
+                            severity: config.outdated_severity(),
+                            patched: &patched,
+                            ignored: config.outdated_ignore(),
+                        },
+                    ),
+                    Err(e) => {
+                        eprintln!("{e:?}");
+                        Vec::new()
+                    }
+                }
+            },
+            || {
+                if config.vulnerability_scan() {
+                    check_vulnerability(project_path)
+                } else {
+                    Vec::new()
+                }
+            },
+        );
+        findings.extend(outdated_findings);
+        findings.extend(vulnerability_findings);
+    }
 
     Ok(findings)
 }
 
+/// Replaces the findings reported on a timeout with those of the phases finished so far.
+fn record_partial(partial: &Mutex<Vec<Finding>>, findings: &[Finding]) {
+    *partial.lock().unwrap_or_else(PoisonError::into_inner) = findings.to_vec();
+}
+
+/// Drops findings of disabled checks and those below `severity_threshold`, under the
+/// configuration of the workspace member each finding belongs to (see [`MemberConfigs`]).
+fn filter_reported(
+    mut findings: Vec<Finding>,
+    members: &MemberConfigs,
+    config: &Config,
+) -> Vec<Finding> {
+    findings.retain(|f| is_reported(f, members.config_for(f, config)));
+    findings
+}
+
+/// Whether `finding` passes `config`: its check is enabled and it meets `severity_threshold`.
+fn is_reported(finding: &Finding, config: &Config) -> bool {
+    // Not every check consults the configuration, so disabled codes are dropped here too
+    config.is_check_enabled(&finding.code)
+        && config
+            .general
+            .severity_threshold
+            .as_ref()
+            .is_none_or(|threshold| finding.severity >= *threshold)
+}
+
+/// Effective configuration of each workspace member: its `[package.metadata.dokita]`
+/// layered over the root project file, under the environment and command line (see
+/// [`ConfigLayers::with_member`]). It decides which findings in the member's files are
+/// reported and whether they fail the run. Checks run under the root configuration, so
+/// a member can disable a check or raise `severity_threshold`, but cannot enable a check
+/// the root disabled. Loaded once per run.
+#[derive(Default)]
+struct MemberConfigs {
+    members: Vec<(PathBuf, Config)>,
+}
+
+impl MemberConfigs {
+    /// Reads the member configurations of the workspace rooted at `project_path`. Outside
+    /// a workspace, or when its manifest cannot be parsed, there are none.
+    fn load(project_path: &Path, layers: &ConfigLayers) -> Self {
+        let workspace = manifest::CargoManifest::parse(&project_path.join("Cargo.toml"))
+            .ok()
+            .and_then(|md| md.workspace);
+        let members = workspace
+            .map(|workspace| code_checks::workspace_member_dirs(project_path, &workspace))
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|dir| match Config::load_from_manifest_metadata(&dir) {
+                Ok(member) => Some((dir, layers.with_member(member))),
+                Err(e) => {
+                    eprintln!("Warning: {e}. Using the root configuration for this member.");
+                    None
+                }
+            })
+            .collect();
+        MemberConfigs { members }
+    }
+
+    /// Configuration that applies to `finding`: that of the innermost member containing
+    /// its file, or `root` for findings outside every member.
+    fn config_for<'a>(&'a self, finding: &Finding, root: &'a Config) -> &'a Config {
+        let Some(file) = finding.file_path.as_deref().map(Path::new) else {
+            return root;
+        };
+        self.members
+            .iter()
+            .filter(|(dir, _)| file.starts_with(dir))
+            .max_by_key(|(dir, _)| dir.components().count())
+            .map_or(root, |(_, member_config)| member_config)
+    }
+
+    /// Whether any finding reaches the `fail_on` severity of the configuration it falls under.
+    fn fails_gate(&self, findings: &[Finding], root: &Config) -> bool {
+        findings.iter().any(|finding| {
+            fails_gate(
+                std::slice::from_ref(finding),
+                &self.config_for(finding, root).fail_on(),
+            )
+        })
+    }
+}
+
+/// Files changed between `git_ref` and `HEAD` (`git diff --name-only <ref>...HEAD`),
+/// as absolute paths comparable with [`code_checks::collect_rust_files`] output.
+fn changed_files_since(project_path: &Path, git_ref: &str) -> Result<Vec<PathBuf>, MyError> {
+    let git = |args: &[&str]| -> Result<String, MyError> {
+        let output = process::Command::new("git")
+            .args(args)
+            .current_dir(project_path)
+            .output()
+            .map_err(|e| MyError::GitDiffFailed(format!("--since requires git: {e}")))?;
+        if !output.status.success() {
+            return Err(MyError::GitDiffFailed(format!(
+                "`git {}` failed: {}",
+                args.join(" "),
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    };
+
+    let toplevel = git(&["rev-parse", "--show-toplevel"])?;
+    let toplevel = fs::canonicalize(toplevel.trim()).map_err(|e| {
+        MyError::GitDiffFailed(format!("Could not resolve git repository root: {e}"))
+    })?;
+    let range = format!("{git_ref}...HEAD");
+    let changed = git(&["diff", "--name-only", &range])?;
+
+    Ok(changed
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| toplevel.join(line))
+        .collect())
+}
+
+/// Whether `file` falls under one of the configured `exclude` or `--ignore-path` paths.
+fn is_excluded(file: &Path, project_path: &Path, config: &Config) -> bool {
+    let relative = file.strip_prefix(project_path).unwrap_or(file);
+    config
+        .excluded_paths()
+        .into_iter()
+        .any(|excluded| relative.starts_with(excluded))
+}
+
+/// Whether any of the reported findings is at or above the `fail_on` severity.
+///
+/// This only drives the exit code. It is evaluated after `severity_threshold` has
+/// filtered the findings, so a finding hidden by the threshold can never fail the run.
+fn fails_gate(findings: &[Finding], fail_on: &Severity) -> bool {
+    findings.iter().any(|f| f.severity >= *fail_on)
+}
+
+/// Keeps the `max` most important findings (most severe first, then by file, line and
+/// code) and returns how many were dropped. The result is independent of scan order.
+fn truncate_findings(findings: &mut Vec<Finding>, max: usize) -> usize {
+    if findings.len() <= max {
+        return 0;
+    }
+    findings.sort_by(|a, b| {
+        b.severity
+            .cmp(&a.severity)
+            .then_with(|| a.file_path.cmp(&b.file_path))
+            .then_with(|| a.line_number.cmp(&b.line_number))
+            .then_with(|| a.code.cmp(&b.code))
+    });
+    let omitted = findings.len() - max;
+    findings.truncate(max);
+    omitted
+}
+
 /// Checks if the given path represents a valid Rust project.
 ///
 /// A directory is considered a valid Rust project if:
@@ -418,4 +1130,580 @@ fn is_rust_project(project_path: &Path) -> bool {
 /// This module contains tests for the core analysis functions and helper utilities.
 /// Tests use the [`analyze_project_for_test`] function to avoid side effects.
 #[cfg(test)]
-mod tests {}
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_my_error_display() {
+        let cases = [
+            (
+                MyError::NotRustProject,
+                "not a Rust project (no Cargo.toml found)",
+            ),
+            (
+                MyError::UnresolvableProjectPath,
+                "could not resolve the project path",
+            ),
+            (
+                MyError::InvalidConfig("bad value".to_string()),
+                "invalid configuration: bad value",
+            ),
+            (
+                MyError::InitFailed("exists".to_string()),
+                "could not write configuration file: exists",
+            ),
+            (
+                MyError::BaselineLoadFailed("missing".to_string()),
+                "could not load baseline: missing",
+            ),
+            (
+                MyError::BaselineSaveFailed("read-only".to_string()),
+                "could not write baseline: read-only",
+            ),
+            (
+                MyError::InputLoadFailed("missing".to_string()),
+                "could not load input report: missing",
+            ),
+            (
+                MyError::MetadataFailed("no lock".to_string()),
+                "could not read cargo metadata: no lock",
+            ),
+            (
+                MyError::GitDiffFailed("no git".to_string()),
+                "could not determine changed files: no git",
+            ),
+            (
+                MyError::HasIssues(vec![Finding::new(
+                    "MD001",
+                    "Missing description".to_string(),
+                    Severity::Warning,
+                    None,
+                )]),
+                "analysis found 1 issues",
+            ),
+        ];
+        for (error, expected) in cases {
+            assert_eq!(error.to_string(), expected);
+        }
+
+        let boxed: Box<dyn std::error::Error> = Box::new(MyError::NotRustProject);
+        assert_eq!(
+            boxed.to_string(),
+            "not a Rust project (no Cargo.toml found)"
+        );
+    }
+
+    #[test]
+    fn test_is_excluded_matches_path_prefixes() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let mut config = Config::default();
+        config.general.exclude = Some(vec!["src/generated".to_string()]);
+
+        assert!(is_excluded(
+            &root.join("src/generated/schema.rs"),
+            root,
+            &config
+        ));
+        assert!(!is_excluded(&root.join("src/lib.rs"), root, &config));
+        // Prefixes match whole path components only
+        assert!(!is_excluded(
+            &root.join("src/generated_extra.rs"),
+            root,
+            &config
+        ));
+    }
+
+    fn git(dir: &Path, args: &[&str]) {
+        let status = process::Command::new("git")
+            .args(["-c", "user.name=test", "-c", "user.email=test@example.com"])
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .unwrap()
+            .status;
+        assert!(status.success(), "git {args:?} failed");
+    }
+
+    #[test]
+    fn test_changed_files_since_lists_files_changed_after_ref() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = fs::canonicalize(temp_dir.path()).unwrap();
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::write(root.join("src/lib.rs"), "pub mod a;\n").unwrap();
+        fs::write(root.join("src/a.rs"), "").unwrap();
+
+        git(&root, &["init", "-q"]);
+        git(&root, &["add", "."]);
+        git(&root, &["commit", "-q", "-m", "base"]);
+        git(&root, &["tag", "base"]);
+        fs::write(root.join("src/a.rs"), "pub fn a() {}\n").unwrap();
+        git(&root, &["commit", "-q", "-am", "change a"]);
+
+        let changed = changed_files_since(&root, "base").unwrap();
+        assert_eq!(changed, vec![root.join("src/a.rs")]);
+
+        assert!(matches!(
+            changed_files_since(&root, "no-such-ref"),
+            Err(MyError::GitDiffFailed(_))
+        ));
+    }
+
+    #[test]
+    fn test_changed_files_since_outside_git_repo() {
+        let temp_dir = TempDir::new().unwrap();
+        let result = changed_files_since(temp_dir.path(), "main");
+        assert!(matches!(result, Err(MyError::GitDiffFailed(_))));
+    }
+
+    fn finding_with(severity: Severity) -> Finding {
+        Finding::new("TEST001", "test finding".to_string(), severity, None)
+    }
+
+    #[test]
+    fn test_fails_gate_on_error() {
+        let notes_and_warnings = vec![
+            finding_with(Severity::Note),
+            finding_with(Severity::Warning),
+        ];
+        assert!(!fails_gate(&notes_and_warnings, &Severity::Error));
+        assert!(fails_gate(
+            &[finding_with(Severity::Error)],
+            &Severity::Error
+        ));
+    }
+
+    #[test]
+    fn test_fails_gate_on_warning() {
+        assert!(!fails_gate(
+            &[finding_with(Severity::Note)],
+            &Severity::Warning
+        ));
+        assert!(fails_gate(
+            &[finding_with(Severity::Warning)],
+            &Severity::Warning
+        ));
+        assert!(fails_gate(
+            &[finding_with(Severity::Error)],
+            &Severity::Warning
+        ));
+    }
+
+    #[test]
+    fn test_fails_gate_on_note() {
+        assert!(fails_gate(&[finding_with(Severity::Note)], &Severity::Note));
+        assert!(!fails_gate(&[], &Severity::Note));
+    }
+
+    #[test]
+    fn test_truncate_findings_keeps_most_severe() {
+        let mut findings = vec![
+            finding_with(Severity::Note).with_line(1),
+            finding_with(Severity::Error).with_line(9),
+            finding_with(Severity::Warning).with_line(5),
+            finding_with(Severity::Error).with_line(2),
+        ];
+        assert!(fails_gate(&findings, &Severity::Warning));
+
+        let omitted = truncate_findings(&mut findings, 2);
+        assert_eq!(omitted, 2);
+        let lines: Vec<_> = findings.iter().map(|f| f.line_number).collect();
+        assert_eq!(lines, vec![Some(2), Some(9)]);
+
+        assert_eq!(truncate_findings(&mut findings, 5), 0);
+        assert_eq!(findings.len(), 2);
+    }
+
+    #[test]
+    fn test_baseline_reports_only_new_findings() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[package]\nname = \"demo\"\nversion = \"0.1.0\"\nedition = \"2021\"\n",
+        )
+        .unwrap();
+        fs::create_dir(root.join("src")).unwrap();
+        fs::write(root.join("src/main.rs"), "fn main() {}\n").unwrap();
+        fs::write(root.join("src/util.rs"), "// TODO: tidy\npub fn f() {}\n").unwrap();
+
+        let mut config = Config::default();
+        config.general.offline = Some(true);
+        config.general.cache = Some(false);
+        let baseline_path = root.join("baseline.json");
+        let report = diagnostics::JsonReport::new(
+            collect_findings(root, &config, &MemberConfigs::default()).unwrap(),
+        );
+        fs::write(&baseline_path, serde_json::to_string(&report).unwrap()).unwrap();
+
+        // Fix the TODO and introduce an unwrap
+        fs::write(
+            root.join("src/util.rs"),
+            "pub fn f() {\n    g().unwrap();\n}\n",
+        )
+        .unwrap();
+        let baseline =
+            baseline::load_baseline(&baseline_path, config::BaselineFormat::Auto, root).unwrap();
+        let diff = baseline::diff_against_baseline(
+            collect_findings(root, &config, &MemberConfigs::default()).unwrap(),
+            &baseline,
+            root,
+        );
+
+        let new_codes: Vec<&str> = diff.new.iter().map(|f| f.code.as_str()).collect();
+        assert_eq!(new_codes, vec!["CODE001"]);
+        assert_eq!(diff.fixed, 1);
+    }
+
+    #[test]
+    fn test_json_report_outcome_matches_gate() {
+        let findings = vec![
+            Finding::new("CODE004", "TODO".to_string(), Severity::Note, None),
+            Finding::new("MD001", "desc".to_string(), Severity::Warning, None),
+        ];
+        for fail_on in [Severity::Error, Severity::Warning, Severity::Note] {
+            let mut config = Config::default();
+            config.general.fail_on = Some(fail_on);
+            let fails = fails_gate(&findings, &config.fail_on());
+
+            let mut out = termcolor::NoColor::new(Vec::new());
+            OutputFormat::Json
+                .renderer(&config, 0, fails)
+                .render(&findings, &mut out)
+                .unwrap();
+            let output = String::from_utf8(out.into_inner()).unwrap();
+            let report: serde_json::Value = serde_json::Deserializer::from_str(&output)
+                .into_iter()
+                .next()
+                .unwrap()
+                .unwrap();
+
+            assert_eq!(report["passed"], !fails);
+            let expected_code = if fails {
+                diagnostics::FAILING_EXIT_CODE
+            } else {
+                0
+            };
+            assert_eq!(report["exit_code"], expected_code);
+        }
+    }
+
+    #[test]
+    fn test_ignore_prefixes_drop_whole_code_families() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[package]\nname = \"demo\"\nversion = \"0.1.0\"\nedition = \"2021\"\n",
+        )
+        .unwrap();
+        fs::create_dir(root.join("src")).unwrap();
+        fs::write(
+            root.join("src/main.rs"),
+            "// TODO: tidy\nfn main() {\n    g().unwrap();\n}\n",
+        )
+        .unwrap();
+
+        let mut config = Config::default();
+        config.general.offline = Some(true);
+        config.general.cache = Some(false);
+        config.general.vulnerability_scan = Some(false);
+        let findings = collect_findings(root, &config, &MemberConfigs::default()).unwrap();
+        assert!(findings.iter().any(|f| f.code.starts_with("CODE")));
+
+        config.checks.ignore_prefixes = Some(vec!["CODE".to_string()]);
+        let findings = collect_findings(root, &config, &MemberConfigs::default()).unwrap();
+        assert!(!findings.iter().any(|f| f.code.starts_with("CODE")));
+        assert!(findings.iter().any(|f| f.code == "MD001"));
+    }
+
+    #[test]
+    fn test_fail_fast_skips_network_phase_after_local_error() {
+        use httpmock::prelude::*;
+
+        let server = MockServer::start();
+        let lookup = server.mock(|when, then| {
+            when.method(GET).path("/serde");
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(serde_json::json!({
+                    "crate": { "max_version": "999.0.0" },
+                    "versions": [{ "num": "999.0.0", "yanked": false }]
+                }));
+        });
+
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        // A virtual workspace root has no [package], so MD005 reports an error, while
+        // cargo still resolves the member's dependencies for the network phase
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"app\"]\nresolver = \"2\"\n",
+        )
+        .unwrap();
+        fs::create_dir_all(root.join("app/src")).unwrap();
+        fs::write(
+            root.join("app/Cargo.toml"),
+            "[package]\nname = \"app\"\nversion = \"0.1.0\"\nedition = \"2021\"\n\n[dependencies]\nserde = \"1.0\"\n",
+        )
+        .unwrap();
+        fs::write(root.join("app/src/main.rs"), "fn main() {}\n").unwrap();
+
+        let mut config = Config::default();
+        config.general.registry_url = Some(server.base_url());
+        config.general.cache = Some(false);
+        config.general.vulnerability_scan = Some(false);
+
+        // Without fail_fast the lookup runs despite the error
+        let findings = collect_findings(root, &config, &MemberConfigs::default()).unwrap();
+        assert!(findings.iter().any(|f| f.code == "MD005"));
+        let hits = lookup.hits();
+        assert!(hits > 0);
+
+        config.general.fail_fast = Some(true);
+        let findings = collect_findings(root, &config, &MemberConfigs::default()).unwrap();
+        assert!(findings.iter().any(|f| f.code == "MD005"));
+        // No further lookups with fail_fast
+        assert_eq!(lookup.hits(), hits);
+    }
+
+    #[test]
+    fn test_deadline_reports_partial_findings() {
+        use httpmock::prelude::*;
+        use std::time::{Duration, Instant};
+
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(GET).path("/serde");
+            then.status(200)
+                .delay(Duration::from_secs(10))
+                .header("content-type", "application/json")
+                .json_body(serde_json::json!({
+                    "crate": { "max_version": "999.0.0" },
+                    "versions": [{ "num": "999.0.0", "yanked": false }]
+                }));
+        });
+
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[package]\nname = \"demo\"\nversion = \"0.1.0\"\nedition = \"2021\"\n\n[dependencies]\nserde = \"1.0\"\n",
+        )
+        .unwrap();
+        fs::create_dir(root.join("src")).unwrap();
+        fs::write(root.join("src/main.rs"), "fn main() {}\n").unwrap();
+
+        let mut config = Config::default();
+        config.general.registry_url = Some(server.base_url());
+        config.general.cache = Some(false);
+        config.general.vulnerability_scan = Some(false);
+        config.general.deadline = Some(1);
+
+        let started = Instant::now();
+        let findings = collect_findings(root, &config, &MemberConfigs::default()).unwrap();
+        assert!(started.elapsed() < Duration::from_secs(5));
+        let timeout = findings.iter().find(|f| f.code == "TIMEOUT001").unwrap();
+        assert_eq!(timeout.severity, Severity::Warning);
+        // Local checks finished before the deadline and are still reported
+        assert!(findings.iter().any(|f| f.code == "MD001"));
+        assert!(!findings.iter().any(|f| f.code == "DP002"));
+    }
+
+    #[test]
+    fn test_member_metadata_overrides_root_config() {
+        use httpmock::prelude::*;
+        use std::time::Duration;
+
+        let temp_dir = TempDir::new().unwrap();
+        let root = fs::canonicalize(temp_dir.path()).unwrap();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"crates/*\"]\nresolver = \"2\"\n",
+        )
+        .unwrap();
+        for (member, extra) in [
+            (
+                "prototype",
+                "\n[package.metadata.dokita.general]\nfail_on = \"error\"\n\n[package.metadata.dokita.checks]\nenabled = { \"CODE004\" = false }\n",
+            ),
+            (
+                "published",
+                "\n[dependencies]\nserde = \"1.0\"\n\n[package.metadata.dokita.general]\nfail_on = \"note\"\n",
+            ),
+        ] {
+            let dir = root.join("crates").join(member);
+            fs::create_dir_all(dir.join("src")).unwrap();
+            fs::write(
+                dir.join("Cargo.toml"),
+                format!(
+                    "[package]\nname = \"{member}\"\nversion = \"0.1.0\"\nedition = \"2021\"\n{extra}"
+                ),
+            )
+            .unwrap();
+            fs::write(
+                dir.join("src/lib.rs"),
+                "// TODO: finish\npub struct Handle(*const u8);\nunsafe impl Send for Handle {}\n",
+            )
+            .unwrap();
+        }
+
+        let mut config = Config::default();
+        config.general.offline = Some(true);
+        config.general.cache = Some(false);
+        config.general.vulnerability_scan = Some(false);
+        config.general.extra_source_dirs = Some(vec!["crates".to_string()]);
+        let layers = |file: &Config, overlay: Config| ConfigLayers {
+            file: file.clone(),
+            overlay,
+        };
+        let members = MemberConfigs::load(&root, &layers(&config, Config::default()));
+        let findings = collect_findings(&root, &config, &members).unwrap();
+
+        // The prototype disabled CODE004 for itself only
+        let in_member = |findings: &[Finding], member: &str| -> Vec<Finding> {
+            let dir = root.join("crates").join(member);
+            findings
+                .iter()
+                .filter(|f| {
+                    f.file_path
+                        .as_deref()
+                        .is_some_and(|p| Path::new(p).starts_with(&dir))
+                })
+                .cloned()
+                .collect()
+        };
+        let prototype = in_member(&findings, "prototype");
+        let published = in_member(&findings, "published");
+        assert!(!prototype.iter().any(|f| f.code == "CODE004"));
+        assert!(published.iter().any(|f| f.code == "CODE004"));
+
+        // Each member is gated on its own fail_on: the prototype's CODE038 warning would
+        // fail the root's default gate but not its own, while the published crate fails on notes
+        assert!(prototype.iter().any(|f| f.code == "CODE038"));
+        assert!(fails_gate(&prototype, &config.fail_on()));
+        assert!(!members.fails_gate(&prototype, &config));
+        let published_notes: Vec<Finding> = published
+            .into_iter()
+            .filter(|f| f.severity == Severity::Note)
+            .collect();
+        assert!(!fails_gate(&published_notes, &config.fail_on()));
+        assert!(members.fails_gate(&published_notes, &config));
+
+        // An explicit --fail-on still beats the member metadata
+        let mut cli = Config::default();
+        cli.general.fail_on = Some(Severity::Warning);
+        let cli_members = MemberConfigs::load(&root, &layers(&config, cli));
+        assert!(cli_members.fails_gate(&prototype, &config));
+        assert!(!cli_members.fails_gate(&published_notes, &config));
+
+        // Findings reported at a deadline go through the member configurations too
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(GET).path("/serde");
+            then.status(200)
+                .delay(Duration::from_secs(10))
+                .header("content-type", "application/json")
+                .json_body(serde_json::json!({
+                    "crate": { "max_version": "999.0.0" },
+                    "versions": [{ "num": "999.0.0", "yanked": false }]
+                }));
+        });
+        config.general.offline = Some(false);
+        config.general.registry_url = Some(server.base_url());
+        config.general.deadline = Some(1);
+        let findings = collect_findings(&root, &config, &members).unwrap();
+        assert!(findings.iter().any(|f| f.code == "TIMEOUT001"));
+        assert!(
+            !in_member(&findings, "prototype")
+                .iter()
+                .any(|f| f.code == "CODE004")
+        );
+        assert!(
+            in_member(&findings, "published")
+                .iter()
+                .any(|f| f.code == "CODE004")
+        );
+    }
+
+    #[test]
+    fn test_vulnerability_scan_can_be_disabled() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[package]\nname = \"demo\"\nversion = \"0.1.0\"\nedition = \"2021\"\n",
+        )
+        .unwrap();
+        fs::create_dir(root.join("src")).unwrap();
+        fs::write(root.join("src/main.rs"), "fn main() {}\n").unwrap();
+
+        let mut config = Config::default();
+        config.general.offline = Some(true);
+        config.general.cache = Some(false);
+        config.general.vulnerability_scan = Some(false);
+        let findings = collect_findings(root, &config, &MemberConfigs::default()).unwrap();
+
+        assert!(
+            !findings
+                .iter()
+                .any(|f| f.code.starts_with("AUD") || f.code == "SEC001"),
+            "unexpected audit findings: {findings:?}"
+        );
+    }
+
+    #[test]
+    fn test_severity_threshold_filters_findings() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[package]\nname = \"demo\"\nversion = \"0.1.0\"\nedition = \"2021\"\n",
+        )
+        .unwrap();
+        fs::create_dir(root.join("src")).unwrap();
+        fs::write(root.join("src/main.rs"), "fn main() {}\n").unwrap();
+
+        let mut config = Config::default();
+        config.general.offline = Some(true);
+        config.general.severity_threshold = Some(Severity::Warning);
+
+        let findings = collect_findings(root, &config, &MemberConfigs::default()).unwrap();
+
+        assert!(!findings.is_empty());
+        assert!(findings.iter().all(|f| f.severity != Severity::Note));
+    }
+
+    #[test]
+    fn test_ignore_path_skips_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[package]\nname = \"demo\"\nversion = \"0.1.0\"\nedition = \"2021\"\n",
+        )
+        .unwrap();
+        fs::create_dir_all(root.join("src/generated")).unwrap();
+        fs::write(root.join("src/lib.rs"), "pub mod generated;\n").unwrap();
+        fs::write(
+            root.join("src/generated/mod.rs"),
+            "pub fn f() { g().unwrap(); }\n",
+        )
+        .unwrap();
+
+        let mut config = Config::default();
+        config.general.offline = Some(true);
+        config.general.cache = Some(false);
+        let findings = collect_findings(root, &config, &MemberConfigs::default()).unwrap();
+        assert!(findings.iter().any(|f| f.code == "CODE001"));
+
+        config.general.ignore_paths = Some(vec!["src/generated".to_string()]);
+        let findings = collect_findings(root, &config, &MemberConfigs::default()).unwrap();
+        assert!(!findings.iter().any(|f| {
+            f.file_path
+                .as_deref()
+                .is_some_and(|p| p.contains("generated"))
+        }));
+    }
+}
@@ -5,18 +5,24 @@
 //!
 //! # Features
 //!
-//! - Defines `Package`, `Dependency`, and `CargoManifest` structs for deserializing Cargo.toml.
+//! - Defines `Package`, `Dependency`, `BinTarget`, `Workspace`, and `CargoManifest` structs for deserializing Cargo.toml.
 //! - Supports both simple and detailed dependency specifications.
 //! - Provides `CargoManifest::parse` for loading and parsing a manifest from disk.
 //! - Implements checks for missing or incomplete package metadata (description, license, repository, readme, etc.).
 //! - Checks for wildcard dependency versions and outdated or missing Rust edition fields.
+//! - Optionally suggests committing `Cargo.lock` for binaries with caret-range dependencies.
+//! - Flags deprecated `[replace]` sections and local-path `[patch]` overrides.
+//! - Validates `[features]` entries against the declared dependencies.
+//! - Resolves the default feature set to tell which `[[bin]]` targets need extra features.
+//! - Optionally checks that dependency tables are sorted (reads the raw document with `toml_edit`).
+//! - Attaches the line of the offending `[package]` key (or the `[package]` header) to metadata findings.
 //! - Includes comprehensive unit tests for manifest parsing and metadata validation.
 //!
 //! This module is intended for use in tools that lint, audit, or analyze Rust project manifests.
 //!
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::Path;
 
@@ -25,16 +31,154 @@ use crate::{
     diagnostics::{Finding, Severity},
 };
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
+/// Category slugs accepted by crates.io (see <https://crates.io/category_slugs>).
+pub const CRATES_IO_CATEGORIES: &[&str] = &[
+    "accessibility",
+    "aerospace",
+    "aerospace::drones",
+    "aerospace::protocols",
+    "aerospace::simulation",
+    "aerospace::space-protocols",
+    "aerospace::unmanned-aerial-vehicles",
+    "algorithms",
+    "api-bindings",
+    "asynchronous",
+    "authentication",
+    "caching",
+    "command-line-interface",
+    "command-line-utilities",
+    "compilers",
+    "compression",
+    "computer-vision",
+    "concurrency",
+    "config",
+    "cryptography",
+    "cryptography::cryptocurrencies",
+    "data-structures",
+    "database",
+    "database-implementations",
+    "date-and-time",
+    "development-tools",
+    "development-tools::build-utils",
+    "development-tools::cargo-plugins",
+    "development-tools::debugging",
+    "development-tools::ffi",
+    "development-tools::procedural-macro-helpers",
+    "development-tools::profiling",
+    "development-tools::testing",
+    "email",
+    "embedded",
+    "emulators",
+    "encoding",
+    "external-ffi-bindings",
+    "filesystem",
+    "finance",
+    "game-development",
+    "game-engines",
+    "games",
+    "graphics",
+    "gui",
+    "hardware-support",
+    "internationalization",
+    "localization",
+    "mathematics",
+    "memory-management",
+    "multimedia",
+    "multimedia::audio",
+    "multimedia::encoding",
+    "multimedia::images",
+    "multimedia::video",
+    "network-programming",
+    "no-std",
+    "no-std::no-alloc",
+    "os",
+    "os::android-apis",
+    "os::freebsd-apis",
+    "os::linux-apis",
+    "os::macos-apis",
+    "os::unix-apis",
+    "os::windows-apis",
+    "parser-implementations",
+    "parsing",
+    "rendering",
+    "rendering::data-formats",
+    "rendering::engine",
+    "rendering::graphics-api",
+    "rust-patterns",
+    "science",
+    "science::bioinformatics",
+    "science::bioinformatics::genomics",
+    "science::bioinformatics::proteomics",
+    "science::bioinformatics::sequence-analysis",
+    "science::geo",
+    "science::neuroscience",
+    "science::robotics",
+    "simulation",
+    "template-engine",
+    "text-editors",
+    "text-processing",
+    "value-formatting",
+    "virtualization",
+    "visualization",
+    "wasm",
+    "web-programming",
+    "web-programming::http-client",
+    "web-programming::http-server",
+    "web-programming::websocket",
+];
+
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
 pub struct Package {
     pub name: String,
     pub version: String,
     pub edition: Option<String>,
     pub description: Option<String>,
     pub license: Option<String>,
+    /// Path to a non-standard license file, used instead of an SPDX `license`.
+    #[serde(rename = "license-file", alias = "license_file")]
+    pub license_file: Option<String>,
     // pub readme: Option<String>,
     pub readme: Option<toml::Value>,
     pub repository: Option<String>,
+    /// crates.io category slugs, e.g. `"command-line-utilities"`, or inherited from the workspace.
+    pub categories: Option<Inheritable<Vec<String>>>,
+    /// Minimum supported Rust version (MSRV), e.g. `"1.70"`, or inherited from the workspace.
+    #[serde(rename = "rust-version", alias = "rust_version")]
+    pub rust_version: Option<Inheritable<String>>,
+    /// `false`, or the registries the crate may be published to. Absent means publishable.
+    pub publish: Option<toml::Value>,
+}
+
+impl Package {
+    /// Whether cargo would publish the crate: `publish` is absent, `true` or a non-empty
+    /// list of registries.
+    pub fn is_published(&self) -> bool {
+        match &self.publish {
+            Some(toml::Value::Boolean(publish)) => *publish,
+            Some(toml::Value::Array(registries)) => !registries.is_empty(),
+            _ => true,
+        }
+    }
+}
+
+/// A `[package]` field that is either set in place or inherited from `[workspace.package]`
+/// with `field.workspace = true`. An inherited field counts as declared; its value lives
+/// in the workspace root manifest and is not resolved here.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum Inheritable<T> {
+    Value(T),
+    Workspace { workspace: bool },
+}
+
+impl<T> Inheritable<T> {
+    /// The value set in this manifest, or `None` when it is inherited.
+    pub fn value(&self) -> Option<&T> {
+        match self {
+            Inheritable::Value(value) => Some(value),
+            Inheritable::Workspace { .. } => None,
+        }
+    }
 }
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -49,9 +193,50 @@ pub struct DetailedDependency {
     pub version: Option<String>,
     pub path: Option<String>,
     pub features: Option<Vec<String>>,
+    #[serde(rename = "default-features", alias = "default_features")]
+    pub default_features: Option<bool>,
+    pub optional: Option<bool>,
 }
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
+/// A `[[bin]]` target declared in Cargo.toml.
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct BinTarget {
+    pub name: Option<String>,
+    pub path: Option<String>,
+    /// Features that must be enabled for cargo to build this target.
+    #[serde(default, rename = "required-features", alias = "required_features")]
+    pub required_features: Vec<String>,
+}
+
+/// The `[lib]` target declared in Cargo.toml.
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct LibTarget {
+    pub name: Option<String>,
+    pub path: Option<String>,
+}
+
+/// The `[workspace]` section of a workspace root manifest.
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct Workspace {
+    /// Member paths, relative to the workspace root. Entries may be globs such as `crates/*`.
+    #[serde(default)]
+    pub members: Vec<String>,
+    /// Paths that are not members even if they match `members`.
+    #[serde(default)]
+    pub exclude: Vec<String>,
+}
+
+/// A `[target.<cfg>]` table: dependencies that only apply on matching platforms.
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct PlatformTarget {
+    pub dependencies: Option<HashMap<String, Dependency>>,
+    #[serde(rename = "dev-dependencies")]
+    pub dev_dependencies: Option<HashMap<String, Dependency>>,
+    #[serde(rename = "build-dependencies")]
+    pub build_dependencies: Option<HashMap<String, Dependency>>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
 pub struct CargoManifest {
     pub package: Option<Package>, // Package section is optional (e.g. in a workspace virtual manifest)
     pub dependencies: Option<HashMap<String, Dependency>>,
@@ -59,7 +244,20 @@ pub struct CargoManifest {
     pub dev_dependencies: Option<HashMap<String, Dependency>>,
     #[serde(rename = "build-dependencies")]
     pub build_dependencies: Option<HashMap<String, Dependency>>,
-    // You can add `workspace`, `lib`, `bin` sections here if needed later
+    pub lib: Option<LibTarget>,
+    pub bin: Option<Vec<BinTarget>>,
+    /// `[target.<cfg>]` tables, keyed by platform, e.g. `cfg(unix)` or a target triple.
+    pub target: Option<HashMap<String, PlatformTarget>>,
+    /// `[patch.<registry>]` tables, keyed by registry (e.g. `crates-io`), then crate name.
+    pub patch: Option<HashMap<String, HashMap<String, Dependency>>>,
+    /// Deprecated `[replace]` table, keyed by package id spec (e.g. `"foo:0.1.0"`).
+    pub replace: Option<HashMap<String, Dependency>>,
+    /// `[features]` table: feature name to the features and dependencies it enables.
+    pub features: Option<HashMap<String, Vec<String>>>,
+    pub workspace: Option<Workspace>,
+    /// Raw text the manifest was parsed from, used to point findings at lines.
+    #[serde(skip)]
+    pub source: Option<String>,
 }
 
 impl CargoManifest {
@@ -67,71 +265,341 @@ impl CargoManifest {
         let content = fs::read_to_string(path_to_cargo_toml)
             .map_err(|e| format!("Failed to read Cargo.toml at {path_to_cargo_toml:?}: {e}",))?;
 
-        toml::from_str(&content)
-            .map_err(|e| format!("Failed to parse Cargo.toml at {path_to_cargo_toml:?}: {e:?}"))
+        let mut manifest: CargoManifest = toml::from_str(&content)
+            .map_err(|e| format!("Failed to parse Cargo.toml at {path_to_cargo_toml:?}: {e:?}"))?;
+        manifest.source = Some(content);
+        Ok(manifest)
+    }
+
+    /// 1-based line of `key` in the `[package]` table, or of the `[package]` header when
+    /// the key is absent. `None` if the manifest was not read from text or has no `[package]`.
+    pub fn package_line(&self, key: &str) -> Option<usize> {
+        self.line_of(&["package", key])
+    }
+
+    /// 1-based line of the key at `path`, e.g. `["dependencies", "serde"]`; see [`toml_line`].
+    /// `None` if the manifest was not read from text.
+    pub fn line_of(&self, path: &[&str]) -> Option<usize> {
+        toml_line(self.source.as_deref()?, path)
+    }
+
+    /// `finding` pointed at the key at `path` (see [`CargoManifest::line_of`]), or left
+    /// without a line when it cannot be located.
+    pub fn locate(&self, finding: Finding, path: &[&str]) -> Finding {
+        match self.line_of(path) {
+            Some(line) => finding.with_line(line),
+            None => finding,
+        }
+    }
+
+    /// The parsed dependency table named `table` (`dependencies`, `dev-dependencies` or
+    /// `build-dependencies`).
+    pub fn dependency_table(&self, table: &str) -> Option<&HashMap<String, Dependency>> {
+        match table {
+            "dependencies" => self.dependencies.as_ref(),
+            "dev-dependencies" => self.dev_dependencies.as_ref(),
+            "build-dependencies" => self.build_dependencies.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Every dependency table with its key path, e.g. `["dev-dependencies"]` or
+    /// `["target", "cfg(unix)", "dependencies"]`. Platform-specific tables come last,
+    /// sorted by platform.
+    pub fn dependency_tables(&self) -> Vec<(Vec<&str>, &HashMap<String, Dependency>)> {
+        let mut tables: Vec<(Vec<&str>, &HashMap<String, Dependency>)> =
+            ["dependencies", "dev-dependencies", "build-dependencies"]
+                .into_iter()
+                .filter_map(|table| Some((vec![table], self.dependency_table(table)?)))
+                .collect();
+        let mut platforms: Vec<(&String, &PlatformTarget)> = self.target.iter().flatten().collect();
+        platforms.sort_unstable_by_key(|(platform, _)| *platform);
+        for (platform, target) in platforms {
+            for (table, deps) in [
+                ("dependencies", &target.dependencies),
+                ("dev-dependencies", &target.dev_dependencies),
+                ("build-dependencies", &target.build_dependencies),
+            ] {
+                if let Some(deps) = deps {
+                    tables.push((vec!["target", platform.as_str(), table], deps));
+                }
+            }
+        }
+        tables
+    }
+
+    /// Names of the crates overridden in `[patch.crates-io]`, sorted.
+    pub fn crates_io_patches(&self) -> Vec<String> {
+        let mut names: Vec<String> = self
+            .patch
+            .as_ref()
+            .and_then(|patch| patch.get("crates-io"))
+            .map(|crates| crates.keys().cloned().collect())
+            .unwrap_or_default();
+        names.sort_unstable();
+        names
+    }
+
+    /// Features enabled by `default`, following references to other features.
+    pub fn default_features(&self) -> HashSet<&str> {
+        let mut enabled = HashSet::new();
+        let Some(features) = &self.features else {
+            return enabled;
+        };
+        let mut pending = vec!["default"];
+        while let Some(feature) = pending.pop() {
+            for value in features.get(feature).into_iter().flatten() {
+                // `dep:x` and `x/feature` entries are never keys of [features]
+                if features.contains_key(value) && enabled.insert(value.as_str()) {
+                    pending.push(value.as_str());
+                }
+            }
+        }
+        enabled
+    }
+
+    /// `required-features` of `bin` that the default feature set leaves off, in declaration
+    /// order. Empty when a plain `cargo build` builds the target.
+    pub fn features_missing_by_default<'a>(&'a self, bin: &'a BinTarget) -> Vec<&'a str> {
+        let defaults = self.default_features();
+        bin.required_features
+            .iter()
+            .map(String::as_str)
+            .filter(|feature| !defaults.contains(feature))
+            .collect()
+    }
+}
+
+/// 1-based line of the key at `path` in the TOML `source`. When the full path does not
+/// exist, the deepest key on it that does is used instead, so a missing key points at its
+/// table header. `None` if not even the first key exists.
+pub fn toml_line(source: &str, path: &[&str]) -> Option<usize> {
+    let document = toml_edit::ImDocument::parse(source).ok()?;
+    let mut table: &dyn toml_edit::TableLike = document.as_table();
+    let mut start = None;
+    for key in path {
+        let Some((key, item)) = table.get_key_value(key) else {
+            break;
+        };
+        start = key.span().map(|span| span.start).or(start);
+        match item.as_table_like() {
+            Some(inner) => table = inner,
+            None => break,
+        }
+    }
+    Some(source[..start?].matches('\n').count() + 1)
+}
+
+/// Manifest table holding the dependencies of `dep_type` ("runtime", "dev" or "build").
+fn dependency_table(dep_type: &str) -> &'static str {
+    match dep_type {
+        "dev" => "dev-dependencies",
+        "build" => "build-dependencies",
+        _ => "dependencies",
+    }
+}
+
+/// Longest package name crates.io accepts.
+pub const CRATES_IO_MAX_NAME_LENGTH: usize = 64;
+
+/// Lists the ways `name` breaks the crates.io naming rules: ASCII alphanumerics, `-` and
+/// `_` only, starting with a letter, at most [`CRATES_IO_MAX_NAME_LENGTH`] characters.
+///
+/// crates.io treats `-` and `_` as the same character, so `my-crate` and `my_crate` name
+/// the same crate; the library target of `my-crate` is `my_crate`.
+pub fn crate_name_problems(name: &str) -> Vec<String> {
+    let mut problems = Vec::new();
+    if name.is_empty() {
+        problems.push("name is empty".to_string());
+        return problems;
+    }
+    if !name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        problems.push("must start with an ASCII letter".to_string());
     }
+    let mut invalid: Vec<char> = name
+        .chars()
+        .filter(|c| !c.is_ascii_alphanumeric() && *c != '-' && *c != '_')
+        .collect();
+    invalid.dedup();
+    if !invalid.is_empty() {
+        problems.push(format!(
+            "contains invalid characters {} (only ASCII letters, digits, '-' and '_' are allowed)",
+            invalid
+                .iter()
+                .map(|c| format!("{c:?}"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+    }
+    let length = name.chars().count();
+    if length > CRATES_IO_MAX_NAME_LENGTH {
+        problems.push(format!(
+            "is {length} characters long (max {CRATES_IO_MAX_NAME_LENGTH})"
+        ));
+    }
+    problems
 }
 
 pub fn check_missing_metadata(manifest: &CargoManifest, config: &Config) -> Vec<Finding> {
     let mut findings = Vec::new();
     if let Some(package) = &manifest.package {
+        // Points a finding at `key` in [package], or at the [package] header if it is missing
+        let located = |finding: Finding, key: &str| manifest.locate(finding, &["package", key]);
+
         if config.is_check_enabled("MD001")
             && (package.description.is_none() || package.description.as_deref() == Some(""))
         {
-            findings.push(Finding::new(
-                "MD001",
-                "Missing 'description' in [package] section of Cargo.toml.".to_string(),
-                Severity::Warning,
-                Some("Cargo.toml".to_string()),
+            findings.push(located(
+                Finding::new(
+                    "MD001",
+                    "Missing 'description' in [package] section of Cargo.toml.".to_string(),
+                    Severity::Warning,
+                    Some("Cargo.toml".to_string()),
+                ),
+                "description",
             ));
         }
 
-        if config.is_check_enabled("MD002")
-            && (package.license.is_none() || package.license.as_deref() == Some(""))
-        {
-            findings.push(Finding::new(
-                "MD002",
-                "Missing 'license' (or 'license-file') in [package] section of Cargo.toml."
+        let has_license = package.license.as_deref().is_some_and(|l| !l.is_empty());
+        let has_license_file = package
+            .license_file
+            .as_deref()
+            .is_some_and(|lf| !lf.is_empty());
+
+        if config.is_check_enabled("MD002") && !has_license && !has_license_file {
+            findings.push(located(
+                Finding::new(
+                    "MD002",
+                    "Missing 'license' (or 'license-file') in [package] section of Cargo.toml."
+                        .to_string(),
+                    Severity::Warning,
+                    Some("Cargo.toml".to_string()),
+                ),
+                "license",
+            ));
+        }
+
+        if config.is_check_enabled("MD016") && has_license && has_license_file {
+            findings.push(located(Finding::new(
+                "MD016",
+                "Both 'license' and 'license-file' are set in Cargo.toml; crates.io expects only one."
                     .to_string(),
-                Severity::Warning,
+                Severity::Note,
                 Some("Cargo.toml".to_string()),
-            ));
+            ), "license-file"));
         }
 
         if config.is_check_enabled("MD003")
             && (package.repository.is_none() || package.repository.as_deref() == Some(""))
         {
-            findings.push(Finding::new(
-                "MD003",
-                "Missing 'repository' in [package] section of Cargo.toml.".to_string(),
-                Severity::Note, // Less critical than license/description for local projects
-                Some("Cargo.toml".to_string()),
+            findings.push(located(
+                Finding::new(
+                    "MD003",
+                    "Missing 'repository' in [package] section of Cargo.toml.".to_string(),
+                    Severity::Note, // Less critical than license/description for local projects
+                    Some("Cargo.toml".to_string()),
+                ),
+                "repository",
             ));
         }
 
         if config.is_check_enabled("MD004") {
             match &package.readme {
                 None => {
-                    findings.push(Finding::new(
+                    findings.push(located(Finding::new(
                         "MD004",
                         "Missing 'readme' field in [package] section of Cargo.toml. Consider adding `readme = \"README.md\"` or `readme = false`.".to_string(),
                         Severity::Note,
                         Some("Cargo.toml".to_string()),
-                    ));
+                    ), "readme"));
                 }
                 Some(readme_value) => {
                     if readme_value.as_str().is_some() || readme_value.as_bool() == Some(false) {
                     } else {
-                        findings.push(Finding::new(
+                        findings.push(located(Finding::new(
                             "MD004",
                             format!("The 'readme' field in Cargo.toml has an unexpected value ( '{readme_value}' ). Expected a file path string (e.g., \"README.md\") or `false`."),
                             Severity::Warning, // This is more than a note, it's likely a misconfiguration.
                             Some("Cargo.toml".to_string()),
-                        ));
+                        ), "readme"));
                     }
                 }
             }
         }
+
+        if config.is_check_enabled("MD019") {
+            // Inherited categories are validated with the workspace root manifest
+            let invalid: Vec<&str> = package
+                .categories
+                .as_ref()
+                .and_then(Inheritable::value)
+                .into_iter()
+                .flatten()
+                .map(String::as_str)
+                .filter(|category| !CRATES_IO_CATEGORIES.contains(category))
+                .collect();
+            if !invalid.is_empty() {
+                findings.push(located(Finding::new(
+                    "MD019",
+                    format!(
+                        "Unknown crates.io categories in Cargo.toml: {}. crates.io ignores them; see https://crates.io/category_slugs for valid slugs.",
+                        invalid
+                            .iter()
+                            .map(|category| format!("'{category}'"))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ),
+                    Severity::Warning,
+                    Some("Cargo.toml".to_string()),
+                ), "categories"));
+            }
+        }
+
+        if config.is_check_enabled("MD020") {
+            let problems = crate_name_problems(&package.name);
+            if !problems.is_empty() {
+                findings.push(located(
+                    Finding::new(
+                        "MD020",
+                        format!(
+                            "Package name '{}' cannot be published to crates.io: {}.",
+                            package.name,
+                            problems.join("; ")
+                        ),
+                        Severity::Warning,
+                        Some("Cargo.toml".to_string()),
+                    ),
+                    "name",
+                ));
+            }
+        }
+
+        match cargo_metadata::semver::Version::parse(&package.version) {
+            Err(e) if config.is_check_enabled("MD017") => {
+                findings.push(located(Finding::new(
+                    "MD017",
+                    format!(
+                        "Package version '{}' is not valid semver ({e}). Use MAJOR.MINOR.PATCH, e.g. \"1.0.0\".",
+                        package.version
+                    ),
+                    Severity::Error,
+                    Some("Cargo.toml".to_string()),
+                ), "version"));
+            }
+            Ok(version) if version.major == 0 && config.is_check_enabled("MD018") => {
+                findings.push(located(Finding::new(
+                    "MD018",
+                    format!(
+                        "Package version '{version}' is pre-1.0. Under semver's 0.x rules a minor bump (0.{}.0 -> 0.{}.0) is a breaking change.",
+                        version.minor,
+                        version.minor + 1
+                    ),
+                    Severity::Note,
+                    Some("Cargo.toml".to_string()),
+                ), "version"));
+            }
+            _ => {}
+        }
         // Add more checks: authors, keywords, categories if desired
     } else {
         findings.push(Finding::new(
@@ -155,13 +623,16 @@ pub fn check_dependency_versions(manifest: &CargoManifest, config: &Config) -> V
                 };
 
                 if version_str == Some("*") {
-                    findings.push(Finding::new(
-                        "DP001",
-                        format!(
-                            "Wildcard version \"*\" used for {dep_type} dependency '{name}'. Specify a version range."
+                    findings.push(manifest.locate(
+                        Finding::new(
+                            "DP001",
+                            format!(
+                                "Wildcard version \"*\" used for {dep_type} dependency '{name}'. Specify a version range."
+                            ),
+                            Severity::Warning,
+                            Some("Cargo.toml".to_string()),
                         ),
-                        Severity::Warning,
-                        Some("Cargo.toml".to_string()),
+                        &[dependency_table(dep_type), name],
                     ));
                 }
                 // Could add more checks: overly broad versions like ">0.1", etc.
@@ -178,6 +649,298 @@ pub fn check_dependency_versions(manifest: &CargoManifest, config: &Config) -> V
     findings
 }
 
+/// Flags dependencies that turn off default features without enabling any replacement,
+/// which often leaves the dependency without the functionality the crate relies on.
+pub fn check_default_features(manifest: &CargoManifest, config: &Config) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    if !config.is_check_enabled("DP014") {
+        return findings;
+    }
+
+    let sections = [
+        (&manifest.dependencies, "runtime"),
+        (&manifest.dev_dependencies, "dev"),
+        (&manifest.build_dependencies, "build"),
+    ];
+    for (deps, dep_type) in sections {
+        let Some(dependencies) = deps else { continue };
+        let mut names: Vec<&String> = dependencies
+            .iter()
+            .filter_map(|(name, dep)| match dep {
+                Dependency::Detailed(d)
+                    if d.default_features == Some(false)
+                        && d.features.as_ref().is_none_or(|f| f.is_empty()) =>
+                {
+                    Some(name)
+                }
+                _ => None,
+            })
+            .collect();
+        names.sort_unstable();
+        for name in names {
+            findings.push(manifest.locate(
+                Finding::new(
+                    "DP014",
+                    format!(
+                        "{dep_type} dependency '{name}' sets default-features = false without enabling any features. Confirm the minimal feature set is intended."
+                    ),
+                    Severity::Note,
+                    Some("Cargo.toml".to_string()),
+                ),
+                &[dependency_table(dep_type), name],
+            ));
+        }
+    }
+
+    findings
+}
+
+/// Dependency named by a feature value: `dep:<name>`, `<name>/<feat>` or `<name>?/<feat>`.
+/// Plain values name another feature (or an implicit optional-dependency feature) and yield `None`.
+fn feature_value_dependency(value: &str) -> Option<&str> {
+    if let Some(name) = value.strip_prefix("dep:") {
+        return Some(name);
+    }
+    value
+        .split_once('/')
+        .map(|(name, _)| name.trim_end_matches('?'))
+}
+
+pub fn check_features(manifest: &CargoManifest, config: &Config) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    let Some(features) = &manifest.features else {
+        return findings;
+    };
+
+    let dependency_tables = manifest.dependency_tables();
+    let all_deps: Vec<(&String, &Dependency)> = dependency_tables
+        .iter()
+        .flat_map(|(_, deps)| deps.iter())
+        .collect();
+
+    let mut feature_names: Vec<&String> = features.keys().collect();
+    feature_names.sort_unstable();
+
+    if config.is_check_enabled("FT001") {
+        for feature in &feature_names {
+            for value in &features[*feature] {
+                if let Some(dep_name) = feature_value_dependency(value)
+                    && !all_deps.iter().any(|(name, _)| name.as_str() == dep_name)
+                {
+                    findings.push(manifest.locate(
+                        Finding::new(
+                            "FT001",
+                            format!(
+                                "Feature '{feature}' enables \"{value}\", but '{dep_name}' is not a declared dependency."
+                            ),
+                            Severity::Warning,
+                            Some("Cargo.toml".to_string()),
+                        ),
+                        &["features", feature],
+                    ));
+                }
+            }
+        }
+    }
+
+    if config.is_check_enabled("FT002") {
+        let mut optional_deps: Vec<&String> = all_deps
+            .iter()
+            .filter(|(_, dep)| matches!(dep, Dependency::Detailed(d) if d.optional == Some(true)))
+            .map(|(name, _)| *name)
+            .collect();
+        optional_deps.sort_unstable();
+        optional_deps.dedup();
+
+        for dep_name in optional_deps {
+            let referenced = features.values().flatten().any(|value| {
+                value == dep_name || feature_value_dependency(value) == Some(dep_name.as_str())
+            });
+            if !referenced {
+                let mut path = dependency_tables
+                    .iter()
+                    .find(|(_, deps)| deps.contains_key(dep_name))
+                    .map_or_else(|| vec!["dependencies"], |(path, _)| path.clone());
+                path.push(dep_name);
+                findings.push(manifest.locate(
+                    Finding::new(
+                        "FT002",
+                        format!(
+                            "Optional dependency '{dep_name}' is not referenced by any feature in [features]."
+                        ),
+                        Severity::Note,
+                        Some("Cargo.toml".to_string()),
+                    ),
+                    &path,
+                ));
+            }
+        }
+    }
+
+    findings
+}
+
+/// Opt-in MD015: reports the first out-of-order key in each dependency table.
+///
+/// Works on the raw manifest text, since [`CargoManifest`] does not preserve key order.
+pub fn check_sorted_dependencies(manifest_content: &str, config: &Config) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    if !config.is_check_enabled("MD015") {
+        return findings;
+    }
+    let Ok(document) = manifest_content.parse::<toml_edit::DocumentMut>() else {
+        return findings;
+    };
+
+    for table_name in ["dependencies", "dev-dependencies", "build-dependencies"] {
+        let Some(table) = document.get(table_name).and_then(|t| t.as_table_like()) else {
+            continue;
+        };
+        let keys: Vec<&str> = table.iter().map(|(key, _)| key).collect();
+        if let Some(pair) = keys
+            .windows(2)
+            .find(|pair| pair[0].to_lowercase() > pair[1].to_lowercase())
+        {
+            let finding = Finding::new(
+                "MD015",
+                format!(
+                    "[{table_name}] is not sorted alphabetically: '{}' comes after '{}'.",
+                    pair[1], pair[0]
+                ),
+                Severity::Note,
+                Some("Cargo.toml".to_string()),
+            );
+            findings.push(match toml_line(manifest_content, &[table_name, pair[1]]) {
+                Some(line) => finding.with_line(line),
+                None => finding,
+            });
+        }
+    }
+
+    findings
+}
+
+/// Flags pairs of `[dependencies]` that serve the same purpose (DP016, opt-in), using
+/// [`Config::redundant_dependency_pairs`].
+pub fn check_redundant_dependencies(manifest: &CargoManifest, config: &Config) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    if !config.is_check_enabled("DP016") {
+        return findings;
+    }
+    let Some(deps) = &manifest.dependencies else {
+        return findings;
+    };
+
+    for (a, b) in config.redundant_dependency_pairs() {
+        if deps.contains_key(a) && deps.contains_key(b) {
+            findings.push(manifest.locate(
+                Finding::new(
+                    "DP016",
+                    format!(
+                        "Both '{a}' and '{b}' are dependencies and serve the same purpose. Consider standardizing on one."
+                    ),
+                    Severity::Note,
+                    Some("Cargo.toml".to_string()),
+                ),
+                &["dependencies", b],
+            ));
+        }
+    }
+    findings
+}
+
+/// Flags test and benchmark crates listed in `[dependencies]` instead of
+/// `[dev-dependencies]` (DP019), using [`Config::dev_only_crates`]. Optional
+/// dependencies are skipped since they are usually behind a feature on purpose.
+pub fn check_dev_only_dependencies(manifest: &CargoManifest, config: &Config) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    if !config.is_check_enabled("DP019") {
+        return findings;
+    }
+    let Some(deps) = &manifest.dependencies else {
+        return findings;
+    };
+
+    let dev_only = config.dev_only_crates();
+    let mut misplaced: Vec<&str> = deps
+        .iter()
+        .filter(|(_, dep)| !matches!(dep, Dependency::Detailed(d) if d.optional == Some(true)))
+        .map(|(name, _)| name.as_str())
+        .filter(|name| dev_only.contains(name))
+        .collect();
+    misplaced.sort_unstable();
+    for name in misplaced {
+        findings.push(manifest.locate(
+            Finding::new(
+                "DP019",
+                format!(
+                    "'{name}' is normally only used by tests or benchmarks but is listed in [dependencies]. Move it to [dev-dependencies] so downstream crates do not build it."
+                ),
+                Severity::Note,
+                Some("Cargo.toml".to_string()),
+            ),
+            &["dependencies", name],
+        ));
+    }
+    findings
+}
+
+pub fn check_patch_and_replace(manifest: &CargoManifest, config: &Config) -> Vec<Finding> {
+    let mut findings = Vec::new();
+
+    if config.is_check_enabled("DP012")
+        && let Some(replace) = &manifest.replace
+        && !replace.is_empty()
+    {
+        let mut specs: Vec<&str> = replace.keys().map(String::as_str).collect();
+        specs.sort_unstable();
+        findings.push(manifest.locate(
+            Finding::new(
+                "DP012",
+                format!(
+                    "Deprecated [replace] section used for {}. Use [patch] instead.",
+                    specs.join(", ")
+                ),
+                Severity::Warning,
+                Some("Cargo.toml".to_string()),
+            ),
+            &["replace", specs[0]],
+        ));
+    }
+
+    if config.is_check_enabled("DP013")
+        && let Some(patch) = &manifest.patch
+    {
+        let mut path_patches: Vec<(&str, &str)> = patch
+            .iter()
+            .flat_map(|(registry, crates)| {
+                crates.iter().filter_map(move |(name, dep)| match dep {
+                    Dependency::Detailed(d) if d.path.is_some() => {
+                        Some((name.as_str(), registry.as_str()))
+                    }
+                    _ => None,
+                })
+            })
+            .collect();
+        path_patches.sort_unstable();
+        for (name, registry) in path_patches {
+            findings.push(manifest.locate(
+                Finding::new(
+                    "DP013",
+                    format!(
+                        "[patch] entry for {name} ({registry}) points at a local path. Make sure it is not meant to be published."
+                    ),
+                    Severity::Note,
+                    Some("Cargo.toml".to_string()),
+                ),
+                &["patch", registry, name],
+            ));
+        }
+    }
+
+    findings
+}
+
 pub fn check_rust_edition(manifest: &CargoManifest) -> Vec<Finding> {
     let mut findings = Vec::new();
     const LATEST_STABLE_EDITION: &str = "2024"; // Update this as new editions are released
@@ -185,24 +948,30 @@ pub fn check_rust_edition(manifest: &CargoManifest) -> Vec<Finding> {
     if let Some(package) = &manifest.package {
         match &package.edition {
             Some(edition) if edition != LATEST_STABLE_EDITION => {
-                findings.push(Finding::new(
-                    "ED001",
-                    format!(
-                        "Project uses Rust edition '{edition}', consider updating to '{LATEST_STABLE_EDITION}'."
+                findings.push(manifest.locate(
+                    Finding::new(
+                        "ED001",
+                        format!(
+                            "Project uses Rust edition '{edition}', consider updating to '{LATEST_STABLE_EDITION}'."
+                        ),
+                        Severity::Note,
+                        Some("Cargo.toml".to_string()),
                     ),
-                    Severity::Note,
-                    Some("Cargo.toml".to_string()),
+                    &["package", "edition"],
                 ));
             }
             None => {
                 // Editions before 2018 were implicit (2015)
-                findings.push(Finding::new(
-                    "ED002",
-                    format!(
-                        "Project does not specify a Rust edition (implicitly 2015), consider specifying and updating to '{LATEST_STABLE_EDITION}'."
+                findings.push(manifest.locate(
+                    Finding::new(
+                        "ED002",
+                        format!(
+                            "Project does not specify a Rust edition (implicitly 2015), consider specifying and updating to '{LATEST_STABLE_EDITION}'."
+                        ),
+                        Severity::Note,
+                        Some("Cargo.toml".to_string()),
                     ),
-                    Severity::Note,
-                    Some("Cargo.toml".to_string()),
+                    &["package", "edition"],
                 ));
             }
             _ => {} // Edition is latest or not applicable
@@ -211,6 +980,157 @@ pub fn check_rust_edition(manifest: &CargoManifest) -> Vec<Finding> {
     findings
 }
 
+/// Whether a version requirement is a caret range, i.e. a bare version (`"1.2.3"`,
+/// which Cargo reads as `^1.2.3`) or an explicit `^`.
+fn is_caret_requirement(version: &str) -> bool {
+    let version = version.trim();
+    version.starts_with('^') || version.starts_with(|c: char| c.is_ascii_digit())
+}
+
+/// Advisory DP015 (opt-in): a binary crate whose dependencies use caret ranges has no
+/// `Cargo.lock` in the project or any parent directory, so builds are not reproducible.
+pub fn check_binary_without_lockfile(
+    manifest: &CargoManifest,
+    project_root: &Path,
+    config: &Config,
+) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    if !config.is_check_enabled("DP015") {
+        return findings;
+    }
+
+    let is_binary = project_root.join("src").join("main.rs").is_file()
+        || project_root.join("src").join("bin").is_dir()
+        || manifest.bin.as_ref().is_some_and(|bins| !bins.is_empty());
+    let has_lockfile = project_root
+        .ancestors()
+        .any(|dir| dir.join("Cargo.lock").is_file());
+    if !is_binary || has_lockfile {
+        return findings;
+    }
+
+    let mut caret_deps: Vec<&str> = manifest
+        .dependencies
+        .iter()
+        .flatten()
+        .filter(|(_, dep)| {
+            let version = match dep {
+                Dependency::Version(v) => Some(v.as_str()),
+                Dependency::Detailed(d) => d.version.as_deref(),
+            };
+            version.is_some_and(is_caret_requirement)
+        })
+        .map(|(name, _)| name.as_str())
+        .collect();
+    if caret_deps.is_empty() {
+        return findings;
+    }
+    caret_deps.sort_unstable();
+
+    findings.push(manifest.locate(
+        Finding::new(
+            "DP015",
+            format!(
+                "Binary crate has no Cargo.lock, so caret requirements ({}) may resolve to newer versions on every fresh build. Commit Cargo.lock for reproducible builds, or use `=` to pin exact versions.",
+                caret_deps.join(", ")
+            ),
+            Severity::Note,
+            Some("Cargo.toml".to_string()),
+        ),
+        &["dependencies"],
+    ));
+    findings
+}
+
+/// Minimum rustc (major, minor) that supports each edition.
+const EDITION_MIN_RUST_VERSIONS: &[(&str, (u64, u64))] =
+    &[("2018", (1, 31)), ("2021", (1, 56)), ("2024", (1, 85))];
+
+/// Parses the `major.minor` part of a `rust-version` such as `1.70` or `1.70.0`.
+pub(crate) fn parse_rust_version(rust_version: &str) -> Option<(u64, u64)> {
+    let mut parts = rust_version.trim().split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next().map_or(Some(0), |minor| minor.parse().ok())?;
+    Some((major, minor))
+}
+
+/// Flags a `rust-version` older than the first rustc release supporting the
+/// declared `edition` (ED005), since the crate cannot build on its stated MSRV.
+pub fn check_edition_rust_version(manifest: &CargoManifest, config: &Config) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    if !config.is_check_enabled("ED005") {
+        return findings;
+    }
+
+    let Some(package) = &manifest.package else {
+        return findings;
+    };
+    // An inherited rust-version is checked against the edition in the workspace root
+    let (Some(edition), Some(rust_version)) = (
+        &package.edition,
+        package.rust_version.as_ref().and_then(Inheritable::value),
+    ) else {
+        return findings;
+    };
+    let Some(&(_, (min_major, min_minor))) = EDITION_MIN_RUST_VERSIONS
+        .iter()
+        .find(|(name, _)| name == edition)
+    else {
+        return findings;
+    };
+
+    if let Some(msrv) = parse_rust_version(rust_version)
+        && msrv < (min_major, min_minor)
+    {
+        findings.push(manifest.locate(
+            Finding::new(
+                "ED005",
+                format!(
+                    "Edition '{edition}' requires Rust {min_major}.{min_minor} or newer, but rust-version is '{rust_version}'."
+                ),
+                Severity::Warning,
+                Some("Cargo.toml".to_string()),
+            ),
+            &["package", "rust-version"],
+        ));
+    }
+    findings
+}
+
+/// Flags publishable library crates without a `rust-version` (ED009). Users of a library
+/// build it with their own toolchain, so a declared MSRV matters more than for binaries.
+/// A crate is a library if it has a `[lib]` section or a `src/lib.rs`.
+pub fn check_library_rust_version(
+    manifest: &CargoManifest,
+    project_root: &Path,
+    config: &Config,
+) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    if !config.is_check_enabled("ED009") {
+        return findings;
+    }
+
+    let Some(package) = &manifest.package else {
+        return findings;
+    };
+    let is_library = manifest.lib.is_some() || project_root.join("src").join("lib.rs").is_file();
+    if !is_library || !package.is_published() || package.rust_version.is_some() {
+        return findings;
+    }
+
+    let finding = Finding::new(
+        "ED009",
+        format!(
+            "Published library '{}' does not declare `rust-version`. Dependents cannot tell which toolchains it supports; set it to the oldest Rust release you test against.",
+            package.name
+        ),
+        Severity::Note,
+        Some("Cargo.toml".to_string()),
+    );
+    findings.push(manifest.locate(finding, &["package", "rust-version"]));
+    findings
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -239,7 +1159,10 @@ mod tests {
 
         Config {
             general: GeneralConfig::default(),
-            checks: ChecksConfig { enabled },
+            checks: ChecksConfig {
+                enabled,
+                ..Default::default()
+            },
         }
     }
 
@@ -252,7 +1175,10 @@ mod tests {
 
         Config {
             general: GeneralConfig::default(),
-            checks: ChecksConfig { enabled },
+            checks: ChecksConfig {
+                enabled,
+                ..Default::default()
+            },
         }
     }
 
@@ -272,7 +1198,10 @@ mod tests {
 
         Config {
             general: GeneralConfig::default(),
-            checks: ChecksConfig { enabled },
+            checks: ChecksConfig {
+                enabled,
+                ..Default::default()
+            },
         }
     }
 
@@ -314,63 +1243,336 @@ cc = "1.0"
             Some("https://github.com/user/repo".to_string())
         );
 
-        assert!(manifest.dependencies.is_some());
-        assert!(manifest.dev_dependencies.is_some());
-        assert!(manifest.build_dependencies.is_some());
+        assert!(manifest.dependencies.is_some());
+        assert!(manifest.dev_dependencies.is_some());
+        assert!(manifest.build_dependencies.is_some());
+    }
+
+    #[test]
+    fn test_parse_minimal_cargo_toml() {
+        let content = r#"
+[package]
+name = "minimal-package"
+version = "0.1.0"
+"#;
+
+        let (_temp_dir, path) = create_temp_cargo_toml(content);
+        let manifest = CargoManifest::parse(&path).unwrap();
+
+        assert!(manifest.package.is_some());
+        let package = manifest.package.unwrap();
+        assert_eq!(package.name, "minimal-package");
+        assert_eq!(package.version, "0.1.0");
+        assert_eq!(package.edition, None);
+        assert_eq!(package.description, None);
+        assert_eq!(package.license, None);
+        assert_eq!(package.repository, None);
+    }
+
+    #[test]
+    fn test_parse_workspace_cargo_toml() {
+        let content = r#"
+[workspace]
+members = ["crate1", "crate2"]
+
+[dependencies]
+shared-dep = "1.0"
+"#;
+
+        let (_temp_dir, path) = create_temp_cargo_toml(content);
+        let manifest = CargoManifest::parse(&path).unwrap();
+
+        assert!(manifest.package.is_none());
+        assert!(manifest.dependencies.is_some());
+    }
+
+    #[test]
+    fn test_parse_nonexistent_file() {
+        let result = CargoManifest::parse(Path::new("nonexistent/Cargo.toml"));
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("Failed to read Cargo.toml"));
+    }
+
+    #[test]
+    fn test_parse_invalid_toml() {
+        let content = "invalid toml content [[[";
+        let (_temp_dir, path) = create_temp_cargo_toml(content);
+        let result = CargoManifest::parse(&path);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("Failed to parse Cargo.toml"));
+    }
+
+    #[test]
+    fn test_parse_bin_targets() {
+        let content = r#"
+[package]
+name = "multi-bin"
+version = "0.1.0"
+
+[[bin]]
+name = "server"
+path = "src/bin/server_main.rs"
+
+[[bin]]
+name = "client"
+"#;
+
+        let (_temp_dir, path) = create_temp_cargo_toml(content);
+        let manifest = CargoManifest::parse(&path).unwrap();
+
+        let bins = manifest.bin.unwrap();
+        assert_eq!(bins.len(), 2);
+        assert_eq!(bins[0].name.as_deref(), Some("server"));
+        assert_eq!(bins[0].path.as_deref(), Some("src/bin/server_main.rs"));
+        assert_eq!(bins[1].name.as_deref(), Some("client"));
+        assert!(bins[1].path.is_none());
+    }
+
+    #[test]
+    fn test_bin_required_features_against_defaults() {
+        let content = r#"
+[package]
+name = "gated-bins"
+version = "0.1.0"
+
+[features]
+default = ["cli"]
+cli = ["color"]
+color = []
+server = ["dep:hyper"]
+
+[[bin]]
+name = "cli"
+required-features = ["color"]
+
+[[bin]]
+name = "server"
+required-features = ["server", "cli"]
+"#;
+
+        let (_temp_dir, path) = create_temp_cargo_toml(content);
+        let manifest = CargoManifest::parse(&path).unwrap();
+
+        let defaults = manifest.default_features();
+        assert!(defaults.contains("cli") && defaults.contains("color"));
+        assert!(!defaults.contains("server"));
+
+        let bins = manifest.bin.as_ref().unwrap();
+        assert!(manifest.features_missing_by_default(&bins[0]).is_empty());
+        assert_eq!(
+            manifest.features_missing_by_default(&bins[1]),
+            vec!["server"]
+        );
+    }
+
+    #[test]
+    fn test_default_features_disabled_without_features() {
+        let content = r#"
+[package]
+name = "minimal"
+version = "0.1.0"
+
+[dependencies]
+foo = { version = "1", default-features = false }
+bar = { version = "1", default-features = false, features = ["std"] }
+baz = { version = "1" }
+"#;
+
+        let (_temp_dir, path) = create_temp_cargo_toml(content);
+        let manifest = CargoManifest::parse(&path).unwrap();
+
+        let findings = check_default_features(&manifest, &Config::default());
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].code, "DP014");
+        assert_eq!(findings[0].severity, Severity::Note);
+        assert!(findings[0].message.contains("'foo'"));
+    }
+
+    #[test]
+    fn test_parse_features_section() {
+        let content = r#"
+[package]
+name = "featured"
+version = "0.1.0"
+
+[dependencies]
+serde = { version = "1", optional = true }
+log = "0.4"
+
+[features]
+default = ["std"]
+std = []
+derive = ["dep:serde", "log/std"]
+"#;
+
+        let (_temp_dir, path) = create_temp_cargo_toml(content);
+        let manifest = CargoManifest::parse(&path).unwrap();
+
+        let features = manifest.features.as_ref().unwrap();
+        assert_eq!(features.len(), 3);
+        assert_eq!(features["derive"], vec!["dep:serde", "log/std"]);
+        assert!(
+            matches!(&manifest.dependencies.as_ref().unwrap()["serde"], Dependency::Detailed(d) if d.optional == Some(true))
+        );
+
+        assert!(check_features(&manifest, &Config::default()).is_empty());
+    }
+
+    #[test]
+    fn test_check_features_unknown_and_unused_dependencies() {
+        let content = r#"
+[package]
+name = "featured"
+version = "0.1.0"
+
+[dependencies]
+serde = { version = "1", optional = true }
+tokio = { version = "1", optional = true }
+
+[features]
+net = ["dep:tokio", "dep:bar", "baz?/std"]
+"#;
+
+        let (_temp_dir, path) = create_temp_cargo_toml(content);
+        let manifest = CargoManifest::parse(&path).unwrap();
+
+        let findings = check_features(&manifest, &Config::default());
+        let unknown: Vec<_> = findings.iter().filter(|f| f.code == "FT001").collect();
+        assert_eq!(unknown.len(), 2);
+        assert!(unknown.iter().any(|f| f.message.contains("'bar'")));
+        assert!(unknown.iter().any(|f| f.message.contains("'baz'")));
+
+        let unused: Vec<_> = findings.iter().filter(|f| f.code == "FT002").collect();
+        assert_eq!(unused.len(), 1);
+        assert!(unused[0].message.contains("'serde'"));
+        assert_eq!(unused[0].severity, Severity::Note);
+    }
+
+    #[test]
+    fn test_check_features_sees_platform_specific_dependencies() {
+        let content = r#"
+[package]
+name = "featured"
+version = "0.1.0"
+
+[target.'cfg(unix)'.dependencies]
+libc = { version = "0.2", optional = true }
+
+[target.'cfg(windows)'.dependencies]
+winapi = { version = "0.3", optional = true }
+
+[features]
+unix = ["dep:libc"]
+"#;
+
+        let (_temp_dir, path) = create_temp_cargo_toml(content);
+        let manifest = CargoManifest::parse(&path).unwrap();
+
+        let findings = check_features(&manifest, &Config::default());
+        assert!(!findings.iter().any(|f| f.code == "FT001"));
+        let unused: Vec<_> = findings.iter().filter(|f| f.code == "FT002").collect();
+        assert_eq!(unused.len(), 1);
+        assert!(unused[0].message.contains("'winapi'"));
+        assert_eq!(unused[0].line_number, Some(10));
+    }
+
+    fn config_with_md015() -> Config {
+        let mut config = Config::default();
+        config.checks.enabled.insert("MD015".to_string(), true);
+        config
+    }
+
+    #[test]
+    fn test_sorted_dependencies_pass() {
+        let content = r#"
+[package]
+name = "sorted"
+version = "0.1.0"
+
+[dependencies]
+anyhow = "1"
+Regex = "1"
+serde = { version = "1", features = ["derive"] }
+
+[dev-dependencies]
+tempfile = "3"
+"#;
+
+        assert!(check_sorted_dependencies(content, &config_with_md015()).is_empty());
     }
 
     #[test]
-    fn test_parse_minimal_cargo_toml() {
+    fn test_unsorted_dependencies_reports_first_out_of_order_key() {
         let content = r#"
 [package]
-name = "minimal-package"
+name = "unsorted"
 version = "0.1.0"
+
+[dependencies]
+serde = "1"
+anyhow = "1"
+log = "0.4"
 "#;
 
-        let (_temp_dir, path) = create_temp_cargo_toml(content);
-        let manifest = CargoManifest::parse(&path).unwrap();
+        let findings = check_sorted_dependencies(content, &config_with_md015());
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].code, "MD015");
+        assert!(findings[0].message.contains("'anyhow' comes after 'serde'"));
 
-        assert!(manifest.package.is_some());
-        let package = manifest.package.unwrap();
-        assert_eq!(package.name, "minimal-package");
-        assert_eq!(package.version, "0.1.0");
-        assert_eq!(package.edition, None);
-        assert_eq!(package.description, None);
-        assert_eq!(package.license, None);
-        assert_eq!(package.repository, None);
+        // Opt-in: nothing is reported unless MD015 is enabled
+        assert!(check_sorted_dependencies(content, &Config::default()).is_empty());
     }
 
     #[test]
-    fn test_parse_workspace_cargo_toml() {
+    fn test_parse_patch_section() {
         let content = r#"
-[workspace]
-members = ["crate1", "crate2"]
+[package]
+name = "patched"
+version = "0.1.0"
 
-[dependencies]
-shared-dep = "1.0"
+[patch.crates-io]
+serde = { path = "../serde" }
+log = { git = "https://github.com/rust-lang/log" }
 "#;
 
         let (_temp_dir, path) = create_temp_cargo_toml(content);
         let manifest = CargoManifest::parse(&path).unwrap();
 
-        assert!(manifest.package.is_none());
-        assert!(manifest.dependencies.is_some());
-    }
+        let patch = manifest.patch.as_ref().unwrap();
+        let crates_io = &patch["crates-io"];
+        assert_eq!(crates_io.len(), 2);
+        assert!(
+            matches!(&crates_io["serde"], Dependency::Detailed(d) if d.path.as_deref() == Some("../serde"))
+        );
 
-    #[test]
-    fn test_parse_nonexistent_file() {
-        let result = CargoManifest::parse(Path::new("nonexistent/Cargo.toml"));
-        assert!(result.is_err());
-        assert!(result.unwrap_err().contains("Failed to read Cargo.toml"));
+        let findings = check_patch_and_replace(&manifest, &Config::default());
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].code, "DP013");
+        assert_eq!(findings[0].severity, Severity::Note);
+        assert!(findings[0].message.contains("serde (crates-io)"));
     }
 
     #[test]
-    fn test_parse_invalid_toml() {
-        let content = "invalid toml content [[[";
+    fn test_parse_replace_section() {
+        let content = r#"
+[package]
+name = "replaced"
+version = "0.1.0"
+
+[replace]
+"foo:0.1.0" = { path = "vendor/foo" }
+"#;
+
         let (_temp_dir, path) = create_temp_cargo_toml(content);
-        let result = CargoManifest::parse(&path);
-        assert!(result.is_err());
-        assert!(result.unwrap_err().contains("Failed to parse Cargo.toml"));
+        let manifest = CargoManifest::parse(&path).unwrap();
+
+        assert!(manifest.replace.as_ref().unwrap().contains_key("foo:0.1.0"));
+
+        let findings = check_patch_and_replace(&manifest, &Config::default());
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].code, "DP012");
+        assert_eq!(findings[0].severity, Severity::Warning);
+        assert!(findings[0].message.contains("foo:0.1.0"));
     }
 
     #[test]
@@ -424,10 +1626,12 @@ path_dep = { path = "../local" }
                 license: Some("MIT".to_string()),
                 readme: Some(toml::Value::String("README.md".to_string())),
                 repository: Some("https://github.com/user/repo".to_string()),
+                ..Default::default()
             }),
             dependencies: None,
             dev_dependencies: None,
             build_dependencies: None,
+            ..Default::default()
         };
 
         let config = mock_config_all_enabled();
@@ -446,10 +1650,12 @@ path_dep = { path = "../local" }
                 license: Some("MIT".to_string()),
                 readme: Some(toml::Value::String("README.md".to_string())),
                 repository: Some("https://github.com/user/repo".to_string()),
+                ..Default::default()
             }),
             dependencies: None,
             dev_dependencies: None,
             build_dependencies: None,
+            ..Default::default()
         };
 
         let config = mock_config_with_checks(&["MD001"]);
@@ -472,10 +1678,12 @@ path_dep = { path = "../local" }
                 license: Some("MIT".to_string()),
                 readme: Some(toml::Value::String("README.md".to_string())),
                 repository: Some("https://github.com/user/repo".to_string()),
+                ..Default::default()
             }),
             dependencies: None,
             dev_dependencies: None,
             build_dependencies: None,
+            ..Default::default()
         };
 
         let config = mock_config_with_checks(&["MD001"]);
@@ -496,10 +1704,12 @@ path_dep = { path = "../local" }
                 license: None,
                 readme: Some(toml::Value::String("README.md".to_string())),
                 repository: Some("https://github.com/user/repo".to_string()),
+                ..Default::default()
             }),
             dependencies: None,
             dev_dependencies: None,
             build_dependencies: None,
+            ..Default::default()
         };
 
         let config = mock_config_with_checks(&["MD002"]);
@@ -522,10 +1732,12 @@ path_dep = { path = "../local" }
                 license: Some("MIT".to_string()),
                 readme: Some(toml::Value::String("README.md".to_string())),
                 repository: None,
+                ..Default::default()
             }),
             dependencies: None,
             dev_dependencies: None,
             build_dependencies: None,
+            ..Default::default()
         };
 
         let config = mock_config_with_checks(&["MD003"]);
@@ -548,10 +1760,12 @@ path_dep = { path = "../local" }
                 license: Some("MIT".to_string()),
                 readme: None,
                 repository: Some("https://github.com/user/repo".to_string()),
+                ..Default::default()
             }),
             dependencies: None,
             dev_dependencies: None,
             build_dependencies: None,
+            ..Default::default()
         };
 
         let config = mock_config_with_checks(&["MD004"]);
@@ -574,10 +1788,12 @@ path_dep = { path = "../local" }
                 license: Some("MIT".to_string()),
                 readme: Some(toml::Value::Boolean(false)),
                 repository: Some("https://github.com/user/repo".to_string()),
+                ..Default::default()
             }),
             dependencies: None,
             dev_dependencies: None,
             build_dependencies: None,
+            ..Default::default()
         };
 
         let config = mock_config_with_checks(&["MD004"]);
@@ -597,10 +1813,12 @@ path_dep = { path = "../local" }
                 license: Some("MIT".to_string()),
                 readme: Some(toml::Value::Integer(123)),
                 repository: Some("https://github.com/user/repo".to_string()),
+                ..Default::default()
             }),
             dependencies: None,
             dev_dependencies: None,
             build_dependencies: None,
+            ..Default::default()
         };
 
         let config = mock_config_with_checks(&["MD004"]);
@@ -619,6 +1837,7 @@ path_dep = { path = "../local" }
             dependencies: Some(HashMap::new()),
             dev_dependencies: None,
             build_dependencies: None,
+            ..Default::default()
         };
 
         let config = mock_config_all_enabled();
@@ -641,10 +1860,12 @@ path_dep = { path = "../local" }
                 license: None,
                 readme: None,
                 repository: None,
+                ..Default::default()
             }),
             dependencies: None,
             dev_dependencies: None,
             build_dependencies: None,
+            ..Default::default()
         };
 
         let config = mock_config_all_enabled();
@@ -670,10 +1891,12 @@ path_dep = { path = "../local" }
                 license: None,
                 readme: None,
                 repository: None,
+                ..Default::default()
             }),
             dependencies: None,
             dev_dependencies: None,
             build_dependencies: None,
+            ..Default::default()
         };
 
         // Create config with only MD001 enabled, others explicitly disabled
@@ -685,7 +1908,10 @@ path_dep = { path = "../local" }
 
         let config = Config {
             general: GeneralConfig::default(),
-            checks: ChecksConfig { enabled },
+            checks: ChecksConfig {
+                enabled,
+                ..Default::default()
+            },
         };
 
         let findings = check_missing_metadata(&manifest, &config);
@@ -706,10 +1932,12 @@ path_dep = { path = "../local" }
                 license: Some("MIT".to_string()),
                 readme: Some(toml::Value::String("README.md".to_string())),
                 repository: Some("https://github.com/user/repo".to_string()),
+                ..Default::default()
             }),
             dependencies: None,
             dev_dependencies: None,
             build_dependencies: None,
+            ..Default::default()
         };
 
         let config = mock_config_with_checks(&["MD004"]);
@@ -723,4 +1951,445 @@ path_dep = { path = "../local" }
         assert_eq!(findings[0].code, "MD004");
         assert_eq!(findings[0].severity, Severity::Warning);
     }
+
+    #[test]
+    fn test_edition_rust_version_consistent() {
+        let (_temp_dir, path) = create_temp_cargo_toml(
+            r#"
+[package]
+name = "test"
+version = "0.1.0"
+edition = "2021"
+rust-version = "1.70"
+"#,
+        );
+        let manifest = CargoManifest::parse(&path).unwrap();
+        assert_eq!(
+            manifest.package.as_ref().unwrap().rust_version,
+            Some(Inheritable::Value("1.70".to_string()))
+        );
+
+        let findings = check_edition_rust_version(&manifest, &Config::default());
+        assert!(findings.is_empty());
+    }
+
+    #[test]
+    fn test_library_without_rust_version() {
+        let (temp_dir, path) = create_temp_cargo_toml(
+            r#"
+[package]
+name = "test"
+version = "0.1.0"
+edition = "2021"
+"#,
+        );
+        let project_root = temp_dir.path();
+        fs::create_dir_all(project_root.join("src")).unwrap();
+        fs::write(project_root.join("src/main.rs"), "fn main() {}").unwrap();
+        let manifest = CargoManifest::parse(&path).unwrap();
+
+        // Binaries are not affected
+        let findings = check_library_rust_version(&manifest, project_root, &Config::default());
+        assert!(findings.is_empty());
+
+        fs::write(project_root.join("src/lib.rs"), "").unwrap();
+        let findings = check_library_rust_version(&manifest, project_root, &Config::default());
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].code, "ED009");
+        assert_eq!(findings[0].severity, Severity::Note);
+        assert_eq!(findings[0].line_number, Some(2));
+
+        let mut unpublished = manifest.clone();
+        unpublished.package.as_mut().unwrap().publish = Some(toml::Value::Boolean(false));
+        assert!(
+            check_library_rust_version(&unpublished, project_root, &Config::default()).is_empty()
+        );
+
+        let mut with_msrv = manifest.clone();
+        with_msrv.package.as_mut().unwrap().rust_version =
+            Some(Inheritable::Value("1.70".to_string()));
+        assert!(
+            check_library_rust_version(&with_msrv, project_root, &Config::default()).is_empty()
+        );
+    }
+
+    #[test]
+    fn test_inherited_rust_version_counts_as_declared() {
+        let (temp_dir, path) = create_temp_cargo_toml(
+            r#"
+[package]
+name = "member"
+version = "0.1.0"
+edition = "2024"
+rust-version.workspace = true
+description = "A workspace member"
+"#,
+        );
+        let project_root = temp_dir.path();
+        fs::create_dir_all(project_root.join("src")).unwrap();
+        fs::write(project_root.join("src/lib.rs"), "").unwrap();
+
+        // Parsing must not fail, or every manifest check would be skipped
+        let manifest = CargoManifest::parse(&path).unwrap();
+        assert_eq!(
+            manifest.package.as_ref().unwrap().rust_version,
+            Some(Inheritable::Workspace { workspace: true })
+        );
+        assert!(check_library_rust_version(&manifest, project_root, &Config::default()).is_empty());
+        assert!(check_edition_rust_version(&manifest, &Config::default()).is_empty());
+
+        let config = mock_config_with_checks(&["MD002"]);
+        let findings = check_missing_metadata(&manifest, &config);
+        assert!(findings.iter().any(|f| f.code == "MD002"));
+    }
+
+    #[test]
+    fn test_edition_newer_than_rust_version() {
+        let (_temp_dir, path) = create_temp_cargo_toml(
+            r#"
+[package]
+name = "test"
+version = "0.1.0"
+edition = "2024"
+rust-version = "1.70.0"
+"#,
+        );
+        let manifest = CargoManifest::parse(&path).unwrap();
+
+        let findings = check_edition_rust_version(&manifest, &Config::default());
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].code, "ED005");
+        assert_eq!(findings[0].severity, Severity::Warning);
+        assert!(findings[0].message.contains("1.85"));
+    }
+
+    #[test]
+    fn test_license_file_satisfies_md002() {
+        let (_temp_dir, path) = create_temp_cargo_toml(
+            r#"
+[package]
+name = "test"
+version = "0.1.0"
+license-file = "LICENSE"
+"#,
+        );
+        let manifest = CargoManifest::parse(&path).unwrap();
+        assert_eq!(
+            manifest.package.as_ref().unwrap().license_file.as_deref(),
+            Some("LICENSE")
+        );
+
+        let config = mock_config_with_checks(&["MD002", "MD016"]);
+        let findings = check_missing_metadata(&manifest, &config);
+        assert!(
+            !findings
+                .iter()
+                .any(|f| f.code == "MD002" || f.code == "MD016")
+        );
+    }
+
+    #[test]
+    fn test_invalid_categories() {
+        let (_temp_dir, path) = create_temp_cargo_toml(
+            r#"
+[package]
+name = "test"
+version = "1.0.0"
+categories = ["command-line-utilities", "web development", "development-tools::testing", "clis"]
+"#,
+        );
+        let manifest = CargoManifest::parse(&path).unwrap();
+
+        let config = mock_config_with_checks(&["MD019"]);
+        let findings = check_missing_metadata(&manifest, &config);
+        let md019: Vec<&Finding> = findings.iter().filter(|f| f.code == "MD019").collect();
+        assert_eq!(md019.len(), 1);
+        assert_eq!(md019[0].severity, Severity::Warning);
+        assert!(md019[0].message.contains("'web development', 'clis'"));
+        assert!(!md019[0].message.contains("command-line-utilities"));
+
+        let (_temp_dir, path) = create_temp_cargo_toml(
+            r#"
+[package]
+name = "test"
+version = "1.0.0"
+categories = ["command-line-utilities"]
+"#,
+        );
+        let manifest = CargoManifest::parse(&path).unwrap();
+        let findings = check_missing_metadata(&manifest, &config);
+        assert!(!findings.iter().any(|f| f.code == "MD019"));
+    }
+
+    #[test]
+    fn test_inherited_categories_are_not_validated() {
+        let (_temp_dir, path) = create_temp_cargo_toml(
+            r#"
+[package]
+name = "member"
+version = "0.1.0"
+edition = "2021"
+rust-version.workspace = true
+categories.workspace = true
+"#,
+        );
+        let manifest = CargoManifest::parse(&path).unwrap();
+        assert_eq!(
+            manifest.package.as_ref().unwrap().categories,
+            Some(Inheritable::Workspace { workspace: true })
+        );
+
+        let config = mock_config_with_checks(&["MD001", "MD019"]);
+        let findings = check_missing_metadata(&manifest, &config);
+        assert!(!findings.iter().any(|f| f.code == "MD019"));
+        // Other manifest checks still run on the member
+        assert!(findings.iter().any(|f| f.code == "MD001"));
+    }
+
+    #[test]
+    fn test_redundant_dependencies() {
+        let (_temp_dir, path) = create_temp_cargo_toml(
+            r#"
+[package]
+name = "test"
+version = "1.0.0"
+
+[dependencies]
+lazy_static = "1"
+once_cell = "1"
+chrono = "0.4"
+time = "0.3"
+"#,
+        );
+        let manifest = CargoManifest::parse(&path).unwrap();
+
+        // Opt-in: silent by default
+        assert!(check_redundant_dependencies(&manifest, &Config::default()).is_empty());
+
+        let mut config = mock_config_with_checks(&["DP016"]);
+        let findings = check_redundant_dependencies(&manifest, &config);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].code, "DP016");
+        assert!(
+            findings[0]
+                .message
+                .contains("'lazy_static' and 'once_cell'")
+        );
+
+        config.checks.redundant_dependency_pairs =
+            Some(vec![["chrono".to_string(), "time".to_string()]]);
+        let findings = check_redundant_dependencies(&manifest, &config);
+        assert_eq!(findings.len(), 2);
+        assert!(findings[1].message.contains("'chrono' and 'time'"));
+    }
+
+    #[test]
+    fn test_dev_only_dependencies() {
+        let (_temp_dir, path) = create_temp_cargo_toml(
+            r#"
+[package]
+name = "test"
+version = "1.0.0"
+
+[dependencies]
+criterion = "0.5"
+serde = "1"
+proptest = { version = "1", optional = true }
+"#,
+        );
+        let manifest = CargoManifest::parse(&path).unwrap();
+        let mut config = Config::default();
+        let findings = check_dev_only_dependencies(&manifest, &config);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].code, "DP019");
+        assert!(findings[0].message.contains("'criterion'"));
+
+        config.checks.dev_only_crates = Some(vec!["serde".to_string()]);
+        let findings = check_dev_only_dependencies(&manifest, &config);
+        assert_eq!(findings.len(), 2);
+        assert!(findings[1].message.contains("'serde'"));
+    }
+
+    #[test]
+    fn test_metadata_findings_point_at_manifest_lines() {
+        let (_temp_dir, path) = create_temp_cargo_toml(
+            r#"# Demo manifest
+
+[package]
+name = "test"
+version = "1.0.0"
+description = ""
+"#,
+        );
+        let manifest = CargoManifest::parse(&path).unwrap();
+        let findings = check_missing_metadata(&manifest, &Config::default());
+
+        // Present but empty: the key's own line
+        let md001 = findings.iter().find(|f| f.code == "MD001").unwrap();
+        assert_eq!(md001.line_number, Some(6));
+        // Missing entirely: the [package] header
+        let md002 = findings.iter().find(|f| f.code == "MD002").unwrap();
+        assert_eq!(md002.line_number, Some(3));
+    }
+
+    #[test]
+    fn test_manifest_findings_point_at_manifest_lines() {
+        let (_temp_dir, path) = create_temp_cargo_toml(
+            r#"[package]
+name = "test"
+version = "1.0.0"
+edition = "2021"
+
+[dependencies]
+serde = "*"
+anyhow = "1.0"
+
+[features]
+extra = ["dep:missing"]
+"#,
+        );
+        let manifest = CargoManifest::parse(&path).unwrap();
+        let config = mock_config_with_checks(&["DP001", "FT001", "MD015"]);
+        let line = |findings: &[Finding], code: &str| {
+            findings
+                .iter()
+                .find(|f| f.code == code)
+                .unwrap()
+                .line_number
+        };
+
+        assert_eq!(line(&check_rust_edition(&manifest), "ED001"), Some(4));
+        assert_eq!(
+            line(&check_dependency_versions(&manifest, &config), "DP001"),
+            Some(7)
+        );
+        assert_eq!(line(&check_features(&manifest, &config), "FT001"), Some(11));
+        let content = fs::read_to_string(&path).unwrap();
+        assert_eq!(
+            line(&check_sorted_dependencies(&content, &config), "MD015"),
+            Some(8)
+        );
+    }
+
+    #[test]
+    fn test_toml_line_falls_back_to_the_deepest_existing_key() {
+        let source = "[package]\nname = \"x\"\n\n[patch.crates-io]\nfoo = { path = \"../foo\" }\n";
+        assert_eq!(toml_line(source, &["patch", "crates-io", "foo"]), Some(5));
+        assert_eq!(toml_line(source, &["package", "edition"]), Some(1));
+        assert_eq!(toml_line(source, &["dependencies", "serde"]), None);
+    }
+
+    #[test]
+    fn test_package_name_crates_io_rules() {
+        let name_findings = |name: &str| {
+            let (_temp_dir, path) = create_temp_cargo_toml(&format!(
+                "[package]\nname = \"{name}\"\nversion = \"1.0.0\"\n"
+            ));
+            let manifest = CargoManifest::parse(&path).unwrap();
+            check_missing_metadata(&manifest, &mock_config_with_checks(&["MD020"]))
+                .into_iter()
+                .filter(|f| f.code == "MD020")
+                .collect::<Vec<_>>()
+        };
+
+        let findings = name_findings("My Crate");
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].severity, Severity::Warning);
+        assert!(findings[0].message.contains("' '"));
+
+        assert!(name_findings("valid-crate_1").is_empty());
+
+        let problems = crate_name_problems("1crate");
+        assert_eq!(
+            problems,
+            vec!["must start with an ASCII letter".to_string()]
+        );
+        assert_eq!(crate_name_problems(&"a".repeat(65)).len(), 1);
+    }
+
+    #[test]
+    fn test_package_version_semver() {
+        let version_findings = |version: &str| {
+            let (_temp_dir, path) = create_temp_cargo_toml(&format!(
+                "[package]\nname = \"test\"\nversion = \"{version}\"\n"
+            ));
+            let manifest = CargoManifest::parse(&path).unwrap();
+            let config = mock_config_with_checks(&["MD017", "MD018"]);
+            check_missing_metadata(&manifest, &config)
+                .into_iter()
+                .filter(|f| f.code == "MD017" || f.code == "MD018")
+                .collect::<Vec<_>>()
+        };
+
+        for invalid in ["1.0", "v1.0.0"] {
+            let findings = version_findings(invalid);
+            assert_eq!(findings.len(), 1, "{invalid}");
+            assert_eq!(findings[0].code, "MD017");
+            assert_eq!(findings[0].severity, Severity::Error);
+            assert!(findings[0].message.contains(&format!("'{invalid}'")));
+        }
+
+        let findings = version_findings("0.3.1");
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].code, "MD018");
+        assert!(findings[0].message.contains("0.3.0 -> 0.4.0"));
+
+        assert!(version_findings("1.2.3").is_empty());
+    }
+
+    #[test]
+    fn test_license_and_license_file_conflict() {
+        let (_temp_dir, path) = create_temp_cargo_toml(
+            r#"
+[package]
+name = "test"
+version = "0.1.0"
+license = "MIT"
+license-file = "LICENSE"
+"#,
+        );
+        let manifest = CargoManifest::parse(&path).unwrap();
+
+        let config = mock_config_with_checks(&["MD002", "MD016"]);
+        let findings = check_missing_metadata(&manifest, &config);
+        assert!(!findings.iter().any(|f| f.code == "MD002"));
+        let md016 = findings.iter().find(|f| f.code == "MD016").unwrap();
+        assert_eq!(md016.severity, Severity::Note);
+    }
+
+    #[test]
+    fn test_binary_with_caret_deps_and_no_lockfile() {
+        let (temp_dir, path) = create_temp_cargo_toml(
+            r#"
+[package]
+name = "tool"
+version = "0.1.0"
+
+[dependencies]
+serde = "1.0"
+clap = { version = "^4" }
+pinned = "=0.3.1"
+"#,
+        );
+        let project_root = temp_dir.path();
+        fs::create_dir_all(project_root.join("src")).unwrap();
+        fs::write(project_root.join("src/main.rs"), "fn main() {}").unwrap();
+        let manifest = CargoManifest::parse(&path).unwrap();
+
+        // Opt-in
+        assert!(
+            check_binary_without_lockfile(&manifest, project_root, &Config::default()).is_empty()
+        );
+
+        let config = mock_config_with_checks(&["DP015"]);
+        let findings = check_binary_without_lockfile(&manifest, project_root, &config);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].code, "DP015");
+        assert_eq!(findings[0].severity, Severity::Note);
+        assert!(findings[0].message.contains("clap, serde"));
+        assert!(!findings[0].message.contains("pinned"));
+
+        fs::write(project_root.join("Cargo.lock"), "").unwrap();
+        assert!(check_binary_without_lockfile(&manifest, project_root, &config).is_empty());
+    }
 }
@@ -0,0 +1,652 @@
+//! # Output Rendering
+//!
+//! One [`Renderer`] per output format, selected through [`OutputFormat`].
+//!
+//! Renderers only write the report body to the writer they are given. Decisions that
+//! are not part of the report (the exit code, notes written to stderr) stay with the
+//! caller, so adding a format means adding one struct and one [`OutputFormat`] variant.
+
+use crate::checks::{self, CheckState};
+use crate::config::{Config, GroupBy};
+use crate::diagnostics::{self, Finding, JsonLine, JsonReport};
+use std::collections::HashMap;
+use std::io;
+use termcolor::{Color, ColorSpec, WriteColor};
+
+/// Output formats accepted by `--format`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Human,
+    Json,
+    JsonLines,
+    Markdown,
+    Compact,
+}
+
+impl OutputFormat {
+    /// Maps a `--format` value to a format. Unknown names fall back to [`OutputFormat::Human`].
+    pub fn from_name(name: &str) -> Self {
+        match name.to_ascii_lowercase().as_str() {
+            "json" => OutputFormat::Json,
+            "jsonl" | "json-lines" => OutputFormat::JsonLines,
+            "markdown" | "md" => OutputFormat::Markdown,
+            "compact" => OutputFormat::Compact,
+            _ => OutputFormat::Human,
+        }
+    }
+
+    /// Whether the output is meant for programs rather than people.
+    pub fn is_machine_readable(&self) -> bool {
+        !matches!(self, OutputFormat::Human)
+    }
+
+    /// Whether the renderer itself writes the "... and N more (limit reached)" note for
+    /// findings dropped by `--max-findings`.
+    pub fn reports_omitted(&self) -> bool {
+        matches!(self, OutputFormat::Human | OutputFormat::Markdown)
+    }
+
+    /// Builds the renderer for this format. `omitted` is the number of findings
+    /// dropped by `--max-findings`; `fails` is the outcome of the `fail_on` gate.
+    pub fn renderer(&self, config: &Config, omitted: usize, fails: bool) -> Box<dyn Renderer> {
+        match self {
+            OutputFormat::Human => Box::new(HumanRenderer {
+                group_by: config.general.group_by,
+                collapse: config.collapse(),
+                omitted,
+            }),
+            OutputFormat::Json => Box::new(JsonRenderer {
+                omitted,
+                fails,
+                rule_prefix: config.rule_prefix().to_string(),
+            }),
+            OutputFormat::JsonLines => Box::new(JsonLinesRenderer {
+                rule_prefix: config.rule_prefix().to_string(),
+            }),
+            OutputFormat::Markdown => Box::new(MarkdownRenderer { omitted }),
+            OutputFormat::Compact => Box::new(CompactRenderer),
+        }
+    }
+}
+
+/// Writes a report of `findings` in one output format.
+///
+/// Takes a [`WriteColor`] rather than a plain [`std::io::Write`] so the human-readable format can
+/// color its output; formats without color simply ignore it.
+pub trait Renderer {
+    fn render(&self, findings: &[Finding], w: &mut dyn WriteColor) -> io::Result<()>;
+}
+
+/// Section header for findings that are not tied to a file under `--group-by file`.
+const NO_FILE_LABEL: &str = "General";
+
+/// Colored text, one finding per line.
+pub struct HumanRenderer {
+    pub group_by: Option<GroupBy>,
+    pub collapse: bool,
+    pub omitted: usize,
+}
+
+impl Renderer for HumanRenderer {
+    fn render(&self, findings: &[Finding], w: &mut dyn WriteColor) -> io::Result<()> {
+        if findings.is_empty() && self.omitted == 0 {
+            return write_no_issues(w);
+        }
+
+        match self.group_by {
+            Some(GroupBy::Category) => {
+                let mut grouped: Vec<&Finding> = findings.iter().collect();
+                grouped.sort_by_key(|f| f.category);
+                for section in grouped.chunk_by(|a, b| a.category == b.category) {
+                    w.set_color(ColorSpec::new().set_bold(true))?;
+                    writeln!(w, "\n== {} ==", section[0].category.label())?;
+                    w.reset()?;
+                    write_human_findings(w, section, self.collapse)?;
+                }
+            }
+            Some(GroupBy::File) => {
+                let mut grouped: Vec<&Finding> = findings.iter().collect();
+                // Findings without a file go last, under a shared label
+                grouped.sort_by(|a, b| {
+                    (a.file_path.is_none(), &a.file_path, a.line_number).cmp(&(
+                        b.file_path.is_none(),
+                        &b.file_path,
+                        b.line_number,
+                    ))
+                });
+                for section in grouped.chunk_by(|a, b| a.file_path == b.file_path) {
+                    let label = section[0].file_path.as_deref().unwrap_or(NO_FILE_LABEL);
+                    w.set_color(ColorSpec::new().set_bold(true))?;
+                    writeln!(w, "\n── {label} ──")?;
+                    w.reset()?;
+                    write_human_findings(w, section, self.collapse)?;
+                }
+            }
+            Some(GroupBy::None) | None => {
+                let all: Vec<&Finding> = findings.iter().collect();
+                write_human_findings(w, &all, self.collapse)?;
+            }
+        }
+
+        if self.omitted > 0 {
+            writeln!(w, "... and {} more (limit reached)", self.omitted)?;
+        }
+        writeln!(w, "\nFound {} issues:", findings.len() + self.omitted)
+    }
+}
+
+/// Pretty-printed [`JsonReport`] envelope and nothing else, so stdout is a single JSON
+/// document. A clean run is an envelope with an empty `findings` array.
+pub struct JsonRenderer {
+    pub omitted: usize,
+    pub fails: bool,
+    pub rule_prefix: String,
+}
+
+impl Renderer for JsonRenderer {
+    fn render(&self, findings: &[Finding], w: &mut dyn WriteColor) -> io::Result<()> {
+        let report =
+            JsonReport::new(with_rule_ids(findings, &self.rule_prefix)).with_outcome(self.fails);
+        let json = serde_json::to_string_pretty(&report).map_err(io::Error::other)?;
+        writeln!(w, "{json}")
+    }
+}
+
+/// One JSON object per finding, followed by a summary line (see [`JsonLine`]).
+pub struct JsonLinesRenderer {
+    pub rule_prefix: String,
+}
+
+impl Renderer for JsonLinesRenderer {
+    fn render(&self, findings: &[Finding], w: &mut dyn WriteColor) -> io::Result<()> {
+        for finding in &with_rule_ids(findings, &self.rule_prefix) {
+            let line =
+                serde_json::to_string(&JsonLine::Finding(finding)).map_err(io::Error::other)?;
+            writeln!(w, "{line}")?;
+        }
+        let summary =
+            serde_json::to_string(&JsonLine::summary(findings)).map_err(io::Error::other)?;
+        writeln!(w, "{summary}")
+    }
+}
+
+/// A Markdown table of findings, for pull request comments and job summaries.
+pub struct MarkdownRenderer {
+    pub omitted: usize,
+}
+
+impl Renderer for MarkdownRenderer {
+    fn render(&self, findings: &[Finding], w: &mut dyn WriteColor) -> io::Result<()> {
+        writeln!(w, "# Cargo Dokita report\n")?;
+        if findings.is_empty() && self.omitted == 0 {
+            return writeln!(w, "No issues found.");
+        }
+        writeln!(w, "| Severity | Code | Location | Message |")?;
+        writeln!(w, "| --- | --- | --- | --- |")?;
+        for finding in findings {
+            let location = match (&finding.file_path, finding.line_number) {
+                (Some(file), Some(line)) => format!("`{file}:{line}`"),
+                (Some(file), None) => format!("`{file}`"),
+                (None, _) => String::new(),
+            };
+            writeln!(
+                w,
+                "| {} | {} | {location} | {} |",
+                finding.severity,
+                finding.code,
+                escape_table_cell(&finding.message)
+            )?;
+        }
+        if self.omitted > 0 {
+            writeln!(w, "\n... and {} more (limit reached)", self.omitted)?;
+        }
+        writeln!(w, "\nFound {} issues.", findings.len() + self.omitted)
+    }
+}
+
+/// One tab-separated line per finding, `severity\tcode\tfile:line\tmessage`, for grep
+/// and awk. No color, header or summary; a clean run prints nothing. Lines are sorted by
+/// location, then code, so the output does not depend on scan order.
+pub struct CompactRenderer;
+
+impl Renderer for CompactRenderer {
+    fn render(&self, findings: &[Finding], w: &mut dyn WriteColor) -> io::Result<()> {
+        let mut sorted: Vec<&Finding> = findings.iter().collect();
+        // Findings without a file go last
+        sorted.sort_by(|a, b| {
+            (
+                a.file_path.is_none(),
+                &a.file_path,
+                a.line_number,
+                &a.code,
+                &a.message,
+            )
+                .cmp(&(
+                    b.file_path.is_none(),
+                    &b.file_path,
+                    b.line_number,
+                    &b.code,
+                    &b.message,
+                ))
+        });
+        for finding in sorted {
+            let location = match (&finding.file_path, finding.line_number) {
+                (Some(file), Some(line)) => format!("{file}:{line}"),
+                (Some(file), None) => file.clone(),
+                (None, _) => "-".to_string(),
+            };
+            writeln!(
+                w,
+                "{}\t{}\t{location}\t{}",
+                finding.severity,
+                finding.code,
+                finding.message.replace(['\t', '\r', '\n'], " ")
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Keeps a message on one table row: pipes are escaped and line breaks flattened.
+fn escape_table_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace(['\r', '\n'], " ")
+}
+
+/// Footer for `--summary-by-check`: every registered check with the number of findings
+/// it produced, or the reason it did not run: disabled, or enabled but in `skipped`
+/// (see [`checks::skipped_checks`]). `0` means the check ran and found nothing.
+pub fn check_summary(
+    findings: &[Finding],
+    config: &Config,
+    skipped: &HashMap<&str, &str>,
+) -> String {
+    let mut summary = String::from("Findings by check:\n");
+    for check in checks::CHECKS {
+        let state = CheckState::resolve(check.code, config);
+        let tally = if let Some(reason) = skipped.get(check.code) {
+            reason.to_string()
+        } else if state.is_enabled() {
+            findings
+                .iter()
+                .filter(|f| f.code == check.code)
+                .count()
+                .to_string()
+        } else {
+            state.label().to_string()
+        };
+        summary.push_str(&format!("  {:<10} {tally}\n", check.code));
+    }
+    summary
+}
+
+/// Copies of `findings` whose `rule_id` is the code behind `rule_prefix`. The code stays
+/// as it is so baselines and fingerprints are unaffected. No `rule_id` without a prefix.
+fn with_rule_ids(findings: &[Finding], rule_prefix: &str) -> Vec<Finding> {
+    findings
+        .iter()
+        .cloned()
+        .map(|mut finding| {
+            if !rule_prefix.is_empty() {
+                finding.rule_id = Some(format!("{rule_prefix}{}", finding.code));
+            }
+            finding
+        })
+        .collect()
+}
+
+fn write_no_issues(w: &mut dyn WriteColor) -> io::Result<()> {
+    w.set_color(ColorSpec::new().set_fg(Some(Color::Green)))?;
+    writeln!(
+        w,
+        "No issues found. Your project looks healthy (based on current checks)!"
+    )?;
+    w.reset()
+}
+
+/// Writes `findings` in order, optionally collapsing repeated codes per file
+/// (see [`diagnostics::collapse_findings`]).
+fn write_human_findings(
+    w: &mut dyn WriteColor,
+    findings: &[&Finding],
+    collapse: bool,
+) -> io::Result<()> {
+    if collapse {
+        for group in diagnostics::collapse_findings(findings.iter().copied()) {
+            write_human_finding(w, &group.summary())?;
+        }
+    } else {
+        for finding in findings {
+            write_human_finding(w, finding)?;
+        }
+    }
+    Ok(())
+}
+
+/// Writes one finding as a colored `[SEVERITY] (CODE): message [file:line]` line, the
+/// location in the `path:line` form editors and terminals open directly.
+fn write_human_finding(w: &mut dyn WriteColor, finding: &Finding) -> io::Result<()> {
+    let severity_str = finding.severity.to_string().to_ascii_uppercase();
+
+    w.set_color(ColorSpec::new().set_fg(Some(Color::Yellow)).set_bold(true))?;
+    write!(w, "[{severity_str}]")?;
+    w.reset()?;
+
+    let file_info = finding.file_path.as_deref().unwrap_or("N/A");
+    let line_info = finding
+        .line_number
+        .map_or("".to_string(), |l| format!(":{l}"));
+
+    w.set_color(ColorSpec::new().set_fg(Some(Color::Magenta)))?;
+    write!(w, " ({})", finding.code)?;
+    w.reset()?;
+
+    writeln!(w, ": {} [{}{}]", finding.message, file_info, line_info)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diagnostics::Severity;
+    use termcolor::NoColor;
+
+    fn sample_findings() -> Vec<Finding> {
+        vec![
+            Finding::new(
+                "MD001",
+                "Missing description".to_string(),
+                Severity::Warning,
+                Some("Cargo.toml".to_string()),
+            ),
+            Finding::new(
+                "CODE004",
+                "TODO".to_string(),
+                Severity::Note,
+                Some("src/lib.rs".to_string()),
+            )
+            .with_line(3),
+        ]
+    }
+
+    fn render_to_string(format: OutputFormat, config: &Config, findings: &[Finding]) -> String {
+        let mut out = NoColor::new(Vec::new());
+        format
+            .renderer(config, 0, false)
+            .render(findings, &mut out)
+            .unwrap();
+        String::from_utf8(out.into_inner()).unwrap()
+    }
+
+    #[test]
+    fn test_output_format_from_name() {
+        assert_eq!(OutputFormat::from_name("json"), OutputFormat::Json);
+        assert_eq!(OutputFormat::from_name("JSONL"), OutputFormat::JsonLines);
+        assert_eq!(
+            OutputFormat::from_name("json-lines"),
+            OutputFormat::JsonLines
+        );
+        assert_eq!(OutputFormat::from_name("md"), OutputFormat::Markdown);
+        assert_eq!(OutputFormat::from_name("Compact"), OutputFormat::Compact);
+        assert_eq!(OutputFormat::from_name("human"), OutputFormat::Human);
+        assert_eq!(OutputFormat::from_name("anything"), OutputFormat::Human);
+    }
+
+    #[test]
+    fn test_human_renderer_output() {
+        let output = render_to_string(OutputFormat::Human, &Config::default(), &sample_findings());
+        assert_eq!(
+            output,
+            "[WARNING] (MD001): Missing description [Cargo.toml]\n\
+             [NOTE] (CODE004): TODO [src/lib.rs:3]\n\
+             \nFound 2 issues:\n"
+        );
+
+        let manifest_finding = Finding::new(
+            "MD017",
+            "Invalid version".to_string(),
+            Severity::Error,
+            Some("Cargo.toml".to_string()),
+        )
+        .with_line(13);
+        let output = render_to_string(OutputFormat::Human, &Config::default(), &[manifest_finding]);
+        assert!(output.starts_with("[ERROR] (MD017): Invalid version [Cargo.toml:13]\n"));
+
+        let mut config = Config::default();
+        config.general.group_by = Some(GroupBy::Category);
+        let output = render_to_string(OutputFormat::Human, &config, &sample_findings());
+        assert!(output.starts_with("\n== Metadata ==\n[WARNING] (MD001)"));
+        assert!(output.contains("\n== Code Quality ==\n[NOTE] (CODE004)"));
+
+        let output = render_to_string(OutputFormat::Human, &Config::default(), &[]);
+        assert_eq!(
+            output,
+            "No issues found. Your project looks healthy (based on current checks)!\n"
+        );
+    }
+
+    #[test]
+    fn test_human_renderer_groups_by_file() {
+        let finding = |file: Option<&str>, line: Option<usize>| {
+            let finding = Finding::new(
+                "CODE001",
+                "unwrap".to_string(),
+                Severity::Warning,
+                file.map(str::to_string),
+            );
+            match line {
+                Some(line) => finding.with_line(line),
+                None => finding,
+            }
+        };
+        let findings = vec![
+            finding(Some("src/b.rs"), Some(9)),
+            finding(None, None),
+            finding(Some("src/a.rs"), Some(4)),
+            finding(Some("src/b.rs"), Some(2)),
+        ];
+
+        let mut config = Config::default();
+        config.general.group_by = Some(GroupBy::File);
+        let output = render_to_string(OutputFormat::Human, &config, &findings);
+        assert_eq!(
+            output,
+            "\n── src/a.rs ──\n\
+             [WARNING] (CODE001): unwrap [src/a.rs:4]\n\
+             \n── src/b.rs ──\n\
+             [WARNING] (CODE001): unwrap [src/b.rs:2]\n\
+             [WARNING] (CODE001): unwrap [src/b.rs:9]\n\
+             \n── General ──\n\
+             [WARNING] (CODE001): unwrap [N/A]\n\
+             \nFound 4 issues:\n"
+        );
+
+        config.general.group_by = Some(GroupBy::None);
+        let output = render_to_string(OutputFormat::Human, &config, &findings);
+        assert!(output.starts_with("[WARNING] (CODE001): unwrap [src/b.rs:9]\n"));
+    }
+
+    #[test]
+    fn test_reports_omitted_matches_renderer_output() {
+        for format in [
+            OutputFormat::Human,
+            OutputFormat::Json,
+            OutputFormat::JsonLines,
+            OutputFormat::Markdown,
+            OutputFormat::Compact,
+        ] {
+            let mut out = NoColor::new(Vec::new());
+            format
+                .renderer(&Config::default(), 3, false)
+                .render(&sample_findings(), &mut out)
+                .unwrap();
+            let output = String::from_utf8(out.into_inner()).unwrap();
+            assert_eq!(
+                output.contains("... and 3 more (limit reached)"),
+                format.reports_omitted(),
+                "{format:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_human_renderer_reports_omitted_findings() {
+        let renderer = HumanRenderer {
+            group_by: None,
+            collapse: false,
+            omitted: 3,
+        };
+        let mut out = NoColor::new(Vec::new());
+        renderer.render(&sample_findings()[..1], &mut out).unwrap();
+        let output = String::from_utf8(out.into_inner()).unwrap();
+        assert!(output.ends_with("... and 3 more (limit reached)\n\nFound 4 issues:\n"));
+    }
+
+    #[test]
+    fn test_json_renderers_match_serde_output() {
+        let findings = sample_findings();
+
+        let output = render_to_string(OutputFormat::Json, &Config::default(), &findings);
+        let expected = serde_json::to_string_pretty(&JsonReport::new(findings.clone())).unwrap();
+        assert!(expected.contains("\"passed\": true"));
+        assert_eq!(output, format!("{expected}\n"));
+
+        let output = render_to_string(OutputFormat::Json, &Config::default(), &[]);
+        let report: JsonReport = serde_json::from_str(&output).unwrap();
+        assert!(report.findings.is_empty());
+        assert!(report.passed);
+
+        let output = render_to_string(OutputFormat::JsonLines, &Config::default(), &findings);
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(
+            lines[0],
+            serde_json::to_string(&JsonLine::Finding(&findings[0])).unwrap()
+        );
+        assert!(lines[2].contains("\"type\":\"summary\""));
+    }
+
+    #[test]
+    fn test_rule_prefix_only_in_machine_output() {
+        let findings = sample_findings();
+        let mut config = Config::default();
+        config.general.rule_prefix = Some("dokita/".to_string());
+
+        let output = render_to_string(OutputFormat::Json, &config, &findings);
+        let report: JsonReport = serde_json::from_str(&output).unwrap();
+        assert_eq!(report.findings[0].rule_id.as_deref(), Some("dokita/MD001"));
+        assert_eq!(report.findings[0].code, "MD001");
+
+        let output = render_to_string(OutputFormat::JsonLines, &config, &findings);
+        assert!(output.contains("\"rule_id\":\"dokita/CODE004\""));
+
+        let output = render_to_string(OutputFormat::Human, &config, &findings);
+        assert!(output.contains("MD001"));
+        assert!(!output.contains("dokita/"));
+
+        // Without a prefix there is no rule_id at all
+        let output = render_to_string(OutputFormat::Json, &Config::default(), &findings);
+        assert!(!output.contains("rule_id"));
+    }
+
+    #[test]
+    fn test_compact_renderer_is_tab_separated_and_sorted() {
+        let mut findings = sample_findings();
+        findings.reverse();
+        findings.push(Finding::new(
+            "STRUCT002",
+            "README.md not found.\nAdd one.".to_string(),
+            Severity::Note,
+            None,
+        ));
+
+        let output = render_to_string(OutputFormat::Compact, &Config::default(), &findings);
+        assert_eq!(
+            output,
+            "warning\tMD001\tCargo.toml\tMissing description\n\
+             note\tCODE004\tsrc/lib.rs:3\tTODO\n\
+             note\tSTRUCT002\t-\tREADME.md not found. Add one.\n"
+        );
+        assert!(output.lines().all(|line| line.split('\t').count() == 4));
+        assert!(render_to_string(OutputFormat::Compact, &Config::default(), &[]).is_empty());
+    }
+
+    #[test]
+    fn test_saved_json_report_renders_as_markdown() {
+        let mut findings = sample_findings();
+        findings[0].message = "Missing a | b".to_string();
+        let saved = render_to_string(OutputFormat::Json, &Config::default(), &findings);
+
+        let report = JsonReport::parse(&format!("Loaded configuration\n{saved}")).unwrap();
+        let output = render_to_string(OutputFormat::Markdown, &Config::default(), &report.findings);
+        assert_eq!(
+            output,
+            "# Cargo Dokita report\n\n\
+             | Severity | Code | Location | Message |\n\
+             | --- | --- | --- | --- |\n\
+             | warning | MD001 | `Cargo.toml` | Missing a \\| b |\n\
+             | note | CODE004 | `src/lib.rs:3` | TODO |\n\
+             \nFound 2 issues.\n"
+        );
+
+        let output = render_to_string(OutputFormat::Markdown, &Config::default(), &[]);
+        assert_eq!(output, "# Cargo Dokita report\n\nNo issues found.\n");
+    }
+
+    #[test]
+    fn test_check_summary_distinguishes_clean_and_disabled() {
+        let mut config = Config::default();
+        config.checks.enabled.insert("MD002".to_string(), false);
+        let summary = check_summary(&sample_findings(), &config, &HashMap::new());
+
+        assert!(summary.starts_with("Findings by check:\n"));
+        assert!(summary.contains("\n  MD001      1\n"));
+        assert!(summary.contains("\n  MD003      0\n"));
+        assert!(summary.contains("\n  MD002      disabled (config)\n"));
+        assert!(summary.contains("\n  CODE035    disabled (opt-in)\n"));
+        assert_eq!(summary.lines().count(), checks::CHECKS.len() + 1);
+    }
+
+    #[test]
+    fn test_check_summary_reports_skipped_phases() {
+        let mut config = Config::default();
+        config.general.offline = Some(true);
+        let skipped = checks::skipped_checks(&sample_findings(), &config);
+        let summary = check_summary(&sample_findings(), &config, &skipped);
+        assert!(summary.contains("\n  DP002      skipped (offline)\n"));
+        assert!(summary.contains("\n  DP020      skipped (offline)\n"));
+        assert!(summary.contains("\n  MD003      0\n"));
+
+        // A local error under fail_fast stops the network phase, offline or not
+        config.general.offline = Some(false);
+        config.general.fail_fast = Some(true);
+        let mut findings = sample_findings();
+        findings.push(Finding::new(
+            "MD017",
+            "Invalid version".to_string(),
+            Severity::Error,
+            Some("Cargo.toml".to_string()),
+        ));
+        let summary = check_summary(
+            &findings,
+            &config,
+            &checks::skipped_checks(&findings, &config),
+        );
+        assert!(summary.contains("\n  DP002      skipped (fail-fast)\n"));
+        assert!(summary.contains("\n  SEC001     skipped (fail-fast)\n"));
+
+        // After a deadline, checks without findings may not have run
+        let mut findings = sample_findings();
+        findings.push(Finding::new(
+            "TIMEOUT001",
+            "Analysis stopped".to_string(),
+            Severity::Warning,
+            None,
+        ));
+        let summary = check_summary(
+            &findings,
+            &config,
+            &checks::skipped_checks(&findings, &config),
+        );
+        assert!(summary.contains("\n  MD001      1\n"));
+        assert!(summary.contains("\n  MD003      skipped (deadline)\n"));
+    }
+}
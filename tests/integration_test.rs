@@ -123,3 +123,108 @@ fn test_analyze_workspace_project() {
     // Workspace projects typically have fewer metadata requirements
     // but may still have structural issues
 }
+
+#[test]
+fn test_check_subcommand_runs_single_check() {
+    let env = TestEnvironment::new();
+
+    // No description, so MD001 fires alongside plenty of other findings
+    ProjectBuilder::new("test-project")
+        .build_in(env.path())
+        .unwrap();
+
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_cargo-dokita"))
+        .args(["dokita", "check", "MD001", "--format", "json", "-p"])
+        .arg(env.path())
+        .output()
+        .unwrap();
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let codes: Vec<&str> = report["findings"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|f| f["code"].as_str().unwrap())
+        .collect();
+    assert_eq!(codes, vec!["MD001"]);
+
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_cargo-dokita"))
+        .args(["dokita", "check", "NOPE001", "-p"])
+        .arg(env.path())
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("MD001"));
+}
+
+/// Codes reported by `cargo dokita check <code>` on a project depending on serde, with
+/// the registry lookup for serde answered by a mock with `status`.
+fn check_against_registry(code: &str, status: u16) -> Vec<String> {
+    use httpmock::prelude::*;
+
+    let env = TestEnvironment::new();
+    ProjectBuilder::new("test-project")
+        .dependency("serde", "1.0")
+        .build_in(env.path())
+        .unwrap();
+
+    let server = MockServer::start();
+    let lookup = server.mock(|when, then| {
+        when.method(GET).path("/serde");
+        then.status(status);
+    });
+
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_cargo-dokita"))
+        .args(["dokita", "check", code, "--format", "json", "-p"])
+        .arg(env.path())
+        .env("DOKITA_REGISTRY_URL", server.base_url())
+        .output()
+        .unwrap();
+    assert!(lookup.hits() > 0, "check {code} did not query the registry");
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    report["findings"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|f| f["code"].as_str().unwrap().to_string())
+        .collect()
+}
+
+#[test]
+fn test_check_subcommand_looks_up_unknown_registry_crates() {
+    assert_eq!(check_against_registry("DP020", 404), vec!["DP020"]);
+}
+
+#[test]
+fn test_print_config_shows_env_override() {
+    let env = TestEnvironment::new();
+    ProjectBuilder::new("test-project")
+        .build_in(env.path())
+        .unwrap();
+
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_cargo-dokita"))
+        .args([
+            "dokita",
+            "--print-config",
+            "--format",
+            "json",
+            "--jobs",
+            "3",
+            "-p",
+        ])
+        .arg(env.path())
+        .env("DOKITA_SEVERITY_THRESHOLD", "error")
+        .env("DOKITA_OFFLINE", "1")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let printed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(printed["general"]["severity_threshold"], "Error");
+    assert_eq!(printed["runtime"]["offline"], true);
+    assert_eq!(printed["runtime"]["jobs"], 3);
+    assert_eq!(printed["runtime"]["format"], "json");
+}
+
+#[test]
+fn test_check_subcommand_reports_failed_registry_lookups() {
+    assert_eq!(check_against_registry("API001", 500), vec!["API001"]);
+}